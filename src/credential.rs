@@ -1,10 +1,22 @@
 use super::{
-    exceptions::QiniuEmptyChainCredentialsProvider,
+    exceptions::{
+        QiniuApiCallError, QiniuEmptyChainCredentialsProvider, QiniuIoError, QiniuJsonError,
+    },
     utils::{parse_header_value, parse_headers, parse_method, parse_uri, PythonIoBase},
 };
-use pyo3::prelude::*;
+use maybe_owned::MaybeOwned;
+use pyo3::{prelude::*, pyclass::CompareOp};
 use qiniu_sdk::credential::{QINIU_ACCESS_KEY_ENV_KEY, QINIU_SECRET_KEY_ENV_KEY};
-use std::{collections::HashMap, future::Future, io::Result as IoResult, pin::Pin, time::Duration};
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    fs,
+    future::Future,
+    hash::{Hash, Hasher},
+    io::{Error as IoError, ErrorKind as IoErrorKind, Result as IoResult},
+    path::PathBuf,
+    pin::Pin,
+    time::Duration,
+};
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "credential")?;
@@ -12,10 +24,15 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add("QINIU_SECRET_KEY_ENV_KEY", QINIU_SECRET_KEY_ENV_KEY)?;
     m.add_class::<Credential>()?;
     m.add_class::<CredentialProvider>()?;
+    m.add_class::<StaticCredentialProvider>()?;
     m.add_class::<GlobalCredentialProvider>()?;
     m.add_class::<EnvCredentialProvider>()?;
     m.add_class::<ChainCredentialsProvider>()?;
     m.add_class::<GetOptions>()?;
+    m.add_function(wrap_pyfunction!(credential_from_env, m)?)?;
+    m.add_function(wrap_pyfunction!(credential_from_file, m)?)?;
+    m.add_function(wrap_pyfunction!(static_credential, m)?)?;
+    m.add_function(wrap_pyfunction!(credential_chain, m)?)?;
     Ok(m)
 }
 
@@ -47,6 +64,40 @@ impl Credential {
         Self::__repr__(self_)
     }
 
+    /// 仅当 AccessKey 和 SecretKey 都相同时，两个认证信息才相等
+    fn __richcmp__(
+        self_: PyRef<'_, Self>,
+        other: PyRef<'_, Self>,
+        op: CompareOp,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let is_equal = || -> PyResult<bool> {
+            let credential = self_.as_ref().0.get(Default::default())?;
+            let other = other.as_ref().0.get(Default::default())?;
+            Ok(credential.access_key() == other.access_key()
+                && credential.secret_key() == other.secret_key())
+        };
+        match op {
+            CompareOp::Eq => Ok(is_equal()?.to_object(py)),
+            CompareOp::Ne => Ok((!is_equal()?).to_object(py)),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    /// 仅根据 AccessKey 计算哈希值
+    ///
+    /// AccessKey 相同但 SecretKey 不同的认证信息哈希值相同，但并不相等
+    fn __hash__(self_: PyRef<'_, Self>) -> PyResult<u64> {
+        let mut hasher = DefaultHasher::new();
+        self_
+            .as_ref()
+            .0
+            .get(Default::default())?
+            .access_key()
+            .hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
     /// 获取认证信息的 AccessKey
     #[pyo3(text_signature = "($self)")]
     fn access_key(self_: PyRef<'_, Self>) -> PyResult<String> {
@@ -115,6 +166,99 @@ impl Credential {
             .to_string())
     }
 
+    /// 使用七牛签名算法 V1 对 HTTP 请求进行签名，返回 Authorization 的值
+    ///
+    /// 签名算法 V1 不对 `method` 签名，请求体的类型将从 `headers` 的 `Content-Type` 中获取
+    #[pyo3(text_signature = "($self, url, method, headers, /, body = None)")]
+    #[args(body = "None")]
+    fn sign_request(
+        self_: PyRef<'_, Self>,
+        url: &str,
+        method: &str,
+        headers: HashMap<String, String>,
+        body: Option<&[u8]>,
+    ) -> PyResult<String> {
+        let super_ = self_.as_ref();
+        let url = parse_uri(url)?;
+        parse_method(method)?;
+        let headers = parse_headers(headers)?;
+        Ok(super_
+            .0
+            .get(Default::default())?
+            .authorization_v1_for_request(
+                &url,
+                headers.get(qiniu_sdk::http::header::CONTENT_TYPE),
+                body.unwrap_or_default(),
+            ))
+    }
+
+    /// 使用七牛签名算法 V2 对 HTTP 请求进行签名，返回 Authorization 的值
+    #[pyo3(text_signature = "($self, url, method, headers, /, body = None)")]
+    #[args(body = "None")]
+    fn sign_request_v2(
+        self_: PyRef<'_, Self>,
+        url: &str,
+        method: &str,
+        headers: HashMap<String, String>,
+        body: Option<&[u8]>,
+    ) -> PyResult<String> {
+        let super_ = self_.as_ref();
+        let url = parse_uri(url)?;
+        let method = parse_method(method)?;
+        let headers = parse_headers(headers)?;
+        Ok(super_
+            .0
+            .get(Default::default())?
+            .authorization_v2_for_request(&method, &url, &headers, body.unwrap_or_default()))
+    }
+
+    /// 验证 HTTP 请求的 Authorization 是否由当前认证信息签名
+    ///
+    /// 支持七牛签名算法 V1 和 V2，如果 `headers` 中缺少 Authorization 或格式错误，将返回 `False`
+    #[pyo3(text_signature = "($self, url, method, headers, /, body = None)")]
+    #[args(body = "None")]
+    fn verify_request(
+        self_: PyRef<'_, Self>,
+        url: &str,
+        method: &str,
+        headers: HashMap<String, String>,
+        body: Option<&[u8]>,
+    ) -> PyResult<bool> {
+        let super_ = self_.as_ref();
+        let url = parse_uri(url)?;
+        let method = parse_method(method)?;
+        let headers = parse_headers(headers)?;
+        let authorization = if let Some(authorization) = headers
+            .get(qiniu_sdk::http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+        {
+            authorization
+        } else {
+            return Ok(false);
+        };
+        let credential = super_.0.get(Default::default())?;
+        let expected = if authorization.starts_with("Qiniu ") {
+            credential.authorization_v2_for_request(
+                &method,
+                &url,
+                &headers,
+                body.unwrap_or_default(),
+            )
+        } else if authorization.starts_with("QBox ") {
+            credential.authorization_v1_for_request(
+                &url,
+                headers.get(qiniu_sdk::http::header::CONTENT_TYPE),
+                body.unwrap_or_default(),
+            )
+        } else {
+            return Ok(false);
+        };
+        Ok(constant_time_eq(
+            expected.as_bytes(),
+            authorization.as_bytes(),
+        ))
+    }
+
     /// 使用七牛签名算法 V1 对 HTTP 请求（请求体为内存数据）进行签名，返回 Authorization 的值
     #[pyo3(text_signature = "($self, url, content_type, body)")]
     fn authorization_v1_for_request(
@@ -300,6 +444,25 @@ impl CredentialProvider {
         })
     }
 
+    /// 获取认证信息的 AccessKey
+    ///
+    /// 总是先获取完整的认证信息再提取 AccessKey，因此可能触发环境变量读取或其他认证信息提供者的获取逻辑；
+    /// `StaticCredentialProvider` 的同名方法则直接返回保存的 AccessKey。
+    /// 如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_access_key(&self, py: Python<'_>) -> PyResult<String> {
+        py.allow_threads(|| self.0.get(Default::default()))
+            .map(|credential| credential.access_key().to_string())
+            .map_err(|err| {
+                QiniuApiCallError::from_err(MaybeOwned::Owned(
+                    qiniu_sdk::http_client::ResponseError::new(
+                        qiniu_sdk::http::ResponseErrorKind::LocalIoError.into(),
+                        err,
+                    ),
+                ))
+            })
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -309,6 +472,13 @@ impl CredentialProvider {
     }
 }
 
+impl CredentialProvider {
+    /// 创建与 `StaticCredentialProvider` 相同的静态认证信息提供者
+    pub(super) fn new_static(access_key: String, secret_key: String) -> Self {
+        StaticCredentialProvider::new(access_key, secret_key).1
+    }
+}
+
 impl qiniu_sdk::credential::CredentialProvider for CredentialProvider {
     fn get(
         &self,
@@ -326,6 +496,55 @@ impl qiniu_sdk::credential::CredentialProvider for CredentialProvider {
     }
 }
 
+/// 静态认证信息提供者，包含一对固定的 AccessKey 和 SecretKey
+#[pyclass(extends = CredentialProvider)]
+#[derive(Debug, Clone)]
+#[pyo3(text_signature = "(access_key, secret_key)")]
+struct StaticCredentialProvider {
+    access_key: String,
+}
+
+#[pymethods]
+impl StaticCredentialProvider {
+    /// 创建静态认证信息提供者
+    #[new]
+    fn new(access_key: String, secret_key: String) -> (Self, CredentialProvider) {
+        (
+            Self {
+                access_key: access_key.to_owned(),
+            },
+            CredentialProvider(Box::new(qiniu_sdk::credential::Credential::new(
+                access_key, secret_key,
+            ))),
+        )
+    }
+
+    /// 获取 AccessKey
+    #[pyo3(text_signature = "($self)")]
+    fn get_access_key(&self) -> String {
+        self.access_key.to_owned()
+    }
+
+    /// 获取 SecretKey
+    ///
+    /// 出于安全考虑，总是返回 `***`
+    #[pyo3(text_signature = "($self)")]
+    fn get_secret_key(&self) -> &'static str {
+        "***"
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "StaticCredentialProvider {{ access_key: {:?}, secret_key: \"***\" }}",
+            self.access_key
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
 /// 全局认证信息提供者，可以将认证信息配置在全局变量中。任何全局认证信息提供者实例都可以设置和访问全局认证信息。
 #[pyclass(extends = CredentialProvider)]
 #[pyo3(text_signature = "()")]
@@ -427,6 +646,64 @@ impl ChainCredentialsProvider {
     }
 }
 
+/// 创建环境变量认证信息提供者
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+fn credential_from_env(py: Python<'_>) -> PyResult<Py<EnvCredentialProvider>> {
+    Py::new(py, EnvCredentialProvider::new())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// 从文件中读取认证信息
+///
+/// 文件内容为 JSON 格式，需要包含 `access_key` 和 `secret_key` 两个字段
+#[pyfunction]
+#[pyo3(text_signature = "(path)")]
+fn credential_from_file(path: PathBuf, py: Python<'_>) -> PyResult<Py<Credential>> {
+    let content = fs::read(&path).map_err(QiniuIoError::from_err)?;
+    let value: serde_json::Value =
+        serde_json::from_slice(&content).map_err(QiniuJsonError::from_err)?;
+    let get_field = |field: &str| {
+        value
+            .get(field)
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_owned())
+            .ok_or_else(|| {
+                QiniuIoError::from_err(IoError::new(
+                    IoErrorKind::InvalidData,
+                    format!("{} is missing in {}", field, path.display()),
+                ))
+            })
+    };
+    let access_key = get_field("access_key")?;
+    let secret_key = get_field("secret_key")?;
+    Py::new(py, Credential::new(access_key, secret_key))
+}
+
+/// 创建静态认证信息提供者
+#[pyfunction]
+#[pyo3(text_signature = "(access_key, secret_key)")]
+fn static_credential(
+    access_key: String,
+    secret_key: String,
+    py: Python<'_>,
+) -> PyResult<Py<StaticCredentialProvider>> {
+    Py::new(py, StaticCredentialProvider::new(access_key, secret_key))
+}
+
+/// 将多个认证信息提供者串联，遍历并找寻第一个可用认证信息
+#[pyfunction]
+#[pyo3(text_signature = "(providers)")]
+fn credential_chain(
+    providers: Vec<CredentialProvider>,
+    py: Python<'_>,
+) -> PyResult<Py<ChainCredentialsProvider>> {
+    Py::new(py, ChainCredentialsProvider::new(providers)?)
+}
+
 /// 获取认证信息的选项
 #[pyclass]
 #[derive(Default, Copy, Clone)]
@@ -1,16 +1,34 @@
 use super::{
     credential::CredentialProvider,
-    exceptions::{QiniuApiCallError, QiniuDownloadError, QiniuEmptyEndpoints},
+    exceptions::{
+        QiniuApiCallError, QiniuDownloadError, QiniuEmptyEndpoints, QiniuHttpCallError,
+        QiniuIoError,
+    },
     http::{HttpResponsePartsMut, TransferProgressInfo},
     http_client::{CallbackContextMut, EndpointsProvider, HttpClient, RequestBuilderPartsRef},
-    utils::{convert_api_call_error, extract_endpoints, parse_headers, PythonIoBase},
+    utils::{
+        convert_api_call_error, extract_endpoints, parse_domain_with_port, parse_headers,
+        PythonIoBase,
+    },
 };
 use anyhow::Result as AnyResult;
-use futures::{lock::Mutex as AsyncMutex, AsyncReadExt};
+use futures::{lock::Mutex as AsyncMutex, AsyncReadExt, StreamExt};
 use maybe_owned::MaybeOwned;
-use pyo3::{exceptions::PyIOError, prelude::*, types::PyBytes};
+use pyo3::{
+    buffer::PyBuffer,
+    exceptions::{PyBufferError, PyIOError, PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
 use std::{
-    collections::HashMap, io::Read, mem::transmute, num::NonZeroU64, sync::Arc, time::Duration,
+    collections::HashMap,
+    fs::File,
+    io::{Error as IoError, Read},
+    mem::transmute,
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
 };
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
@@ -27,6 +45,8 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<DownloadManager>()?;
     m.add_class::<DownloadingObjectReader>()?;
     m.add_class::<AsyncDownloadingObjectReader>()?;
+    m.add_class::<DownloadStream>()?;
+    m.add_class::<AsyncDownloadStream>()?;
     Ok(m)
 }
 
@@ -303,7 +323,10 @@ impl UrlsSigner {
 #[derive(Debug, Clone)]
 #[pyclass(extends = DownloadUrlsGenerator)]
 #[pyo3(text_signature = "(endpoints, use_https=None)")]
-struct StaticDomainsUrlsGenerator;
+struct StaticDomainsUrlsGenerator {
+    endpoints: Vec<qiniu_sdk::http_client::Endpoint>,
+    use_https: Option<bool>,
+}
 
 #[pymethods]
 impl StaticDomainsUrlsGenerator {
@@ -314,7 +337,23 @@ impl StaticDomainsUrlsGenerator {
         use_https: Option<bool>,
     ) -> PyResult<(Self, DownloadUrlsGenerator)> {
         let endpoints = extract_endpoints(endpoints)?;
-        let mut iter = endpoints.into_iter();
+        let generator = Self::build(&endpoints, use_https)?;
+        Ok((
+            Self {
+                endpoints,
+                use_https,
+            },
+            generator,
+        ))
+    }
+}
+
+impl StaticDomainsUrlsGenerator {
+    fn build(
+        endpoints: &[qiniu_sdk::http_client::Endpoint],
+        use_https: Option<bool>,
+    ) -> PyResult<DownloadUrlsGenerator> {
+        let mut iter = endpoints.iter().cloned();
         let mut builder = if let Some(endpoint) = iter.next() {
             qiniu_sdk::download::StaticDomainsUrlsGenerator::builder(endpoint)
         } else {
@@ -326,7 +365,7 @@ impl StaticDomainsUrlsGenerator {
         if let Some(use_https) = use_https {
             builder.use_https(use_https);
         }
-        Ok((Self, DownloadUrlsGenerator(Box::new(builder.build()))))
+        Ok(DownloadUrlsGenerator(Box::new(builder.build())))
     }
 }
 
@@ -353,7 +392,13 @@ impl EndpointsUrlGenerator {
 #[pyclass]
 #[derive(Debug, Clone)]
 #[pyo3(text_signature = "(urls_generator, use_https = None, http_client = None)")]
-struct DownloadManager(qiniu_sdk::download::DownloadManager);
+struct DownloadManager {
+    inner: qiniu_sdk::download::DownloadManager,
+    urls_generator: DownloadUrlsGenerator,
+    http_client: qiniu_sdk::http_client::HttpClient,
+    use_https: Option<bool>,
+    static_domains: Option<StaticDomainsUrlsGenerator>,
+}
 
 #[pymethods]
 impl DownloadManager {
@@ -361,18 +406,124 @@ impl DownloadManager {
     #[new]
     #[args(use_https = "None", http_client = "None")]
     fn new(
-        urls_generator: DownloadUrlsGenerator,
+        urls_generator: &PyAny,
         use_https: Option<bool>,
         http_client: Option<HttpClient>,
-    ) -> Self {
-        let mut builder = qiniu_sdk::download::DownloadManager::builder(urls_generator);
-        if let Some(use_https) = use_https {
-            builder.use_https(use_https);
-        }
-        if let Some(http_client) = http_client {
-            builder.http_client(http_client.into());
-        }
-        Self(builder.build())
+    ) -> PyResult<Self> {
+        let static_domains = urls_generator
+            .downcast::<PyCell<StaticDomainsUrlsGenerator>>()
+            .ok()
+            .map(|generator| generator.borrow().to_owned());
+        Ok(Self::build(
+            urls_generator.extract()?,
+            use_https,
+            http_client.map(Into::into).unwrap_or_default(),
+            static_domains,
+        ))
+    }
+
+    /// 获取下载域名列表
+    ///
+    /// 仅当下载管理器直接使用 `StaticDomainsUrlsGenerator` 创建时才能获取下载域名，否则返回空列表
+    #[pyo3(text_signature = "($self)")]
+    fn list_domains(&self) -> Vec<String> {
+        self.static_domains
+            .iter()
+            .flat_map(|generator| generator.endpoints.iter())
+            .map(|endpoint| endpoint.to_string())
+            .collect()
+    }
+
+    /// 获取首个下载域名
+    ///
+    /// 如果下载域名列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_primary_domain(&self) -> PyResult<String> {
+        self.list_domains()
+            .into_iter()
+            .next()
+            .ok_or_else(|| QiniuEmptyEndpoints::new_err("empty domains"))
+    }
+
+    /// 创建追加了指定下载域名的下载管理器
+    ///
+    /// 如果 `domain` 不是合法的域名，将抛出 `QiniuInvalidDomainWithPortError` 异常
+    #[pyo3(text_signature = "($self, domain)")]
+    fn with_additional_domain(&self, domain: &str) -> PyResult<Self> {
+        let endpoint: qiniu_sdk::http_client::Endpoint = parse_domain_with_port(domain)?.into();
+        let mut endpoints = self.static_domains()?.endpoints.to_owned();
+        endpoints.push(endpoint);
+        self.with_domains(endpoints)
+    }
+
+    /// 创建移除了指定下载域名的下载管理器
+    ///
+    /// 如果下载域名列表中不存在 `domain`，将抛出 `ValueError` 异常
+    #[pyo3(text_signature = "($self, domain)")]
+    fn remove_domain(&self, domain: &str) -> PyResult<Self> {
+        let mut endpoints = self.static_domains()?.endpoints.to_owned();
+        let index = endpoints
+            .iter()
+            .position(|endpoint| endpoint.to_string() == domain)
+            .ok_or_else(|| PyValueError::new_err(format!("domain {:?} is not present", domain)))?;
+        endpoints.remove(index);
+        self.with_domains(endpoints)
+    }
+
+    /// 批量下载对象到指定目录
+    ///
+    /// 每个对象保存为 `dest_dir/key`，`key` 中的 `/` 将作为子目录处理，中间目录会自动创建。
+    /// 最多同时下载 `concurrency` 个对象，部分对象下载失败不会中断其他对象的下载，每个对象完成后都会调用 `on_progress(completed, total)`。
+    /// 返回 `{"succeeded": int, "failed": [{"key": str, "error": str}]}`，写入目标目录失败时直接抛出 `QiniuIoError` 异常
+    #[pyo3(text_signature = "($self, keys, dest_dir, /, concurrency = 4, on_progress = None)")]
+    #[args(concurrency = "4", on_progress = "None")]
+    fn download_batch(
+        &self,
+        keys: Vec<String>,
+        dest_dir: PathBuf,
+        concurrency: usize,
+        on_progress: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let results = py.allow_threads(|| {
+            async_std::task::block_on(download_batch_objects(
+                &self.inner,
+                keys,
+                &dest_dir,
+                concurrency,
+                on_progress,
+            ))
+        })?;
+        convert_download_batch_results(results, py)
+    }
+
+    /// 异步批量下载对象到指定目录
+    ///
+    /// 每个对象保存为 `dest_dir/key`，`key` 中的 `/` 将作为子目录处理，中间目录会自动创建。
+    /// 最多同时下载 `concurrency` 个对象，部分对象下载失败不会中断其他对象的下载，每个对象完成后都会调用 `on_progress(completed, total)`。
+    /// 返回 `{"succeeded": int, "failed": [{"key": str, "error": str}]}`，写入目标目录失败时直接抛出 `QiniuIoError` 异常
+    #[pyo3(text_signature = "($self, keys, dest_dir, /, concurrency = 4, on_progress = None)")]
+    #[args(concurrency = "4", on_progress = "None")]
+    fn async_download_batch<'p>(
+        &self,
+        keys: Vec<String>,
+        dest_dir: PathBuf,
+        concurrency: usize,
+        on_progress: Option<PyObject>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let download_manager = self.inner.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let results = download_batch_objects(
+                &download_manager,
+                keys,
+                &dest_dir,
+                concurrency,
+                on_progress,
+            )
+            .await?;
+            Python::with_gil(|py| convert_download_batch_results(results, py))
+        })
     }
 
     /// 获取下载内容阅读器
@@ -647,8 +798,235 @@ impl DownloadManager {
         })
     }
 
+    /// 校验远程对象的 Etag 是否与预期一致
+    ///
+    /// 仅会发送 HEAD 请求，并从响应头中获取对象的 Etag
+    #[pyo3(text_signature = "($self, key, expected_etag)")]
+    fn verify_download(&self, key: &str, expected_etag: &str, py: Python<'_>) -> PyResult<bool> {
+        let etag = py.allow_threads(|| self.remote_etag(key))?;
+        Ok(etag.as_deref() == Some(expected_etag))
+    }
+
+    /// 异步校验远程对象的 Etag 是否与预期一致
+    ///
+    /// 仅会发送 HEAD 请求，并从响应头中获取对象的 Etag
+    #[pyo3(text_signature = "($self, key, expected_etag)")]
+    fn async_verify_download<'p>(
+        &self,
+        key: String,
+        expected_etag: String,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let download_manager = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let etag = download_manager.async_remote_etag(&key).await?;
+            Ok(etag.as_deref() == Some(expected_etag.as_str()))
+        })
+    }
+
+    /// 计算本地文件的 Etag，并校验是否与远程对象的 Etag 一致
+    #[pyo3(text_signature = "($self, key, local_path)")]
+    fn compute_and_verify(&self, key: &str, local_path: PathBuf, py: Python<'_>) -> PyResult<bool> {
+        py.allow_threads(|| {
+            let local_etag = File::open(&local_path)
+                .and_then(qiniu_sdk::etag::etag_of)
+                .map_err(QiniuIoError::from_err)?;
+            let remote_etag = self.remote_etag(key)?;
+            Ok(remote_etag.as_deref() == Some(local_etag.as_str()))
+        })
+    }
+
+    /// 异步计算本地文件的 Etag，并校验是否与远程对象的 Etag 一致
+    #[pyo3(text_signature = "($self, key, local_path)")]
+    fn async_compute_and_verify<'p>(
+        &self,
+        key: String,
+        local_path: PathBuf,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let download_manager = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let file = async_std::fs::File::open(&local_path)
+                .await
+                .map_err(QiniuIoError::from_err)?;
+            let local_etag = qiniu_sdk::etag::async_etag_of(file)
+                .await
+                .map_err(QiniuIoError::from_err)?;
+            let remote_etag = download_manager.async_remote_etag(&key).await?;
+            Ok(remote_etag.as_deref() == Some(local_etag.as_str()))
+        })
+    }
+
+    /// 获取对象元信息，不会下载对象内容
+    ///
+    /// 返回的字典包含 `etag`，`content_type`，`content_length` 和 `last_modified`（ISO 8601 格式）。
+    /// 如果对象不存在，将抛出 `QiniuDownloadError` 异常
+    #[pyo3(text_signature = "($self, key)")]
+    fn head_object(&self, key: &str, py: Python<'_>) -> PyResult<PyObject> {
+        let urls = self
+            .urls_generator
+            .0
+            .generate(
+                key,
+                qiniu_sdk::download::GeneratorOptions::builder().build(),
+            )
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        let metadata = py.allow_threads(|| {
+            let http_caller = self.http_client.http_caller();
+            let mut last_error = None;
+            for url in urls {
+                let mut request = make_head_request(url, Default::default());
+                match http_caller.call(&mut request) {
+                    Ok(response) => {
+                        return ObjectMetadata::from_response(
+                            response.status_code(),
+                            response.headers(),
+                        )
+                    }
+                    Err(err) => last_error = Some(err),
+                }
+            }
+            Err(make_head_object_error(last_error))
+        })?;
+        metadata.into_py_dict(py)
+    }
+
+    /// 异步获取对象元信息，不会下载对象内容
+    ///
+    /// 返回的字典包含 `etag`，`content_type`，`content_length` 和 `last_modified`（ISO 8601 格式）。
+    /// 如果对象不存在，将抛出 `QiniuDownloadError` 异常
+    #[pyo3(text_signature = "($self, key)")]
+    fn async_head_object<'p>(&self, key: String, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let download_manager = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let urls = download_manager
+                .urls_generator
+                .0
+                .async_generate(
+                    &key,
+                    qiniu_sdk::download::GeneratorOptions::builder().build(),
+                )
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let http_caller = download_manager.http_client.http_caller();
+            let mut last_error = None;
+            for url in urls {
+                let mut request = make_head_request(url, Default::default());
+                match http_caller.async_call(&mut request).await {
+                    Ok(response) => {
+                        let metadata = ObjectMetadata::from_response(
+                            response.status_code(),
+                            response.headers(),
+                        )?;
+                        return Python::with_gil(|py| metadata.into_py_dict(py));
+                    }
+                    Err(err) => last_error = Some(err),
+                }
+            }
+            Err(make_head_object_error(last_error))
+        })
+    }
+
+    /// 下载对象指定范围的内容
+    ///
+    /// `start` 和 `end` 均为闭区间的字节偏移量，必须满足 `0 <= start <= end`，否则将抛出 `ValueError` 异常。
+    /// 如果范围无法满足，将抛出 `QiniuDownloadError` 异常
+    #[pyo3(text_signature = "($self, key, start, end)")]
+    fn download_range<'p>(
+        &self,
+        key: &str,
+        start: i64,
+        end: i64,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyBytes> {
+        let object = self.make_range_object(key, make_range_header(start, end)?)?;
+        let buf = py.allow_threads(|| {
+            let mut buf = Vec::new();
+            object
+                .to_writer(&mut buf)
+                .map_err(QiniuDownloadError::from_err)?;
+            Ok::<_, PyErr>(buf)
+        })?;
+        Ok(PyBytes::new(py, &buf))
+    }
+
+    /// 异步下载对象指定范围的内容
+    ///
+    /// `start` 和 `end` 均为闭区间的字节偏移量，必须满足 `0 <= start <= end`，否则将抛出 `ValueError` 异常。
+    /// 如果范围无法满足，将抛出 `QiniuDownloadError` 异常
+    #[pyo3(text_signature = "($self, key, start, end)")]
+    fn async_download_range<'p>(
+        &self,
+        key: &str,
+        start: i64,
+        end: i64,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let object = self.make_range_object(key, make_range_header(start, end)?)?;
+        async_download_to_bytes(object, py)
+    }
+
+    /// 下载对象末尾指定字节数的内容
+    ///
+    /// `last_n_bytes` 必须大于 0，否则将抛出 `ValueError` 异常
+    #[pyo3(text_signature = "($self, key, last_n_bytes)")]
+    fn download_suffix<'p>(
+        &self,
+        key: &str,
+        last_n_bytes: i64,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyBytes> {
+        let object = self.make_range_object(key, make_suffix_range_header(last_n_bytes)?)?;
+        let buf = py.allow_threads(|| {
+            let mut buf = Vec::new();
+            object
+                .to_writer(&mut buf)
+                .map_err(QiniuDownloadError::from_err)?;
+            Ok::<_, PyErr>(buf)
+        })?;
+        Ok(PyBytes::new(py, &buf))
+    }
+
+    /// 异步下载对象末尾指定字节数的内容
+    ///
+    /// `last_n_bytes` 必须大于 0，否则将抛出 `ValueError` 异常
+    #[pyo3(text_signature = "($self, key, last_n_bytes)")]
+    fn async_download_suffix<'p>(
+        &self,
+        key: &str,
+        last_n_bytes: i64,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let object = self.make_range_object(key, make_suffix_range_header(last_n_bytes)?)?;
+        async_download_to_bytes(object, py)
+    }
+
+    /// 获取下载流
+    ///
+    /// 返回的下载流实现了 Python 文件对象的读取接口，可以直接传递给接受文件对象的库
+    #[pyo3(text_signature = "($self, key)")]
+    fn stream_download(&self, key: &str) -> PyResult<DownloadStream> {
+        let object = self
+            .inner
+            .download(key)
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        Ok(DownloadStream(Some(object.into_read())))
+    }
+
+    /// 获取异步下载流
+    #[pyo3(text_signature = "($self, key)")]
+    fn async_stream_download(&self, key: &str) -> PyResult<AsyncDownloadStream> {
+        let object = self
+            .inner
+            .download(key)
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        Ok(AsyncDownloadStream(Arc::new(AsyncMutex::new(Some(
+            object.into_async_read(),
+        )))))
+    }
+
     fn __repr__(&self) -> String {
-        format!("{:?}", self.0)
+        format!("{:?}", self.inner)
     }
 
     fn __str__(&self) -> String {
@@ -738,7 +1116,526 @@ impl AsyncDownloadingObjectReader {
     }
 }
 
+/// 下载流读取时预先分配的缓冲区上限，避免按调用方传入的 `size` 一次性分配过大的内存
+const MAX_STREAM_READ_RESERVE_SIZE: u64 = 64 * 1024;
+
+/// 下载流
+///
+/// 通过 `DownloadManager.stream_download()` 获取，关闭后将断开下载连接
+#[pyclass]
+#[derive(Debug)]
+struct DownloadStream(Option<qiniu_sdk::download::DownloadingObjectReader>);
+
+#[pymethods]
+impl DownloadStream {
+    /// 读取下载的数据
+    #[pyo3(text_signature = "($self, size = -1, /)")]
+    #[args(size = "-1")]
+    fn read<'a>(&mut self, size: i64, py: Python<'a>) -> PyResult<&'a PyBytes> {
+        let reader = self.reader()?;
+        let mut buf = Vec::new();
+        py.allow_threads(|| {
+            if let Ok(size) = u64::try_from(size) {
+                buf.reserve(size.min(MAX_STREAM_READ_RESERVE_SIZE) as usize);
+                reader.take(size).read_to_end(&mut buf)
+            } else {
+                reader.read_to_end(&mut buf)
+            }
+            .map_err(convert_download_stream_error)
+        })?;
+        Ok(PyBytes::new(py, &buf))
+    }
+
+    /// 读取所有下载的数据
+    #[pyo3(text_signature = "($self)")]
+    fn readall<'a>(&mut self, py: Python<'a>) -> PyResult<&'a PyBytes> {
+        self.read(-1, py)
+    }
+
+    /// 读取下载的数据到指定的缓冲区，返回读取的字节数
+    ///
+    /// `buffer` 可以是任何支持缓冲区协议的可写连续字节缓冲区，例如 `bytearray` 或 `memoryview`，
+    /// 否则将抛出 `TypeError` 异常。如果读取期间缓冲区被缩小，将抛出 `BufferError` 异常
+    #[pyo3(text_signature = "($self, buffer, /)")]
+    fn readinto(&mut self, buffer: PyBuffer<u8>, py: Python<'_>) -> PyResult<usize> {
+        if buffer.readonly() || !buffer.is_c_contiguous() {
+            return Err(PyTypeError::new_err(
+                "buffer must be a writable contiguous bytes-like object",
+            ));
+        }
+        let reader = self.reader()?;
+        let mut buf = vec![0u8; buffer.item_count()];
+        let have_read =
+            py.allow_threads(|| reader.read(&mut buf).map_err(convert_download_stream_error))?;
+        let cells = buffer.as_mut_slice(py).ok_or_else(|| {
+            PyTypeError::new_err("buffer must be a writable contiguous bytes-like object")
+        })?;
+        if cells.len() < have_read {
+            return Err(PyBufferError::new_err(
+                "buffer was shrunk while reading data into it",
+            ));
+        }
+        for (cell, &byte) in cells.iter().zip(&buf[..have_read]) {
+            cell.set(byte);
+        }
+        Ok(have_read)
+    }
+
+    /// 是否可读
+    #[pyo3(text_signature = "($self)")]
+    fn readable(&self) -> bool {
+        true
+    }
+
+    /// 是否已经关闭
+    #[getter]
+    fn get_closed(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// 关闭下载流
+    ///
+    /// 可以重复调用
+    #[pyo3(text_signature = "($self)")]
+    fn close(&mut self) {
+        self.0 = None;
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) {
+        self.close();
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl DownloadStream {
+    fn reader(&mut self) -> PyResult<&mut qiniu_sdk::download::DownloadingObjectReader> {
+        self.0
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("I/O operation on closed stream"))
+    }
+}
+
+/// 异步下载流
+///
+/// 通过 `DownloadManager.async_stream_download()` 获取，关闭后将断开下载连接
+#[pyclass]
+#[derive(Debug)]
+struct AsyncDownloadStream(
+    Arc<AsyncMutex<Option<qiniu_sdk::download::AsyncDownloadingObjectReader>>>,
+);
+
+#[pymethods]
+impl AsyncDownloadStream {
+    /// 异步读取下载的数据
+    #[pyo3(text_signature = "($self, size = -1, /)")]
+    #[args(size = "-1")]
+    fn read<'a>(&self, size: i64, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let reader = self.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut reader = reader.lock().await;
+            let reader = reader
+                .as_mut()
+                .ok_or_else(|| PyValueError::new_err("I/O operation on closed stream"))?;
+            let mut buf = Vec::new();
+            if let Ok(size) = u64::try_from(size) {
+                buf.reserve(size.min(MAX_STREAM_READ_RESERVE_SIZE) as usize);
+                reader.take(size).read_to_end(&mut buf).await
+            } else {
+                reader.read_to_end(&mut buf).await
+            }
+            .map_err(convert_download_stream_error)?;
+            Python::with_gil(|py| Ok(PyBytes::new(py, &buf).to_object(py)))
+        })
+    }
+
+    /// 异步读取所有下载的数据
+    #[pyo3(text_signature = "($self)")]
+    fn readall<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        self.read(-1, py)
+    }
+
+    /// 异步关闭下载流
+    ///
+    /// 可以重复调用
+    #[pyo3(text_signature = "($self)")]
+    fn close<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let reader = self.0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            reader.lock().await.take();
+            Ok(())
+        })
+    }
+
+    fn __aenter__<'a>(slf: PyRef<'_, Self>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let slf: PyObject = slf.into_py(py);
+        pyo3_asyncio::async_std::future_into_py(py, async move { Ok(slf) })
+    }
+
+    fn __aexit__<'a>(
+        &self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+        py: Python<'a>,
+    ) -> PyResult<&'a PyAny> {
+        self.close(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+fn make_head_request<B>(
+    url: qiniu_sdk::http::Uri,
+    body: B,
+) -> qiniu_sdk::http::Request<'static, B> {
+    let mut builder = qiniu_sdk::http::RequestParts::builder();
+    builder.url(url);
+    builder.method(qiniu_sdk::http::Method::HEAD);
+    qiniu_sdk::http::Request::from_parts_and_body(builder.build(), body)
+}
+
+struct ObjectMetadata {
+    etag: Option<String>,
+    content_type: Option<String>,
+    content_length: Option<u64>,
+    last_modified: Option<String>,
+}
+
+impl ObjectMetadata {
+    fn from_response(
+        status_code: qiniu_sdk::http::StatusCode,
+        headers: &qiniu_sdk::http::HeaderMap,
+    ) -> PyResult<Self> {
+        if !status_code.is_success() {
+            let err = qiniu_sdk::http_client::ResponseError::new(
+                qiniu_sdk::http_client::ResponseErrorKind::StatusCodeError(status_code),
+                format!("Failed to head object, status code: {}", status_code),
+            );
+            return Err(if status_code == qiniu_sdk::http::StatusCode::NOT_FOUND {
+                QiniuDownloadError::from_err(err.into())
+            } else {
+                QiniuApiCallError::from_err(MaybeOwned::Owned(err))
+            });
+        }
+        let header_value = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.to_owned())
+        };
+        Ok(Self {
+            etag: header_value("x-qiniu-hash")
+                .or_else(|| header_value("etag"))
+                .map(|etag| etag.trim_matches('"').to_owned()),
+            content_type: header_value("content-type"),
+            content_length: header_value("content-length").and_then(|len| len.parse().ok()),
+            last_modified: header_value("last-modified")
+                .map(|date| convert_http_date_to_iso8601(&date).unwrap_or(date)),
+        })
+    }
+
+    fn into_py_dict(self, py: Python<'_>) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        dict.set_item("etag", self.etag)?;
+        dict.set_item("content_type", self.content_type)?;
+        dict.set_item("content_length", self.content_length)?;
+        dict.set_item("last_modified", self.last_modified)?;
+        Ok(dict.into_py(py))
+    }
+}
+
+fn etag_from_head_response(
+    status_code: qiniu_sdk::http::StatusCode,
+    headers: &qiniu_sdk::http::HeaderMap,
+) -> PyResult<Option<String>> {
+    if !status_code.is_success() {
+        let err = qiniu_sdk::http_client::ResponseError::new(
+            qiniu_sdk::http_client::ResponseErrorKind::StatusCodeError(status_code),
+            format!("Failed to head object, status code: {}", status_code),
+        );
+        return Err(QiniuDownloadError::from_err(err.into()));
+    }
+    Ok(ObjectMetadata::from_response(status_code, headers)?.etag)
+}
+
+fn make_remote_etag_error(err: Option<qiniu_sdk::http::ResponseError>) -> PyErr {
+    let err = if let Some(err) = err {
+        qiniu_sdk::http_client::ResponseError::from(err)
+    } else {
+        qiniu_sdk::http_client::ResponseError::new(
+            qiniu_sdk::http_client::ResponseErrorKind::NoTry,
+            "No download url is generated",
+        )
+    };
+    QiniuDownloadError::from_err(err.into())
+}
+
+fn make_head_object_error(err: Option<qiniu_sdk::http::ResponseError>) -> PyErr {
+    if let Some(err) = err {
+        QiniuHttpCallError::from_err(err)
+    } else {
+        QiniuEmptyEndpoints::new_err("No download url is generated")
+    }
+}
+
+/// 将 HTTP 日期（例如 `Sun, 06 Nov 1994 08:49:37 GMT`）转换为 ISO 8601 格式
+fn convert_http_date_to_iso8601(date: &str) -> Option<String> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut parts = date.split_once(", ")?.1.split(' ');
+    let day: u8 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTHS.iter().position(|&m| m == month)? + 1;
+    let year: u16 = parts.next()?.parse().ok()?;
+    let time = parts.next()?;
+    if parts.next()? != "GMT" || time.len() != 8 {
+        return None;
+    }
+    Some(format!("{:04}-{:02}-{:02}T{}Z", year, month, day, time))
+}
+
+fn convert_download_stream_error(err: IoError) -> PyErr {
+    if err.get_ref().map_or(false, |inner| {
+        inner.is::<qiniu_sdk::download::DownloadError>()
+    }) {
+        if let Some(Ok(err)) = err
+            .into_inner()
+            .map(|inner| inner.downcast::<qiniu_sdk::download::DownloadError>())
+        {
+            return QiniuDownloadError::from_err(*err);
+        }
+        unreachable!("inner error must be DownloadError");
+    }
+    QiniuIoError::from_err(err)
+}
+
+async fn download_batch_objects(
+    download_manager: &qiniu_sdk::download::DownloadManager,
+    keys: Vec<String>,
+    dest_dir: &Path,
+    concurrency: usize,
+    on_progress: Option<PyObject>,
+) -> PyResult<Vec<(String, PyResult<()>)>> {
+    let total = keys.len();
+    let mut downloads = futures::stream::iter(keys)
+        .map(|key| async move {
+            let result = download_batch_object(download_manager, &key, dest_dir).await;
+            (key, result)
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut results = Vec::with_capacity(total);
+    while let Some((key, result)) = downloads.next().await {
+        results.push((key, result?));
+        if let Some(on_progress) = &on_progress {
+            Python::with_gil(|py| on_progress.call1(py, (results.len(), total)))?;
+        }
+    }
+    Ok(results)
+}
+
+/// 下载单个对象，外层错误为目标路径的 IO 错误，将中断整个批量下载，内层错误为下载错误
+async fn download_batch_object(
+    download_manager: &qiniu_sdk::download::DownloadManager,
+    key: &str,
+    dest_dir: &Path,
+) -> PyResult<PyResult<()>> {
+    let path = if let Some(path) = make_download_batch_path(dest_dir, key) {
+        path
+    } else {
+        return Ok(Err(PyValueError::new_err(format!(
+            "key {:?} can't be saved under the destination directory",
+            key
+        ))));
+    };
+    if let Some(parent) = path.parent() {
+        async_std::fs::create_dir_all(parent)
+            .await
+            .map_err(QiniuIoError::from_err)?;
+    }
+    let mut file = async_std::fs::File::create(&path)
+        .await
+        .map_err(QiniuIoError::from_err)?;
+    let result = match download_manager.download(key) {
+        Ok(object) => object
+            .to_async_writer(&mut file)
+            .await
+            .map_err(QiniuDownloadError::from_err),
+        Err(err) => Err(QiniuApiCallError::from_err(MaybeOwned::Owned(err))),
+    };
+    if result.is_err() {
+        drop(file);
+        async_std::fs::remove_file(&path).await.ok();
+    }
+    Ok(result)
+}
+
+fn make_download_batch_path(dest_dir: &Path, key: &str) -> Option<PathBuf> {
+    let mut path = dest_dir.to_owned();
+    let mut has_file_name = false;
+    for component in key.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => return None,
+            component => {
+                path.push(component);
+                has_file_name = true;
+            }
+        }
+    }
+    if has_file_name && !key.ends_with('/') {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+fn convert_download_batch_results(
+    results: Vec<(String, PyResult<()>)>,
+    py: Python<'_>,
+) -> PyResult<PyObject> {
+    let mut succeeded = 0usize;
+    let mut failed = Vec::new();
+    for (key, result) in results {
+        match result {
+            Ok(()) => succeeded += 1,
+            Err(err) => {
+                let failure = PyDict::new(py);
+                failure.set_item("key", key)?;
+                failure.set_item("error", err.value(py).str()?)?;
+                failed.push(failure);
+            }
+        }
+    }
+    let summary = PyDict::new(py);
+    summary.set_item("succeeded", succeeded)?;
+    summary.set_item("failed", failed)?;
+    Ok(summary.into_py(py))
+}
+
 impl DownloadManager {
+    fn build(
+        urls_generator: DownloadUrlsGenerator,
+        use_https: Option<bool>,
+        http_client: qiniu_sdk::http_client::HttpClient,
+        static_domains: Option<StaticDomainsUrlsGenerator>,
+    ) -> Self {
+        let mut builder = qiniu_sdk::download::DownloadManager::builder(urls_generator.to_owned());
+        if let Some(use_https) = use_https {
+            builder.use_https(use_https);
+        }
+        builder.http_client(http_client.to_owned());
+        Self {
+            inner: builder.build(),
+            urls_generator,
+            http_client,
+            use_https,
+            static_domains,
+        }
+    }
+
+    fn static_domains(&self) -> PyResult<&StaticDomainsUrlsGenerator> {
+        self.static_domains.as_ref().ok_or_else(|| {
+            PyValueError::new_err("download manager is not created with StaticDomainsUrlsGenerator")
+        })
+    }
+
+    fn with_domains(&self, endpoints: Vec<qiniu_sdk::http_client::Endpoint>) -> PyResult<Self> {
+        let use_https = self.static_domains()?.use_https;
+        let urls_generator = StaticDomainsUrlsGenerator::build(&endpoints, use_https)?;
+        Ok(Self::build(
+            urls_generator,
+            self.use_https,
+            self.http_client.to_owned(),
+            Some(StaticDomainsUrlsGenerator {
+                endpoints,
+                use_https,
+            }),
+        ))
+    }
+
+    fn remote_etag(&self, key: &str) -> PyResult<Option<String>> {
+        let urls = self
+            .urls_generator
+            .0
+            .generate(
+                key,
+                qiniu_sdk::download::GeneratorOptions::builder().build(),
+            )
+            .map_err(|err| QiniuDownloadError::from_err(err.into()))?;
+        let http_caller = self.http_client.http_caller();
+        let mut last_error = None;
+        for url in urls {
+            let mut request = make_head_request(url, Default::default());
+            match http_caller.call(&mut request) {
+                Ok(response) => {
+                    return etag_from_head_response(response.status_code(), response.headers())
+                }
+                Err(err) => last_error = Some(err),
+            }
+        }
+        Err(make_remote_etag_error(last_error))
+    }
+
+    async fn async_remote_etag(&self, key: &str) -> PyResult<Option<String>> {
+        let urls = self
+            .urls_generator
+            .0
+            .async_generate(
+                key,
+                qiniu_sdk::download::GeneratorOptions::builder().build(),
+            )
+            .await
+            .map_err(|err| QiniuDownloadError::from_err(err.into()))?;
+        let http_caller = self.http_client.http_caller();
+        let mut last_error = None;
+        for url in urls {
+            let mut request = make_head_request(url, Default::default());
+            match http_caller.async_call(&mut request).await {
+                Ok(response) => {
+                    return etag_from_head_response(response.status_code(), response.headers())
+                }
+                Err(err) => last_error = Some(err),
+            }
+        }
+        Err(make_remote_etag_error(last_error))
+    }
+
+    fn make_range_object(
+        &self,
+        key: &str,
+        range: String,
+    ) -> PyResult<qiniu_sdk::download::DownloadingObject> {
+        let object = self
+            .inner
+            .download(key)
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .headers(parse_headers(HashMap::from([("Range".to_owned(), range)]))?);
+        Ok(object)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn make_download_object(
         &self,
@@ -753,7 +1650,7 @@ impl DownloadManager {
         response_error: Option<PyObject>,
     ) -> PyResult<qiniu_sdk::download::DownloadingObject> {
         let mut object = self
-            .0
+            .inner
             .download(object_name)
             .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
         if let Some(range_from) = range_from {
@@ -788,6 +1685,40 @@ impl DownloadManager {
     }
 }
 
+fn make_range_header(start: i64, end: i64) -> PyResult<String> {
+    if start < 0 || start > end {
+        return Err(PyValueError::new_err(format!(
+            "invalid range: start={}, end={}",
+            start, end
+        )));
+    }
+    Ok(format!("bytes={}-{}", start, end))
+}
+
+fn make_suffix_range_header(last_n_bytes: i64) -> PyResult<String> {
+    if last_n_bytes <= 0 {
+        return Err(PyValueError::new_err(format!(
+            "invalid suffix length: {}",
+            last_n_bytes
+        )));
+    }
+    Ok(format!("bytes=-{}", last_n_bytes))
+}
+
+fn async_download_to_bytes(
+    object: qiniu_sdk::download::DownloadingObject,
+    py: Python<'_>,
+) -> PyResult<&PyAny> {
+    pyo3_asyncio::async_std::future_into_py(py, async move {
+        let mut buf = Vec::new();
+        object
+            .to_async_writer(&mut buf)
+            .await
+            .map_err(QiniuDownloadError::from_err)?;
+        Python::with_gil(|py| Ok(PyBytes::new(py, &buf).to_object(py)))
+    })
+}
+
 fn on_before_request(
     callback: PyObject,
 ) -> impl Fn(&mut qiniu_sdk::http_client::RequestBuilderParts<'_>) -> AnyResult<()> + Send + Sync + 'static
@@ -1,6 +1,11 @@
-use super::utils::PythonIoBase;
-use pyo3::prelude::*;
+use super::{
+    exceptions::{QiniuBase64Error, QiniuIoError},
+    utils::PythonIoBase,
+};
+use pyo3::{prelude::*, types::PyBytes};
 use qiniu_sdk::etag::{FixedOutput, GenericArray, Reset, Update, ETAG_SIZE};
+use sha1::{Digest, Sha1};
+use std::io::{Error as IoError, ErrorKind as IoErrorKind};
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "etag")?;
@@ -9,10 +14,13 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<EtagV2>()?;
     m.add_class::<Etag>()?;
     m.add_class::<EtagVersion>()?;
+    m.add_class::<EtagReader>()?;
     m.add_function(wrap_pyfunction!(etag_of, m)?)?;
     m.add_function(wrap_pyfunction!(etag_with_parts, m)?)?;
     m.add_function(wrap_pyfunction!(async_etag_of, m)?)?;
     m.add_function(wrap_pyfunction!(async_etag_with_parts, m)?)?;
+    m.add_function(wrap_pyfunction!(etag_of_parts, m)?)?;
+    m.add_function(wrap_pyfunction!(etag_of_part_etags, m)?)?;
     Ok(m)
 }
 
@@ -115,6 +123,123 @@ impl From<EtagVersion> for qiniu_sdk::etag::EtagVersion {
     }
 }
 
+/// Etag 读取器
+///
+/// 包装 Python 文件对象，在读取数据的同时计算 Etag V1。
+/// 可以作为上下文管理器使用，正常退出时将自动调用 `finalize()`，并且总是关闭文件对象
+#[pyclass]
+#[pyo3(text_signature = "(io_base)")]
+struct EtagReader {
+    io_base: PyObject,
+    etag: qiniu_sdk::etag::EtagV1,
+    result: Option<String>,
+}
+
+#[pymethods]
+impl EtagReader {
+    /// 创建 Etag 读取器
+    #[new]
+    fn new(io_base: PyObject) -> Self {
+        Self {
+            io_base,
+            etag: qiniu_sdk::etag::EtagV1::new(),
+            result: None,
+        }
+    }
+
+    /// 读取数据，同时更新 Etag
+    #[pyo3(text_signature = "($self, size = -1)")]
+    #[args(size = "-1")]
+    fn read<'p>(&mut self, size: i64, py: Python<'p>) -> PyResult<&'p PyBytes> {
+        let data: &PyBytes = self
+            .io_base
+            .call_method1(py, "read", (size,))?
+            .into_ref(py)
+            .downcast()?;
+        self.etag.update(data.as_bytes());
+        Ok(data)
+    }
+
+    /// 读取剩余的全部数据，获取 Etag 计算结果
+    ///
+    /// 重复调用将返回相同的结果
+    #[pyo3(text_signature = "($self)")]
+    fn finalize(&mut self, py: Python<'_>) -> PyResult<String> {
+        if let Some(result) = &self.result {
+            return Ok(result.to_owned());
+        }
+        while !self.read(1 << 16, py)?.as_bytes().is_empty() {}
+        let mut buf =
+            GenericArray::<u8, <qiniu_sdk::etag::EtagV1 as FixedOutput>::OutputSize>::default();
+        self.etag.finalize_into_reset(&mut buf);
+        let result = String::from_utf8(buf.to_vec()).unwrap();
+        self.result = Some(result.to_owned());
+        Ok(result)
+    }
+
+    /// 获取 Etag 计算结果，如果尚未调用 `finalize()` 则返回 `None`
+    #[getter]
+    fn get_etag(&self) -> Option<String> {
+        self.result.to_owned()
+    }
+
+    /// 关闭文件对象
+    ///
+    /// 如果文件对象关闭失败，将抛出 `QiniuIoError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn close(&self, py: Python<'_>) -> PyResult<()> {
+        self.io_base
+            .call_method0(py, "close")
+            .map_err(|err| QiniuIoError::from_err(IoError::new(IoErrorKind::Other, err)))?;
+        Ok(())
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+        py: Python<'_>,
+    ) -> PyResult<bool> {
+        let result = if exc_type.map_or(true, |exc_type| exc_type.is_none()) {
+            self.finalize(py).map(|_| ())
+        } else {
+            Ok(())
+        };
+        self.close(py)?;
+        result?;
+        Ok(false)
+    }
+
+    fn __aenter__<'a>(slf: PyRef<'_, Self>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let slf: PyObject = slf.into_py(py);
+        pyo3_asyncio::async_std::future_into_py(py, async move { Ok(slf) })
+    }
+
+    fn __aexit__<'a>(
+        &mut self,
+        exc_type: Option<&PyAny>,
+        exc_value: Option<&PyAny>,
+        traceback: Option<&PyAny>,
+        py: Python<'a>,
+    ) -> PyResult<&'a PyAny> {
+        let suppressed = self.__exit__(exc_type, exc_value, traceback, py)?;
+        pyo3_asyncio::async_std::future_into_py(py, async move { Ok(suppressed) })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("EtagReader({:?})", self.etag)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
 /// 读取 reader 中的数据并计算它的 Etag V1，生成结果
 #[pyfunction]
 #[pyo3(text_signature = "(io_base)")]
@@ -155,3 +280,62 @@ fn async_etag_with_parts(io_base: PyObject, parts: Vec<usize>, py: Python<'_>) -
         Ok(etag)
     })
 }
+
+/// 根据给出的分片数据计算 Etag V2，生成结果
+///
+/// 除最后一个分片外，每个分片的尺寸应该是 4 MB 的整数倍（默认为 4 MB），
+/// 与分片上传 API 的 `partSize` 参数保持一致，才能得到与七牛服务端一致的 Etag
+#[pyfunction]
+#[pyo3(text_signature = "(parts)")]
+fn etag_of_parts(parts: Vec<Vec<u8>>) -> PyResult<String> {
+    if parts.is_empty() {
+        return Err(empty_parts_error());
+    }
+    let mut etag = qiniu_sdk::etag::EtagV2::new();
+    for part in parts.iter() {
+        etag.update(part);
+    }
+    let mut buf =
+        GenericArray::<u8, <qiniu_sdk::etag::EtagV2 as FixedOutput>::OutputSize>::default();
+    etag.finalize_into_reset(&mut buf);
+    Ok(String::from_utf8(buf.to_vec()).unwrap())
+}
+
+/// 根据给出的每个分片的 Etag 计算 Etag V2，生成结果
+///
+/// 除最后一个分片外，每个分片的尺寸应该是 4 MB 的整数倍（默认为 4 MB），
+/// 与分片上传 API 的 `partSize` 参数保持一致，才能得到与七牛服务端一致的 Etag
+#[pyfunction]
+#[pyo3(text_signature = "(part_etags)")]
+fn etag_of_part_etags(part_etags: Vec<String>) -> PyResult<String> {
+    const ETAG_V2_PREFIX: u8 = 0x9e;
+
+    match part_etags.len() {
+        0 => Err(empty_parts_error()),
+        1 => Ok(part_etags.into_iter().next().unwrap()),
+        _ => {
+            let mut sha1 = Sha1::new();
+            for part_etag in part_etags.iter() {
+                let decoded = qiniu_sdk::utils::base64::decode(part_etag.as_bytes())
+                    .map_err(QiniuBase64Error::from_err)?;
+                if part_etag.len() != ETAG_SIZE || decoded.len() != 21 {
+                    return Err(QiniuIoError::from_err(IoError::new(
+                        IoErrorKind::InvalidInput,
+                        format!("Invalid part etag: {}", part_etag),
+                    )));
+                }
+                sha1.update(&decoded[1..]);
+            }
+            let mut buf = vec![ETAG_V2_PREFIX];
+            buf.extend_from_slice(&sha1.finalize());
+            Ok(qiniu_sdk::utils::base64::urlsafe(&buf))
+        }
+    }
+}
+
+fn empty_parts_error() -> PyErr {
+    QiniuIoError::from_err(IoError::new(
+        IoErrorKind::InvalidInput,
+        "parts must not be empty",
+    ))
+}
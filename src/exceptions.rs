@@ -1,3 +1,4 @@
+use super::utils::convert_json_value_to_py_object;
 use maybe_owned::MaybeOwned;
 use pyo3::{
     create_exception,
@@ -60,6 +61,14 @@ pub(super) fn register(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         "QiniuInvalidSourceKeyLengthError",
         py.get_type::<QiniuInvalidSourceKeyLengthError>(),
     )?;
+    m.add(
+        "QiniuInvalidTagError",
+        py.get_type::<QiniuInvalidTagError>(),
+    )?;
+    m.add(
+        "QiniuInvalidServiceNameError",
+        py.get_type::<QiniuInvalidServiceNameError>(),
+    )?;
 
     QiniuInvalidURLError::register(py, m)?;
     QiniuInvalidStatusCodeError::register(py, m)?;
@@ -90,6 +99,18 @@ pub(super) fn register(py: Python<'_>, m: &PyModule) -> PyResult<()> {
 
 macro_rules! create_exception_with_info {
     ($module: ident, $name: ident, $name_str: literal, $base: ty, $inner_name: ident, $inner_type:ty, $doc: expr) => {
+        create_exception_with_info!(
+            $module,
+            $name,
+            $name_str,
+            $base,
+            $inner_name,
+            $inner_type,
+            $doc,
+            |err| format!("message={}", py_repr_str(&err.to_string()))
+        );
+    };
+    ($module: ident, $name: ident, $name_str: literal, $base: ty, $inner_name: ident, $inner_type:ty, $doc: expr, |$err: ident| $fields: expr) => {
         create_exception!($module, $name, $base, $doc);
 
         #[pyclass]
@@ -99,7 +120,8 @@ macro_rules! create_exception_with_info {
         #[pymethods]
         impl $inner_name {
             fn __repr__(&self) -> String {
-                format!("{:?}", self.0)
+                let $err: &$inner_type = &self.0;
+                format!("{}({})", stringify!($inner_name), $fields)
             }
 
             fn __str__(&self) -> String {
@@ -218,6 +240,18 @@ create_exception!(
     PyValueError,
     "七牛数据源 KEY 长度错误"
 );
+create_exception!(
+    qiniu_sdk_bindings,
+    QiniuInvalidTagError,
+    PyValueError,
+    "七牛对象标签错误"
+);
+create_exception!(
+    qiniu_sdk_bindings,
+    QiniuInvalidServiceNameError,
+    PyValueError,
+    "七牛非法名称错误"
+);
 create_exception_with_info!(
     qiniu_sdk_bindings,
     QiniuCallbackError,
@@ -387,7 +421,12 @@ create_exception_with_info!(
     PyIOError,
     QiniuIoErrorInfo,
     IoError,
-    "七牛本地 IO 错误"
+    "七牛本地 IO 错误",
+    |err| format!(
+        "kind={:?}, message={}",
+        err.kind(),
+        py_repr_str(&err.to_string())
+    )
 );
 create_exception_with_info!(
     qiniu_sdk_bindings,
@@ -405,7 +444,8 @@ create_exception_with_info!(
     PyIOError,
     QiniuApiCallErrorInfo,
     MaybeOwned<'static, qiniu_sdk::http_client::ResponseError>,
-    "七牛 API 调用错误"
+    "七牛 API 调用错误",
+    |err| api_call_error_repr_fields(err)
 );
 create_exception_with_info!(
     qiniu_sdk_bindings,
@@ -435,3 +475,86 @@ create_exception_with_info!(
     qiniu_sdk::http_client::PrefixLenError,
     "七牛子网掩码前缀长度异常"
 );
+
+#[pymethods]
+impl QiniuApiCallErrorInfo {
+    /// 获取错误响应体
+    ///
+    /// 如果不是状态码错误，将返回 `None`，如果响应体不是合法的 JSON，将抛出 `QiniuJsonError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn response_body(&self) -> PyResult<Option<PyObject>> {
+        use qiniu_sdk::http_client::ResponseErrorKind;
+
+        let text = if let Some(text) = self.response_body_text() {
+            text
+        } else {
+            return Ok(None);
+        };
+        let value = match self.0.kind() {
+            // SDK 在解析状态码错误时仅保留响应体中的 `error` 字段
+            ResponseErrorKind::StatusCodeError(_) => serde_json::json!({ "error": text }),
+            _ => serde_json::from_str(&text).map_err(QiniuJsonError::from_err)?,
+        };
+        convert_json_value_to_py_object(&value).map(Some)
+    }
+
+    /// 获取错误响应体文本
+    ///
+    /// 如果不是状态码错误，将返回 `None`
+    #[pyo3(text_signature = "($self)")]
+    fn response_body_text(&self) -> Option<String> {
+        use qiniu_sdk::http_client::ResponseErrorKind;
+
+        let err: &qiniu_sdk::http_client::ResponseError = &self.0;
+        match err.kind() {
+            ResponseErrorKind::StatusCodeError(_) | ResponseErrorKind::UnexpectedStatusCode(_) => {
+                Some(
+                    std::error::Error::source(err)
+                        .map(|source| source.to_string())
+                        .unwrap_or_else(|| err.to_string()),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+fn api_call_error_repr_fields(err: &qiniu_sdk::http_client::ResponseError) -> String {
+    use qiniu_sdk::http_client::ResponseErrorKind;
+
+    let mut fields = Vec::with_capacity(3);
+    match err.kind() {
+        ResponseErrorKind::StatusCodeError(status_code)
+        | ResponseErrorKind::UnexpectedStatusCode(status_code) => {
+            fields.push(format!("status_code={}", status_code.as_u16()));
+        }
+        kind => {
+            fields.push(format!("kind={:?}", kind));
+        }
+    }
+    let error = std::error::Error::source(err)
+        .map(|source| source.to_string())
+        .unwrap_or_else(|| err.to_string());
+    fields.push(format!("error={}", py_repr_str(&error)));
+    if let Some(request_id) = err.x_reqid().and_then(|v| v.to_str().ok()) {
+        fields.push(format!("request_id={}", py_repr_str(request_id)));
+    }
+    fields.join(", ")
+}
+
+fn py_repr_str(s: &str) -> String {
+    let mut repr = String::with_capacity(s.len() + 2);
+    repr.push('\'');
+    for c in s.chars() {
+        match c {
+            '\\' => repr.push_str("\\\\"),
+            '\'' => repr.push_str("\\'"),
+            '\n' => repr.push_str("\\n"),
+            '\r' => repr.push_str("\\r"),
+            '\t' => repr.push_str("\\t"),
+            c => repr.push(c),
+        }
+    }
+    repr.push('\'');
+    repr
+}
@@ -15,12 +15,14 @@ use futures::{future::BoxFuture, lock::Mutex as AsyncMutex};
 use pyo3::{
     exceptions::{PyIOError, PyNotImplementedError},
     prelude::*,
+    pyclass::CompareOp,
     types::PyBytes,
 };
-use qiniu_sdk::http::{Method, Uri};
+use qiniu_sdk::http::Method;
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
     io::Read,
     mem::{take, transmute},
     net::IpAddr,
@@ -38,6 +40,8 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<SyncHttpRequest>()?;
     m.add_class::<AsyncHttpRequest>()?;
     m.add_class::<Version>()?;
+    m.add_class::<Uri>()?;
+    m.add_class::<StatusCode>()?;
     m.add_class::<Metrics>()?;
     m.add_class::<HttpResponseParts>()?;
     m.add_class::<HttpResponsePartsRef>()?;
@@ -270,7 +274,9 @@ impl HttpRequestParts {
     /// 设置 HTTP 请求 URL
     #[setter]
     fn set_url(&mut self, url: &str) -> PyResult<()> {
-        *self.0.url_mut() = url.parse::<Uri>().map_err(QiniuInvalidURLError::from_err)?;
+        *self.0.url_mut() = url
+            .parse::<qiniu_sdk::http::Uri>()
+            .map_err(QiniuInvalidURLError::from_err)?;
         Ok(())
     }
 
@@ -617,6 +623,160 @@ impl From<Version> for qiniu_sdk::http::Version {
     }
 }
 
+/// URI
+///
+/// 可以在传入 `DownloadManager` 或 `ObjectsManager` 前检查 URL 是否合法
+#[pyclass]
+#[derive(Debug, Clone)]
+pub(super) struct Uri(qiniu_sdk::http::Uri);
+
+#[pymethods]
+impl Uri {
+    /// 解析 URI
+    ///
+    /// 如果 URI 不合法，将抛出 `QiniuInvalidURLError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(uri)")]
+    fn parse(uri: &str) -> PyResult<Self> {
+        Ok(Self(parse_uri(uri)?))
+    }
+
+    /// 获取协议
+    #[pyo3(text_signature = "($self)")]
+    fn scheme(&self) -> Option<&str> {
+        self.0.scheme_str()
+    }
+
+    /// 获取主机名
+    #[pyo3(text_signature = "($self)")]
+    fn host(&self) -> Option<&str> {
+        self.0.host()
+    }
+
+    /// 获取端口号
+    #[pyo3(text_signature = "($self)")]
+    fn port(&self) -> Option<u16> {
+        self.0.port_u16()
+    }
+
+    /// 获取路径
+    #[pyo3(text_signature = "($self)")]
+    fn path(&self) -> &str {
+        self.0.path()
+    }
+
+    /// 获取查询字符串
+    #[pyo3(text_signature = "($self)")]
+    fn query(&self) -> Option<&str> {
+        self.0.query()
+    }
+
+    /// 转换为字符串
+    #[pyo3(text_signature = "($self)")]
+    #[allow(clippy::inherent_to_string)]
+    fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
+        match op {
+            CompareOp::Eq => (self.0.to_string() == other.0.to_string()).to_object(py),
+            CompareOp::Ne => (self.0.to_string() != other.0.to_string()).to_object(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.to_string().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
+/// HTTP 状态码
+#[pyclass]
+#[derive(Debug, Clone, Copy)]
+pub(super) struct StatusCode(qiniu_sdk::http::StatusCode);
+
+#[pymethods]
+impl StatusCode {
+    /// 通过整数创建 HTTP 状态码
+    ///
+    /// 如果状态码不在 100 到 999 之间，将抛出 `QiniuInvalidStatusCodeError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(code)")]
+    fn from_int(code: i64) -> PyResult<Self> {
+        // 超出 u16 范围的状态码转换为 0，同样会被判定为非法状态码
+        Ok(Self(parse_status_code(u16::try_from(code).unwrap_or(0))?))
+    }
+
+    /// 是否为成功状态码（2xx）
+    #[pyo3(text_signature = "($self)")]
+    fn is_success(&self) -> bool {
+        self.0.is_success()
+    }
+
+    /// 是否为重定向状态码（3xx）
+    #[pyo3(text_signature = "($self)")]
+    fn is_redirection(&self) -> bool {
+        self.0.is_redirection()
+    }
+
+    /// 是否为客户端错误状态码（4xx）
+    #[pyo3(text_signature = "($self)")]
+    fn is_client_error(&self) -> bool {
+        self.0.is_client_error()
+    }
+
+    /// 是否为服务端错误状态码（5xx）
+    #[pyo3(text_signature = "($self)")]
+    fn is_server_error(&self) -> bool {
+        self.0.is_server_error()
+    }
+
+    /// 获取状态码的标准描述
+    ///
+    /// 如果是非标准状态码，返回 `None`
+    #[pyo3(text_signature = "($self)")]
+    fn canonical_reason(&self) -> Option<&'static str> {
+        self.0.canonical_reason()
+    }
+
+    fn __int__(&self) -> u16 {
+        self.0.as_u16()
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
+        match op {
+            CompareOp::Eq => (self.0 == other.0).to_object(py),
+            CompareOp::Ne => (self.0 != other.0).to_object(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.as_u16().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.0)
+    }
+}
+
 /// HTTP 响应的指标信息
 #[pyclass]
 #[derive(Clone)]
@@ -4,8 +4,8 @@ use crate::{
     exceptions::{
         QiniuApiCallError, QiniuApiCallErrorInfo, QiniuAuthorizationError,
         QiniuBodySizeMissingError, QiniuEmptyChainedResolver, QiniuHeaderValueEncodingError,
-        QiniuInvalidPrefixLengthError, QiniuIoError, QiniuIsahcError, QiniuJsonError,
-        QiniuTrustDNSError,
+        QiniuHttpCallError, QiniuInvalidPrefixLengthError, QiniuInvalidServiceNameError,
+        QiniuIoError, QiniuIsahcError, QiniuJsonError, QiniuTrustDNSError,
     },
     http::{
         AsyncHttpRequest, AsyncHttpResponse, HttpCaller, HttpRequestParts, HttpResponseParts,
@@ -26,7 +26,14 @@ use maybe_owned::MaybeOwned;
 use num_integer::Integer;
 use pyo3::{prelude::*, types::PyIterator};
 use qiniu_sdk::prelude::AuthorizationProvider;
-use std::{borrow::Cow, collections::HashMap, mem::transmute, path::PathBuf, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    mem::transmute,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Authorization>()?;
@@ -44,6 +51,7 @@ pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<SubnetChooser>()?;
     m.add_class::<ShuffledChooser>()?;
     m.add_class::<NeverEmptyHandedChooser>()?;
+    m.add_class::<SelectorConfig>()?;
     m.add_class::<Idempotent>()?;
     m.add_class::<RetryDecision>()?;
     m.add_class::<RequestRetrier>()?;
@@ -60,6 +68,7 @@ pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<CallbackContextMut>()?;
     m.add_class::<ExtendedCallbackContextRef>()?;
     m.add_class::<JsonResponse>()?;
+    m.add_function(wrap_pyfunction!(build_default_http_client, m)?)?;
 
     Ok(())
 }
@@ -782,7 +791,8 @@ impl IpChooser {
 
 /// 子网选择器
 ///
-/// 包含子网黑名单，一旦被反馈 API 调用失败，则将所有相关子网内 IP 地址冻结一段时间
+/// 包含子网黑名单，一旦被反馈 API 调用失败，则将所有相关子网内 IP 地址冻结一段时间。
+/// `ipv4_netmask_prefix_length` 不能大于 32，`ipv6_netmask_prefix_length` 不能大于 128，否则将抛出 `QiniuInvalidPrefixLengthError` 异常
 #[pyclass(extends = Chooser)]
 #[pyo3(
     text_signature = "(/, block_duration_secs = None, shrink_interval_secs = None, ipv4_netmask_prefix_length = None, ipv6_netmask_prefix_length = None)"
@@ -870,6 +880,116 @@ impl NeverEmptyHandedChooser {
     }
 }
 
+/// 终端地址选择策略配置
+///
+/// `strategy` 可以为 `"round_robin"`（按顺序选择，按 IP 地址冻结失败地址）、`"random"`（按 IP 地址冻结失败地址，并随机打乱可用地址）或 `"latency"`（按子网冻结失败地址，优先选择响应正常的子网）。
+/// 调用失败的地址将被冻结 `punish_duration_secs` 秒，重试前的退避时长以 `base_timeout_ms` 毫秒为基数指数增长
+#[pyclass]
+#[pyo3(
+    text_signature = "(/, strategy = 'round_robin', punish_duration_secs = 30, base_timeout_ms = 500)"
+)]
+#[derive(Clone, Debug)]
+pub(crate) struct SelectorConfig {
+    strategy: SelectorStrategy,
+    punish_duration_secs: u64,
+    base_timeout_ms: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum SelectorStrategy {
+    RoundRobin,
+    Random,
+    Latency,
+}
+
+#[pymethods]
+impl SelectorConfig {
+    #[new]
+    #[args(
+        strategy = "\"round_robin\"",
+        punish_duration_secs = "30",
+        base_timeout_ms = "500"
+    )]
+    fn new(strategy: &str, punish_duration_secs: u64, base_timeout_ms: u64) -> PyResult<Self> {
+        let strategy = match strategy {
+            "round_robin" => SelectorStrategy::RoundRobin,
+            "random" => SelectorStrategy::Random,
+            "latency" => SelectorStrategy::Latency,
+            _ => {
+                return Err(QiniuInvalidServiceNameError::new_err(format!(
+                    "Unknown selector strategy: {}",
+                    strategy
+                )))
+            }
+        };
+        Ok(Self {
+            strategy,
+            punish_duration_secs,
+            base_timeout_ms,
+        })
+    }
+
+    /// 获取选择策略
+    #[getter]
+    fn get_strategy(&self) -> &'static str {
+        match self.strategy {
+            SelectorStrategy::RoundRobin => "round_robin",
+            SelectorStrategy::Random => "random",
+            SelectorStrategy::Latency => "latency",
+        }
+    }
+
+    /// 获取失败地址的冻结时长
+    #[getter]
+    fn get_punish_duration_secs(&self) -> u64 {
+        self.punish_duration_secs
+    }
+
+    /// 获取退避时长基数
+    #[getter]
+    fn get_base_timeout_ms(&self) -> u64 {
+        self.base_timeout_ms
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl SelectorConfig {
+    pub(crate) fn chooser(&self) -> Chooser {
+        let block_duration = Duration::from_secs(self.punish_duration_secs);
+        match self.strategy {
+            SelectorStrategy::RoundRobin => Chooser(Box::new(make_ip_chooser(block_duration))),
+            SelectorStrategy::Random => Chooser(Box::new(
+                qiniu_sdk::http_client::ShuffledChooser::new(make_ip_chooser(block_duration)),
+            )),
+            SelectorStrategy::Latency => {
+                let mut builder = qiniu_sdk::http_client::SubnetChooser::builder();
+                builder.block_duration(block_duration);
+                Chooser(Box::new(builder.build()))
+            }
+        }
+    }
+
+    pub(crate) fn backoff(&self) -> Backoff {
+        Backoff(Box::new(qiniu_sdk::http_client::ExponentialBackoff::new(
+            2,
+            Duration::from_millis(self.base_timeout_ms),
+        )))
+    }
+}
+
+fn make_ip_chooser(block_duration: Duration) -> qiniu_sdk::http_client::IpChooser {
+    let mut builder = qiniu_sdk::http_client::IpChooser::builder();
+    builder.block_duration(block_duration);
+    builder.build()
+}
+
 /// API 幂等性
 #[pyclass]
 #[derive(Debug, Copy, Clone)]
@@ -1331,12 +1451,18 @@ fn convert_fraction<'a, U: FromPyObject<'a> + Clone + Integer>(
 /// HTTP 客户端
 ///
 /// 用于发送 HTTP 请求的入口。
+///
+/// 可以作为上下文管理器使用，退出时将关闭 HTTP 客户端，此后通过该客户端发出的请求都将抛出 `QiniuHttpCallError` 异常。
 #[pyclass(subclass)]
 #[pyo3(
     text_signature = "(/, http_caller = None, use_https = None, appended_user_agent = None, request_retrier = None, backoff = None, chooser = None, resolver = None, uploading_progress = None, receive_response_status = None, receive_response_header = None, to_resolve_domain = None, domain_resolved = None, to_choose_ips = None, ips_chosen = None, before_request_signed = None, after_request_signed = None, response_ok = None, response_error = None, before_backoff = None, after_backoff = None)"
 )]
-#[derive(Clone)]
-pub(crate) struct HttpClient(qiniu_sdk::http_client::HttpClient);
+#[derive(Clone, Debug)]
+pub(crate) struct HttpClient(
+    qiniu_sdk::http_client::HttpClient,
+    ClosableHttpCaller,
+    HttpClientSettings,
+);
 
 #[pymethods]
 impl HttpClient {
@@ -1386,71 +1512,34 @@ impl HttpClient {
         before_backoff: Option<PyObject>,
         after_backoff: Option<PyObject>,
     ) -> PyResult<Self> {
-        let mut builder = if let Some(http_caller) = http_caller {
-            qiniu_sdk::http_client::HttpClient::builder(http_caller)
+        let caller = ClosableHttpCaller::new(if let Some(http_caller) = http_caller {
+            Arc::new(http_caller)
         } else {
-            qiniu_sdk::http_client::HttpClient::build_isahc().map_err(QiniuIsahcError::from_err)?
+            Arc::new(qiniu_sdk::isahc::Client::default_client().map_err(QiniuIsahcError::from_err)?)
+        });
+        let settings = HttpClientSettings {
+            use_https,
+            appended_user_agent: appended_user_agent.map(ToOwned::to_owned),
+            request_retrier,
+            backoff,
+            chooser,
+            resolver,
+            uploading_progress,
+            receive_response_status,
+            receive_response_header,
+            to_resolve_domain,
+            domain_resolved,
+            to_choose_ips,
+            ips_chosen,
+            before_request_signed,
+            after_request_signed,
+            response_ok,
+            response_error,
+            before_backoff,
+            after_backoff,
         };
-
-        if let Some(use_https) = use_https {
-            builder.use_https(use_https);
-        }
-        if let Some(appended_user_agent) = appended_user_agent {
-            builder.appended_user_agent(appended_user_agent);
-        }
-        if let Some(request_retrier) = request_retrier {
-            builder.request_retrier(request_retrier);
-        }
-        if let Some(backoff) = backoff {
-            builder.backoff(backoff);
-        }
-        if let Some(chooser) = chooser {
-            builder.chooser(chooser);
-        }
-        if let Some(resolver) = resolver {
-            builder.resolver(resolver);
-        }
-        if let Some(uploading_progress) = uploading_progress {
-            builder.on_uploading_progress(on_uploading_progress(uploading_progress));
-        }
-        if let Some(receive_response_status) = receive_response_status {
-            builder.on_receive_response_status(on_receive_response_status(receive_response_status));
-        }
-        if let Some(receive_response_header) = receive_response_header {
-            builder.on_receive_response_header(on_receive_response_header(receive_response_header));
-        }
-        if let Some(to_resolve_domain) = to_resolve_domain {
-            builder.on_to_resolve_domain(on_to_resolve_domain(to_resolve_domain));
-        }
-        if let Some(domain_resolved) = domain_resolved {
-            builder.on_domain_resolved(on_domain_resolved(domain_resolved));
-        }
-        if let Some(to_choose_ips) = to_choose_ips {
-            builder.on_to_choose_ips(on_to_choose_ips(to_choose_ips));
-        }
-        if let Some(ips_chosen) = ips_chosen {
-            builder.on_ips_chosen(on_ips_chosen(ips_chosen));
-        }
-        if let Some(before_request_signed) = before_request_signed {
-            builder.on_before_request_signed(on_request_signed(before_request_signed));
-        }
-        if let Some(after_request_signed) = after_request_signed {
-            builder.on_after_request_signed(on_request_signed(after_request_signed));
-        }
-        if let Some(response_ok) = response_ok {
-            builder.on_response(on_response(response_ok));
-        }
-        if let Some(response_error) = response_error {
-            builder.on_error(on_error(response_error));
-        }
-        if let Some(before_backoff) = before_backoff {
-            builder.on_before_backoff(on_backoff(before_backoff));
-        }
-        if let Some(after_backoff) = after_backoff {
-            builder.on_after_backoff(on_backoff(after_backoff));
-        }
-
-        Ok(Self(builder.build()))
+        let http_client = settings.builder(caller.to_owned()).build();
+        Ok(Self(http_client, caller, settings))
     }
 
     /// 获得默认的 [`HttpCaller`] 实例
@@ -1726,6 +1815,50 @@ impl HttpClient {
         })
     }
 
+    /// 关闭 HTTP 客户端
+    ///
+    /// 释放内部的 HTTP 调用实现及其连接池，正在进行中的请求结束后连接才会被关闭。
+    /// 关闭后，通过该客户端以及使用该客户端创建的上传、下载、对象管理器发出的请求都将失败
+    #[pyo3(text_signature = "($self)")]
+    fn close(&self) {
+        self.1.close();
+    }
+
+    /// HTTP 客户端是否已经关闭
+    #[getter]
+    fn get_closed(&self) -> bool {
+        self.1.is_closed()
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+    ) {
+        self.close();
+    }
+
+    fn __aenter__<'p>(slf: PyRef<'p, Self>, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let slf: PyObject = slf.into_py(py);
+        pyo3_asyncio::async_std::future_into_py(py, async move { Ok(slf) })
+    }
+
+    fn __aexit__<'p>(
+        &self,
+        _exc_type: Option<&PyAny>,
+        _exc_value: Option<&PyAny>,
+        _traceback: Option<&PyAny>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        self.close();
+        pyo3_asyncio::async_std::future_into_py(py, async move { Ok(()) })
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -1736,6 +1869,14 @@ impl HttpClient {
 }
 
 impl HttpClient {
+    fn ensure_not_closed(&self) -> PyResult<()> {
+        if self.1.is_closed() {
+            Err(QiniuHttpCallError::new_err("HttpClient is closed"))
+        } else {
+            Ok(())
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn _call(
         &self,
@@ -1775,6 +1916,7 @@ impl HttpClient {
         after_backoff: Option<PyObject>,
         py: Python<'_>,
     ) -> PyResult<(SyncHttpResponse, HttpResponseParts)> {
+        self.ensure_not_closed()?;
         let service_names = service_names
             .unwrap_or_default()
             .into_iter()
@@ -1888,6 +2030,7 @@ impl HttpClient {
         before_backoff: Option<PyObject>,
         after_backoff: Option<PyObject>,
     ) -> PyResult<(AsyncHttpResponse, HttpResponseParts)> {
+        self.ensure_not_closed()?;
         let mut local_agent = None;
         let service_names = service_names
             .unwrap_or_default()
@@ -2080,15 +2223,189 @@ impl HttpClient {
     }
 }
 
+/// 创建默认配置的 HTTP 客户端
+///
+/// 基于 Isahc 库发送请求，`connection_timeout_ms` 为连接超时时长，`request_timeout_ms` 为整个请求的超时时长。
+/// 返回的 HTTP 客户端可以在多个线程之间安全共享
+#[pyfunction]
+#[pyo3(
+    text_signature = "(/, use_https = False, user_agent = None, connection_timeout_ms = 3000, request_timeout_ms = 60000)"
+)]
+#[args(
+    use_https = "false",
+    user_agent = "None",
+    connection_timeout_ms = "3000",
+    request_timeout_ms = "60000"
+)]
+fn build_default_http_client(
+    use_https: bool,
+    user_agent: Option<&str>,
+    connection_timeout_ms: u64,
+    request_timeout_ms: u64,
+) -> PyResult<HttpClient> {
+    use qiniu_sdk::isahc::isahc::config::Configurable;
+
+    let isahc_client = qiniu_sdk::isahc::isahc::HttpClient::builder()
+        .connect_timeout(Duration::from_millis(connection_timeout_ms))
+        .timeout(Duration::from_millis(request_timeout_ms))
+        .build()
+        .map_err(QiniuIsahcError::from_err)?;
+    let caller = ClosableHttpCaller::new(Arc::new(qiniu_sdk::isahc::Client::new(isahc_client)));
+    let settings = HttpClientSettings {
+        use_https: Some(use_https),
+        appended_user_agent: user_agent.map(ToOwned::to_owned),
+        ..Default::default()
+    };
+    let http_client = settings.builder(caller.to_owned()).build();
+    Ok(HttpClient(http_client, caller, settings))
+}
+
+impl HttpClient {
+    /// 基于当前 HTTP 客户端的 HTTP 调用实现和设置创建构建器，用于在其基础上修改设置
+    pub(crate) fn to_builder(&self) -> qiniu_sdk::http_client::HttpClientBuilder {
+        self.2.builder(self.1.to_owned())
+    }
+}
+
 impl From<HttpClient> for qiniu_sdk::http_client::HttpClient {
     fn from(client: HttpClient) -> Self {
         client.0
     }
 }
 
-impl From<qiniu_sdk::http_client::HttpClient> for HttpClient {
-    fn from(client: qiniu_sdk::http_client::HttpClient) -> Self {
-        Self(client)
+/// 创建 HTTP 客户端时使用的设置，用于在已有 HTTP 客户端的基础上重新创建
+#[derive(Clone, Debug, Default)]
+struct HttpClientSettings {
+    use_https: Option<bool>,
+    appended_user_agent: Option<String>,
+    request_retrier: Option<RequestRetrier>,
+    backoff: Option<Backoff>,
+    chooser: Option<Chooser>,
+    resolver: Option<Resolver>,
+    uploading_progress: Option<PyObject>,
+    receive_response_status: Option<PyObject>,
+    receive_response_header: Option<PyObject>,
+    to_resolve_domain: Option<PyObject>,
+    domain_resolved: Option<PyObject>,
+    to_choose_ips: Option<PyObject>,
+    ips_chosen: Option<PyObject>,
+    before_request_signed: Option<PyObject>,
+    after_request_signed: Option<PyObject>,
+    response_ok: Option<PyObject>,
+    response_error: Option<PyObject>,
+    before_backoff: Option<PyObject>,
+    after_backoff: Option<PyObject>,
+}
+
+impl HttpClientSettings {
+    fn builder(&self, caller: ClosableHttpCaller) -> qiniu_sdk::http_client::HttpClientBuilder {
+        let mut builder = qiniu_sdk::http_client::HttpClient::builder(caller);
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
+        }
+        if let Some(appended_user_agent) = &self.appended_user_agent {
+            builder.appended_user_agent(appended_user_agent.as_str());
+        }
+        if let Some(request_retrier) = self.request_retrier.to_owned() {
+            builder.request_retrier(request_retrier);
+        }
+        if let Some(backoff) = self.backoff.to_owned() {
+            builder.backoff(backoff);
+        }
+        if let Some(chooser) = self.chooser.to_owned() {
+            builder.chooser(chooser);
+        }
+        if let Some(resolver) = self.resolver.to_owned() {
+            builder.resolver(resolver);
+        }
+        if let Some(uploading_progress) = self.uploading_progress.to_owned() {
+            builder.on_uploading_progress(on_uploading_progress(uploading_progress));
+        }
+        if let Some(receive_response_status) = self.receive_response_status.to_owned() {
+            builder.on_receive_response_status(on_receive_response_status(receive_response_status));
+        }
+        if let Some(receive_response_header) = self.receive_response_header.to_owned() {
+            builder.on_receive_response_header(on_receive_response_header(receive_response_header));
+        }
+        if let Some(to_resolve_domain) = self.to_resolve_domain.to_owned() {
+            builder.on_to_resolve_domain(on_to_resolve_domain(to_resolve_domain));
+        }
+        if let Some(domain_resolved) = self.domain_resolved.to_owned() {
+            builder.on_domain_resolved(on_domain_resolved(domain_resolved));
+        }
+        if let Some(to_choose_ips) = self.to_choose_ips.to_owned() {
+            builder.on_to_choose_ips(on_to_choose_ips(to_choose_ips));
+        }
+        if let Some(ips_chosen) = self.ips_chosen.to_owned() {
+            builder.on_ips_chosen(on_ips_chosen(ips_chosen));
+        }
+        if let Some(before_request_signed) = self.before_request_signed.to_owned() {
+            builder.on_before_request_signed(on_request_signed(before_request_signed));
+        }
+        if let Some(after_request_signed) = self.after_request_signed.to_owned() {
+            builder.on_after_request_signed(on_request_signed(after_request_signed));
+        }
+        if let Some(response_ok) = self.response_ok.to_owned() {
+            builder.on_response(on_response(response_ok));
+        }
+        if let Some(response_error) = self.response_error.to_owned() {
+            builder.on_error(on_error(response_error));
+        }
+        if let Some(before_backoff) = self.before_backoff.to_owned() {
+            builder.on_before_backoff(on_backoff(before_backoff));
+        }
+        if let Some(after_backoff) = self.after_backoff.to_owned() {
+            builder.on_after_backoff(on_backoff(after_backoff));
+        }
+        builder
+    }
+}
+
+/// 可以关闭的 HTTP 调用实现
+///
+/// 所有克隆共享同一个内部实现，关闭后内部实现将被释放，之后的请求都将返回错误
+#[derive(Clone, Debug)]
+struct ClosableHttpCaller(Arc<RwLock<Option<Arc<dyn qiniu_sdk::http::HttpCaller>>>>);
+
+impl ClosableHttpCaller {
+    fn new(caller: Arc<dyn qiniu_sdk::http::HttpCaller>) -> Self {
+        Self(Arc::new(RwLock::new(Some(caller))))
+    }
+
+    fn close(&self) {
+        self.0.write().unwrap().take();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.0.read().unwrap().is_none()
+    }
+
+    fn caller(
+        &self,
+    ) -> Result<Arc<dyn qiniu_sdk::http::HttpCaller>, qiniu_sdk::http::ResponseError> {
+        self.0.read().unwrap().to_owned().ok_or_else(|| {
+            qiniu_sdk::http::ResponseError::builder_with_msg(
+                qiniu_sdk::http::ResponseErrorKind::LocalIoError,
+                "HttpClient is closed",
+            )
+            .build()
+        })
+    }
+}
+
+impl qiniu_sdk::http::HttpCaller for ClosableHttpCaller {
+    fn call(
+        &self,
+        request: &mut qiniu_sdk::http::SyncRequest<'_>,
+    ) -> qiniu_sdk::http::SyncResponseResult {
+        self.caller()?.call(request)
+    }
+
+    fn async_call<'a>(
+        &'a self,
+        request: &'a mut qiniu_sdk::http::AsyncRequest<'_>,
+    ) -> futures::future::BoxFuture<'a, qiniu_sdk::http::AsyncResponseResult> {
+        Box::pin(async move { self.caller()?.async_call(request).await })
     }
 }
 
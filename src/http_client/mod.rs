@@ -5,7 +5,7 @@ mod region;
 
 pub(super) use client::{
     Authorization, Backoff, CallbackContextMut, Chooser, HttpClient, Idempotent, JsonResponse,
-    RequestBuilderPartsRef, RequestRetrier, Resolver,
+    RequestBuilderPartsRef, RequestRetrier, Resolver, SelectorConfig,
 };
 pub(super) use region::{
     BucketRegionsQueryer, Endpoint, Endpoints, EndpointsProvider, RegionsProvider, ServiceName,
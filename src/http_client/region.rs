@@ -1,16 +1,31 @@
 use crate::{
     credential::CredentialProvider,
     exceptions::{
-        QiniuApiCallError, QiniuEmptyRegionsProvider, QiniuInvalidEndpointError,
-        QiniuInvalidIpAddrWithPortError,
+        QiniuApiCallError, QiniuEmptyEndpoints, QiniuEmptyRegionsProvider,
+        QiniuInvalidEndpointError, QiniuInvalidIpAddrWithPortError, QiniuInvalidServiceNameError,
+        QiniuJsonError,
     },
-    utils::{extract_endpoints, parse_domain_with_port},
+    utils::{extract_endpoints, parse_domain_with_port, parse_uri},
 };
 use futures::future::BoxFuture;
 use maybe_owned::MaybeOwned;
-use pyo3::{prelude::*, pyclass::CompareOp};
+use pyo3::{
+    exceptions::{PyIndexError, PyTypeError, PyValueError},
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PySlice, PyString},
+};
 use qiniu_sdk::http_client::EndpointsGetOptions;
-use std::{borrow::Cow, path::PathBuf, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::hash_map::DefaultHasher,
+    future::Future,
+    hash::{Hash, Hasher},
+    net::{IpAddr, Ipv4Addr},
+    path::PathBuf,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
 pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<DomainWithPort>()?;
@@ -20,6 +35,7 @@ pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Endpoints>()?;
     m.add_class::<EndpointsProvider>()?;
     m.add_class::<Region>()?;
+    m.add_class::<RegionsGetOptions>()?;
     m.add_class::<RegionsProvider>()?;
     m.add_class::<AllRegionsProvider>()?;
     m.add_class::<BucketRegionsQueryer>()?;
@@ -31,6 +47,7 @@ pub(super) fn register(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
 /// 域名和端口号
 ///
 /// 用来表示一个七牛服务器的地址，端口号是可选的，如果不提供，则根据传输协议判定默认的端口号。
+/// 该类型的实例总是为真值，判断可选的地址是否存在时，应该使用 `is not None`。
 #[pyclass]
 #[pyo3(text_signature = "(domain, port = None)")]
 #[derive(Clone)]
@@ -72,9 +89,21 @@ impl DomainWithPort {
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
         match op {
             CompareOp::Eq => (self.0 == other.0).to_object(py),
+            CompareOp::Ne => (self.0 != other.0).to_object(py),
             _ => py.NotImplemented(),
         }
     }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// 总是返回 `True`
+    fn __bool__(&self) -> bool {
+        true
+    }
 }
 
 /// IP 地址和端口号
@@ -111,6 +140,48 @@ impl IpAddrWithPort {
         self.0.port().map(|port| port.get())
     }
 
+    /// 是否是 IPv4 地址
+    #[pyo3(text_signature = "($self)")]
+    fn is_ipv4(&self) -> bool {
+        self.0.ip_addr().is_ipv4()
+    }
+
+    /// 是否是 IPv6 地址
+    #[pyo3(text_signature = "($self)")]
+    fn is_ipv6(&self) -> bool {
+        self.0.ip_addr().is_ipv6()
+    }
+
+    /// 将 IPv4 映射的 IPv6 地址（即 `::ffff:a.b.c.d`）转换为 IPv4 地址，端口保持不变
+    ///
+    /// 如果不是 IPv4 映射的 IPv6 地址，则返回 `None`
+    #[pyo3(text_signature = "($self)")]
+    fn to_ipv4_mapped(&self) -> Option<Self> {
+        match self.0.ip_addr() {
+            IpAddr::V6(ip_addr) => match ip_addr.octets() {
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, a, b, c, d] => {
+                    Some(Self(qiniu_sdk::http_client::IpAddrWithPort::new(
+                        IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+                        self.0.port(),
+                    )))
+                }
+                _ => None,
+            },
+            IpAddr::V4(_) => None,
+        }
+    }
+
+    /// 转换为 Python `socket` 模块使用的 `(host, port)` 元组
+    ///
+    /// 如果没有指定端口号，则使用 `80`
+    #[pyo3(text_signature = "($self)")]
+    fn to_socket_addr(&self) -> (String, u16) {
+        (
+            self.0.ip_addr().to_string(),
+            self.0.port().map_or(80, |port| port.get()),
+        )
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -122,14 +193,22 @@ impl IpAddrWithPort {
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
         match op {
             CompareOp::Eq => (self.0 == other.0).to_object(py),
+            CompareOp::Ne => (self.0 != other.0).to_object(py),
             _ => py.NotImplemented(),
         }
     }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 /// 终端地址
 ///
 /// 用来表示一个域名和端口号，或 IP 地址和端口号。
+/// 该类型的实例总是为真值，判断可选的终端地址是否存在时，应该使用 `is not None`。
 #[pyclass]
 #[pyo3(text_signature = "(domain_or_ip_addr, port = None)")]
 #[derive(Clone)]
@@ -149,6 +228,26 @@ impl Endpoint {
         Ok(Self(host))
     }
 
+    /// 从 URL 中解析终端地址
+    ///
+    /// 仅使用 URL 中的主机和端口，忽略协议、路径和查询参数。
+    /// 如果 URL 非法，将抛出 `QiniuInvalidURLError` 异常，如果主机无法解析为域名或 IP 地址，将抛出 `QiniuInvalidEndpointError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(url)")]
+    fn from_url(url: &str) -> PyResult<Self> {
+        let url = parse_uri(url)?;
+        let host = url.host().unwrap_or_default();
+        let host = if let Some(port) = url.port_u16() {
+            format!("{}:{}", host, port)
+        } else {
+            host.trim_start_matches('[')
+                .trim_end_matches(']')
+                .to_owned()
+        };
+        let endpoint = host.parse().map_err(QiniuInvalidEndpointError::from_err)?;
+        Ok(Self(endpoint))
+    }
+
     /// 获取域名
     #[getter]
     fn get_domain(&self) -> Option<&str> {
@@ -167,6 +266,66 @@ impl Endpoint {
         self.0.port().map(|port| port.get())
     }
 
+    /// 是否是 IPv4 地址，对于域名总是返回 `False`
+    #[pyo3(text_signature = "($self)")]
+    fn is_ipv4(&self) -> bool {
+        self.0.ip_addr().map_or(false, |ip_addr| ip_addr.is_ipv4())
+    }
+
+    /// 是否是 IPv6 地址，对于域名总是返回 `False`
+    #[pyo3(text_signature = "($self)")]
+    fn is_ipv6(&self) -> bool {
+        self.0.ip_addr().map_or(false, |ip_addr| ip_addr.is_ipv6())
+    }
+
+    /// 生成仅包含协议和主机的基础 URL
+    #[pyo3(text_signature = "($self, /, use_https = False)")]
+    #[args(use_https = "false")]
+    fn to_base_url(&self, use_https: bool) -> String {
+        let scheme = if use_https { "https" } else { "http" };
+        match self.0.ip_addr() {
+            Some(IpAddr::V6(ip_addr)) if self.0.port().is_none() => {
+                format!("{}://[{}]", scheme, ip_addr)
+            }
+            _ => format!("{}://{}", scheme, self.0),
+        }
+    }
+
+    /// 转换为 Python `socket` 模块使用的 `(host, port)` 元组
+    ///
+    /// 不会解析域名，如果没有指定端口号，则根据 `use_https` 使用 `443` 或 `80`
+    #[pyo3(text_signature = "($self, /, use_https = False)")]
+    #[args(use_https = "false")]
+    fn to_socket_addr(&self, use_https: bool) -> (String, u16) {
+        let host = match (self.0.domain(), self.0.ip_addr()) {
+            (Some(domain), _) => domain.to_owned(),
+            (None, Some(ip_addr)) => ip_addr.to_string(),
+            (None, None) => self.0.to_string(),
+        };
+        let port = self
+            .0
+            .port()
+            .map_or(if use_https { 443 } else { 80 }, |port| port.get());
+        (host, port)
+    }
+
+    /// 将终端地址与路径组合为 URL
+    ///
+    /// `path` 必须以 `/` 开头，如果包含非法的 URL 字符，将抛出 `QiniuInvalidURLError` 异常
+    #[pyo3(text_signature = "($self, path, /, use_https = False)")]
+    #[args(use_https = "false")]
+    fn to_url(&self, path: &str, use_https: bool) -> PyResult<String> {
+        if !path.starts_with('/') {
+            return Err(PyValueError::new_err(format!(
+                "Path must start with `/`: {}",
+                path
+            )));
+        }
+        let url = format!("{}{}", self.to_base_url(use_https), path);
+        parse_uri(&url)?;
+        Ok(url)
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -178,9 +337,43 @@ impl Endpoint {
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
         match op {
             CompareOp::Eq => (self.0 == other.0).to_object(py),
+            CompareOp::Ne => (self.0 != other.0).to_object(py),
             _ => py.NotImplemented(),
         }
     }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// 将终端地址与路径以 `/` 连接，不包含协议部分
+    ///
+    /// 路径开头的 `/` 将被忽略，如果 `path` 不是字符串，将抛出 `TypeError` 异常
+    fn __add__(&self, path: &PyAny) -> PyResult<String> {
+        let path = path.downcast::<PyString>().map_err(|_| {
+            PyTypeError::new_err(format!(
+                "Path must be str, not {}",
+                path.get_type().name().unwrap_or("unknown")
+            ))
+        })?;
+        Ok(format!(
+            "{}/{}",
+            self.0,
+            path.to_str()?.trim_start_matches('/')
+        ))
+    }
+
+    /// 与 `__add__()` 相同，以便使用 `endpoint / "path"` 的写法
+    fn __truediv__(&self, path: &PyAny) -> PyResult<String> {
+        self.__add__(path)
+    }
+
+    /// 总是返回 `True`
+    fn __bool__(&self) -> bool {
+        true
+    }
 }
 
 impl From<Endpoint> for qiniu_sdk::http_client::Endpoint {
@@ -189,6 +382,12 @@ impl From<Endpoint> for qiniu_sdk::http_client::Endpoint {
     }
 }
 
+impl From<qiniu_sdk::http_client::Endpoint> for Endpoint {
+    fn from(e: qiniu_sdk::http_client::Endpoint) -> Self {
+        Self(e)
+    }
+}
+
 #[pyclass]
 #[derive(Clone, Copy)]
 pub(crate) enum ServiceName {
@@ -306,6 +505,23 @@ impl EndpointsProvider {
         })
     }
 
+    /// 为终端地址获取设置超时时长
+    ///
+    /// 超时后如果提供了 `fallback`，则从 `fallback` 获取终端地址，否则抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, timeout_secs, /, fallback = None)")]
+    #[args(fallback = "None")]
+    fn with_timeout(
+        &self,
+        timeout_secs: f64,
+        fallback: Option<EndpointsProvider>,
+    ) -> PyResult<Self> {
+        Ok(Self(Box::new(TimeoutEndpointsProvider {
+            provider: self.0.to_owned(),
+            timeout: parse_timeout_secs(timeout_secs)?,
+            fallback: fallback.map(|fallback| fallback.0),
+        })))
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -347,6 +563,49 @@ impl EndpointsProvider {
     }
 }
 
+#[derive(Clone, Debug)]
+struct TimeoutEndpointsProvider {
+    provider: Box<dyn qiniu_sdk::http_client::EndpointsProvider>,
+    timeout: Duration,
+    fallback: Option<Box<dyn qiniu_sdk::http_client::EndpointsProvider>>,
+}
+
+impl qiniu_sdk::http_client::EndpointsProvider for TimeoutEndpointsProvider {
+    fn get_endpoints<'e>(
+        &'e self,
+        options: qiniu_sdk::http_client::EndpointsGetOptions<'_>,
+    ) -> qiniu_sdk::http_client::ApiResult<Cow<'e, qiniu_sdk::http_client::Endpoints>> {
+        let result = async_std::task::block_on(async_std::future::timeout(
+            self.timeout,
+            self.provider.async_get_endpoints(options.to_owned()),
+        ));
+        match (result, &self.fallback) {
+            (Ok(result), _) => result.map(|endpoints| Cow::Owned(endpoints.into_owned())),
+            (Err(_), Some(fallback)) => fallback.get_endpoints(options),
+            (Err(_), None) => Err(make_timeout_error(self.timeout)),
+        }
+    }
+
+    fn async_get_endpoints<'a>(
+        &'a self,
+        options: qiniu_sdk::http_client::EndpointsGetOptions<'a>,
+    ) -> BoxFuture<'a, qiniu_sdk::http_client::ApiResult<Cow<'a, qiniu_sdk::http_client::Endpoints>>>
+    {
+        Box::pin(async move {
+            let result = async_std::future::timeout(
+                self.timeout,
+                self.provider.async_get_endpoints(options.to_owned()),
+            )
+            .await;
+            match (result, &self.fallback) {
+                (Ok(result), _) => result,
+                (Err(_), Some(fallback)) => fallback.async_get_endpoints(options).await,
+                (Err(_), None) => Err(make_timeout_error(self.timeout)),
+            }
+        })
+    }
+}
+
 /// 终端地址列表
 ///
 /// 存储一个七牛服务的多个终端地址，包含主要地址列表和备选地址列表
@@ -387,14 +646,179 @@ impl Endpoints {
         self.0.alternative().iter().cloned().map(Endpoint).collect()
     }
 
+    /// 从字符串列表创建终端地址列表
+    #[staticmethod]
+    #[pyo3(text_signature = "(preferred, /, alternative = None)")]
+    #[args(alternative = "None")]
+    fn from_list(
+        preferred: Vec<&str>,
+        alternative: Option<Vec<&str>>,
+        py: Python<'_>,
+    ) -> PyResult<Py<Endpoints>> {
+        let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+        builder.add_preferred_endpoints(parse_endpoints(preferred)?);
+        if let Some(alternative) = alternative {
+            builder.add_alternative_endpoints(parse_endpoints(alternative)?);
+        }
+        EndpointsProvider::make_initializer(builder.build(), py)
+    }
+
+    /// 从 JSON 字符串创建终端地址列表
+    ///
+    /// JSON 格式为 `{"preferred": ["endpoint1", ...], "alternative": ["endpoint2", ...]}`
+    #[staticmethod]
+    #[pyo3(text_signature = "(json)")]
+    fn from_json(json: &str, py: Python<'_>) -> PyResult<Py<Endpoints>> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(QiniuJsonError::from_err)?;
+        let preferred = extract_json_endpoints(&value, "preferred")?;
+        let alternative = extract_json_endpoints(&value, "alternative")?;
+        Self::from_list(
+            preferred.iter().map(String::as_str).collect(),
+            Some(alternative.iter().map(String::as_str).collect()),
+            py,
+        )
+    }
+
+    /// 将终端地址列表序列化为 JSON 字符串
+    #[pyo3(text_signature = "($self)")]
+    fn to_json(&self) -> PyResult<String> {
+        let to_strings = |endpoints: &[qiniu_sdk::http_client::Endpoint]| {
+            endpoints
+                .iter()
+                .map(|endpoint| endpoint.to_string())
+                .collect::<Vec<_>>()
+        };
+        serde_json::to_string(&serde_json::json!({
+            "preferred": to_strings(self.0.preferred()),
+            "alternative": to_strings(self.0.alternative()),
+        }))
+        .map_err(QiniuJsonError::from_err)
+    }
+
+    /// 返回一个新的终端地址列表，指定的终端地址将被插入到主要终端地址列表的最前面
+    #[pyo3(text_signature = "($self, endpoint)")]
+    fn with_preferred_endpoint_prepended(
+        &self,
+        endpoint: Endpoint,
+        py: Python<'_>,
+    ) -> PyResult<Py<Endpoints>> {
+        let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+        builder.add_preferred_endpoints(
+            [endpoint.0]
+                .into_iter()
+                .chain(self.0.preferred().iter().cloned())
+                .collect(),
+        );
+        builder.add_alternative_endpoints(self.0.alternative().to_vec());
+        EndpointsProvider::make_initializer(builder.build(), py)
+    }
+
+    /// 返回一个新的终端地址列表，指定的终端地址将被插入到备选终端地址列表的最前面
+    #[pyo3(text_signature = "($self, endpoint)")]
+    fn with_alternative_endpoint_prepended(
+        &self,
+        endpoint: Endpoint,
+        py: Python<'_>,
+    ) -> PyResult<Py<Endpoints>> {
+        let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+        builder.add_preferred_endpoints(self.0.preferred().to_vec());
+        builder.add_alternative_endpoints(
+            [endpoint.0]
+                .into_iter()
+                .chain(self.0.alternative().iter().cloned())
+                .collect(),
+        );
+        EndpointsProvider::make_initializer(builder.build(), py)
+    }
+
+    /// 返回一个新的终端地址列表，指定的终端地址将从主要终端地址列表和备选终端地址列表中移除
+    #[pyo3(text_signature = "($self, endpoint)")]
+    fn without_endpoint(&self, endpoint: Endpoint, py: Python<'_>) -> PyResult<Py<Endpoints>> {
+        let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+        builder.add_preferred_endpoints(
+            self.0
+                .preferred()
+                .iter()
+                .filter(|&e| e != &endpoint.0)
+                .cloned()
+                .collect(),
+        );
+        builder.add_alternative_endpoints(
+            self.0
+                .alternative()
+                .iter()
+                .filter(|&e| e != &endpoint.0)
+                .cloned()
+                .collect(),
+        );
+        EndpointsProvider::make_initializer(builder.build(), py)
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.preferred().len() + self.0.alternative().len()
+    }
+
+    /// 按照先主要终端地址，后备选终端地址的顺序获取终端地址
+    ///
+    /// 参数为整数时返回单个终端地址，为切片时返回终端地址列表
+    fn __getitem__(&self, index: &PyAny, py: Python<'_>) -> PyResult<PyObject> {
+        let endpoints = self
+            .0
+            .preferred()
+            .iter()
+            .chain(self.0.alternative().iter())
+            .cloned()
+            .map(Endpoint)
+            .collect::<Vec<_>>();
+        if let Ok(slice) = index.downcast::<PySlice>() {
+            let indices = slice.indices(endpoints.len().try_into()?)?;
+            let selected = (0..indices.slicelength)
+                .map(|i| endpoints[(indices.start + i * indices.step) as usize].to_owned())
+                .collect::<Vec<_>>();
+            Ok(selected.into_py(py))
+        } else {
+            let index = index.extract::<isize>()?;
+            let len = endpoints.len() as isize;
+            let normalized = if index < 0 { index + len } else { index };
+            if (0..len).contains(&normalized) {
+                Ok(endpoints[normalized as usize].to_owned().into_py(py))
+            } else {
+                Err(PyIndexError::new_err("endpoints index out of range"))
+            }
+        }
+    }
+
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
         match op {
             CompareOp::Eq => (self.0 == other.0).to_object(py),
+            CompareOp::Ne => (self.0 != other.0).to_object(py),
             _ => py.NotImplemented(),
         }
     }
 }
 
+fn parse_endpoints(endpoints: Vec<&str>) -> PyResult<Vec<qiniu_sdk::http_client::Endpoint>> {
+    endpoints
+        .into_iter()
+        .map(|endpoint| {
+            endpoint
+                .parse()
+                .map_err(QiniuInvalidEndpointError::from_err)
+        })
+        .collect()
+}
+
+fn extract_json_endpoints(value: &serde_json::Value, key: &str) -> PyResult<Vec<String>> {
+    value
+        .get(key)
+        .cloned()
+        .map(serde_json::from_value)
+        .transpose()
+        .map_err(QiniuJsonError::from_err)
+        .map(Option::unwrap_or_default)
+}
+
 impl From<Endpoints> for qiniu_sdk::http_client::Endpoints {
     fn from(endpoints: Endpoints) -> Self {
         endpoints.0
@@ -407,6 +831,145 @@ impl From<qiniu_sdk::http_client::Endpoints> for Endpoints {
     }
 }
 
+/// 获取区域信息的选项
+///
+/// `service_names` 用于筛选提供了这些服务终端地址的区域，`timeout_ms` 用于设置获取区域信息的超时时长。
+/// 获取单个区域时优先返回首选区域，仅当首选区域未提供这些服务时，才获取所有区域并返回第一个符合条件的区域
+#[pyclass]
+#[derive(Clone, Default)]
+#[pyo3(text_signature = "(/, service_names = None, timeout_ms = None)")]
+struct RegionsGetOptions {
+    service_names: Vec<ServiceName>,
+    timeout: Option<Duration>,
+}
+
+#[pymethods]
+impl RegionsGetOptions {
+    #[new]
+    #[args(service_names = "None", timeout_ms = "None")]
+    fn new(service_names: Option<Vec<ServiceName>>, timeout_ms: Option<u64>) -> Self {
+        Self {
+            service_names: service_names.unwrap_or_default(),
+            timeout: timeout_ms.map(Duration::from_millis),
+        }
+    }
+
+    /// 获取服务名称列表
+    #[getter]
+    fn get_service_names(&self) -> Vec<ServiceName> {
+        self.service_names.to_owned()
+    }
+
+    /// 获取超时时长，单位为毫秒
+    #[getter]
+    fn get_timeout_ms(&self) -> Option<u128> {
+        self.timeout.map(|timeout| timeout.as_millis())
+    }
+}
+
+impl RegionsGetOptions {
+    fn get(
+        &self,
+        provider: &dyn qiniu_sdk::http_client::RegionsProvider,
+    ) -> PyResult<qiniu_sdk::http_client::Region> {
+        let region = self
+            .call(provider.async_get(Default::default()))?
+            .into_region();
+        if self.provides_services(&region) {
+            return Ok(region);
+        }
+        self.get_all(provider)?.into_iter().next().ok_or_else(|| {
+            QiniuEmptyRegionsProvider::new_err("no region provides the specified services")
+        })
+    }
+
+    fn get_all(
+        &self,
+        provider: &dyn qiniu_sdk::http_client::RegionsProvider,
+    ) -> PyResult<Vec<qiniu_sdk::http_client::Region>> {
+        let regions = self
+            .call(provider.async_get_all(Default::default()))?
+            .into_regions();
+        Ok(self.filter_regions(regions))
+    }
+
+    async fn async_get(
+        &self,
+        provider: &dyn qiniu_sdk::http_client::RegionsProvider,
+    ) -> PyResult<qiniu_sdk::http_client::Region> {
+        let region = self
+            .async_call(provider.async_get(Default::default()))
+            .await?
+            .into_region();
+        if self.provides_services(&region) {
+            return Ok(region);
+        }
+        self.async_get_all(provider)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                QiniuEmptyRegionsProvider::new_err("no region provides the specified services")
+            })
+    }
+
+    async fn async_get_all(
+        &self,
+        provider: &dyn qiniu_sdk::http_client::RegionsProvider,
+    ) -> PyResult<Vec<qiniu_sdk::http_client::Region>> {
+        let regions = self
+            .async_call(provider.async_get_all(Default::default()))
+            .await?
+            .into_regions();
+        Ok(self.filter_regions(regions))
+    }
+
+    fn call<T>(
+        &self,
+        f: impl Future<Output = qiniu_sdk::http_client::ApiResult<T>>,
+    ) -> PyResult<T> {
+        async_std::task::block_on(self.async_call(f))
+    }
+
+    async fn async_call<T>(
+        &self,
+        f: impl Future<Output = qiniu_sdk::http_client::ApiResult<T>>,
+    ) -> PyResult<T> {
+        if let Some(timeout) = self.timeout {
+            async_std::future::timeout(timeout, f)
+                .await
+                .unwrap_or_else(|_| Err(make_timeout_error(timeout)))
+        } else {
+            f.await
+        }
+        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+    }
+
+    fn filter_regions(
+        &self,
+        regions: Vec<qiniu_sdk::http_client::Region>,
+    ) -> Vec<qiniu_sdk::http_client::Region> {
+        regions
+            .into_iter()
+            .filter(|region| self.provides_services(region))
+            .collect()
+    }
+
+    fn provides_services(&self, region: &qiniu_sdk::http_client::Region) -> bool {
+        use qiniu_sdk::http_client::EndpointsProvider as _;
+
+        self.service_names.iter().all(|&service_name| {
+            let service_names = [service_name.into()];
+            let opts = EndpointsGetOptions::builder()
+                .service_names(&service_names)
+                .build();
+            region
+                .get_endpoints(opts)
+                .map_or(false, |endpoints| !endpoints.preferred().is_empty())
+        })
+    }
+}
+
 /// 区域信息获取接口
 ///
 /// 可以获取一个区域也可以获取多个区域
@@ -421,59 +984,61 @@ pub(crate) struct RegionsProvider(Box<dyn qiniu_sdk::http_client::RegionsProvide
 impl RegionsProvider {
     #[new]
     fn new(regions: Vec<Region>) -> PyResult<Self> {
-        let mut iter = regions.into_iter();
-        if let Some(region) = iter.next() {
-            let mut provider = qiniu_sdk::http_client::StaticRegionsProvider::new(region.0);
-            provider.extend(iter.map(|r| r.0));
-            Ok(Self(Box::new(provider)))
-        } else {
-            Err(QiniuEmptyRegionsProvider::new_err("regions is empty"))
-        }
+        Self::new_static(regions.into_iter().map(|r| r.0))
     }
 
-    #[pyo3(text_signature = "()")]
-    fn get(&self, py: Python<'_>) -> PyResult<Py<Region>> {
-        let region = py
-            .allow_threads(|| self.0.get(Default::default()))
-            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
-            .into_region();
+    #[pyo3(text_signature = "($self, /, options = None)")]
+    #[args(options = "None")]
+    fn get(&self, options: Option<RegionsGetOptions>, py: Python<'_>) -> PyResult<Py<Region>> {
+        let options = options.unwrap_or_default();
+        let region = py.allow_threads(|| options.get(&self.0))?;
         Self::make_initializer(region, py)
     }
 
-    #[pyo3(text_signature = "()")]
-    fn get_all(&self, py: Python<'_>) -> PyResult<Vec<Py<Region>>> {
+    #[pyo3(text_signature = "($self, /, options = None)")]
+    #[args(options = "None")]
+    fn get_all(
+        &self,
+        options: Option<RegionsGetOptions>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<Py<Region>>> {
+        let options = options.unwrap_or_default();
         let regions = py
-            .allow_threads(|| self.0.get_all(Default::default()))
-            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
-            .into_regions()
+            .allow_threads(|| options.get_all(&self.0))?
             .into_iter()
             .map(|region| Self::make_initializer(region, py))
             .collect::<PyResult<Vec<Py<Region>>>>()?;
         Ok(regions)
     }
 
-    #[pyo3(text_signature = "()")]
-    fn async_get<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+    #[pyo3(text_signature = "($self, /, options = None)")]
+    #[args(options = "None")]
+    fn async_get<'p>(
+        &self,
+        options: Option<RegionsGetOptions>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
         let provider = self.0.to_owned();
+        let options = options.unwrap_or_default();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let region = provider
-                .async_get(Default::default())
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
-                .into_region();
+            let region = options.async_get(&provider).await?;
             Python::with_gil(|py| Self::make_initializer(region, py))
         })
     }
 
-    #[pyo3(text_signature = "()")]
-    fn async_get_all<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+    #[pyo3(text_signature = "($self, /, options = None)")]
+    #[args(options = "None")]
+    fn async_get_all<'p>(
+        &self,
+        options: Option<RegionsGetOptions>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
         let provider = self.0.to_owned();
+        let options = options.unwrap_or_default();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let regions = provider
-                .async_get_all(Default::default())
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
-                .into_regions()
+            let regions = options
+                .async_get_all(&provider)
+                .await?
                 .into_iter()
                 .map(|region| Python::with_gil(|py| Self::make_initializer(region, py)))
                 .collect::<PyResult<Vec<Py<Region>>>>()?;
@@ -481,6 +1046,57 @@ impl RegionsProvider {
         })
     }
 
+    /// 创建仅返回指定服务终端地址列表的终端地址列表获取接口
+    ///
+    /// 无论获取时指定何种服务，都将返回 `service` 服务的终端地址列表
+    #[pyo3(text_signature = "($self, service)")]
+    fn to_endpoints_provider_for_service(&self, service: ServiceName) -> EndpointsProvider {
+        EndpointsProvider(Box::new(ServiceEndpointsProvider {
+            endpoints: qiniu_sdk::http_client::RegionsProviderEndpoints::new(self.0.to_owned()),
+            service_names: [service.into()],
+        }))
+    }
+
+    /// 为区域信息获取设置超时时长
+    ///
+    /// 超时后如果提供了 `fallback`，则从 `fallback` 获取区域信息，否则抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, timeout_secs, /, fallback = None)")]
+    #[args(fallback = "None")]
+    fn with_timeout(&self, timeout_secs: f64, fallback: Option<RegionsProvider>) -> PyResult<Self> {
+        Ok(Self(Box::new(TimeoutRegionsProvider {
+            provider: self.0.to_owned(),
+            timeout: parse_timeout_secs(timeout_secs)?,
+            fallback: fallback.map(|fallback| fallback.0),
+        })))
+    }
+
+    /// 组合多个区域信息获取接口
+    ///
+    /// `strategy` 为 `fallback` 时依次尝试每个接口，返回首个成功的结果；
+    /// 为 `merge` 时调用所有接口，并合并获取到的区域列表
+    #[staticmethod]
+    #[pyo3(text_signature = "(providers, /, strategy = 'fallback')")]
+    #[args(strategy = "\"fallback\"")]
+    fn combined(providers: Vec<RegionsProvider>, strategy: &str) -> PyResult<Self> {
+        if providers.is_empty() {
+            return Err(QiniuEmptyRegionsProvider::new_err("providers is empty"));
+        }
+        let strategy = match strategy {
+            "fallback" => CombineStrategy::Fallback,
+            "merge" => CombineStrategy::Merge,
+            _ => {
+                return Err(QiniuInvalidServiceNameError::new_err(format!(
+                    "Unknown combine strategy: {}",
+                    strategy
+                )))
+            }
+        };
+        Ok(Self(Box::new(CombinedRegionsProvider {
+            providers: providers.into_iter().map(|provider| provider.0).collect(),
+            strategy,
+        })))
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -520,45 +1136,299 @@ impl qiniu_sdk::http_client::RegionsProvider for RegionsProvider {
     }
 }
 
-impl RegionsProvider {
-    fn make_initializer(
-        region: qiniu_sdk::http_client::Region,
-        py: Python<'_>,
-    ) -> PyResult<Py<Region>> {
-        Py::new(
-            py,
-            (Region(region.to_owned()), RegionsProvider(Box::new(region))),
-        )
-    }
+#[derive(Clone, Debug)]
+struct TimeoutRegionsProvider {
+    provider: Box<dyn qiniu_sdk::http_client::RegionsProvider>,
+    timeout: Duration,
+    fallback: Option<Box<dyn qiniu_sdk::http_client::RegionsProvider>>,
 }
 
-impl From<Box<dyn qiniu_sdk::http_client::RegionsProvider>> for RegionsProvider {
-    fn from(provider: Box<dyn qiniu_sdk::http_client::RegionsProvider>) -> Self {
-        RegionsProvider(provider)
+impl qiniu_sdk::http_client::RegionsProvider for TimeoutRegionsProvider {
+    fn get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+        let result = async_std::task::block_on(async_std::future::timeout(
+            self.timeout,
+            self.provider.async_get(opts.to_owned()),
+        ));
+        match (result, &self.fallback) {
+            (Ok(result), _) => result,
+            (Err(_), Some(fallback)) => fallback.get(opts),
+            (Err(_), None) => Err(make_timeout_error(self.timeout)),
+        }
     }
-}
 
-impl From<RegionsProvider> for Box<dyn qiniu_sdk::http_client::RegionsProvider> {
-    fn from(provider: RegionsProvider) -> Self {
-        provider.0
+    fn get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        let result = async_std::task::block_on(async_std::future::timeout(
+            self.timeout,
+            self.provider.async_get_all(opts.to_owned()),
+        ));
+        match (result, &self.fallback) {
+            (Ok(result), _) => result,
+            (Err(_), Some(fallback)) => fallback.get_all(opts),
+            (Err(_), None) => Err(make_timeout_error(self.timeout)),
+        }
+    }
+
+    fn async_get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion>> {
+        Box::pin(async move {
+            let result =
+                async_std::future::timeout(self.timeout, self.provider.async_get(opts.to_owned()))
+                    .await;
+            match (result, &self.fallback) {
+                (Ok(result), _) => result,
+                (Err(_), Some(fallback)) => fallback.async_get(opts).await,
+                (Err(_), None) => Err(make_timeout_error(self.timeout)),
+            }
+        })
+    }
+
+    fn async_get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions>> {
+        Box::pin(async move {
+            let result = async_std::future::timeout(
+                self.timeout,
+                self.provider.async_get_all(opts.to_owned()),
+            )
+            .await;
+            match (result, &self.fallback) {
+                (Ok(result), _) => result,
+                (Err(_), Some(fallback)) => fallback.async_get_all(opts).await,
+                (Err(_), None) => Err(make_timeout_error(self.timeout)),
+            }
+        })
     }
 }
 
-/// 七牛存储区域
-///
-/// 提供七牛不同服务的终端地址列表
-#[pyclass(extends = RegionsProvider)]
-#[pyo3(
-    text_signature = "(region_id, /, s3_region_id = None, up_preferred_endpoints = None, up_alternative_endpoints = None, io_preferred_endpoints = None, io_alternative_endpoints = None, uc_preferred_endpoints = None, uc_preferred_endpoints = None, rs_preferred_endpoints = None, rs_alternative_endpoints = None, rsf_preferred_endpoints = None, rsf_alternative_endpoints = None, s3_preferred_endpoints = None, s3_alternative_endpoints = None, api_preferred_endpoints = None, api_alternative_endpoints = None)"
-)]
-#[derive(Clone)]
-struct Region(qiniu_sdk::http_client::Region);
+#[derive(Clone, Copy, Debug)]
+enum CombineStrategy {
+    Fallback,
+    Merge,
+}
 
-#[pymethods]
-impl Region {
-    #[new]
-    #[args(
-        s3_region_id = "None",
+#[derive(Clone, Debug)]
+struct CombinedRegionsProvider {
+    providers: Vec<Box<dyn qiniu_sdk::http_client::RegionsProvider>>,
+    strategy: CombineStrategy,
+}
+
+impl qiniu_sdk::http_client::RegionsProvider for CombinedRegionsProvider {
+    fn get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+        let mut last_err = None;
+        for provider in self.providers.iter() {
+            match provider.get(opts.to_owned()) {
+                Ok(region) => return Ok(region),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.unwrap())
+    }
+
+    fn get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        let mut merged = Vec::new();
+        let mut last_err = None;
+        for provider in self.providers.iter() {
+            match provider.get_all(opts.to_owned()) {
+                Ok(regions) => match self.strategy {
+                    CombineStrategy::Fallback => return Ok(regions),
+                    CombineStrategy::Merge => merge_regions(&mut merged, regions.into_regions()),
+                },
+                Err(err) => last_err = Some(err),
+            }
+        }
+        self.finish_merge(merged, last_err)
+    }
+
+    fn async_get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion>> {
+        Box::pin(async move {
+            let mut last_err = None;
+            for provider in self.providers.iter() {
+                match provider.async_get(opts.to_owned()).await {
+                    Ok(region) => return Ok(region),
+                    Err(err) => last_err = Some(err),
+                }
+            }
+            Err(last_err.unwrap())
+        })
+    }
+
+    fn async_get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions>> {
+        Box::pin(async move {
+            let mut merged = Vec::new();
+            let mut last_err = None;
+            for provider in self.providers.iter() {
+                match provider.async_get_all(opts.to_owned()).await {
+                    Ok(regions) => match self.strategy {
+                        CombineStrategy::Fallback => return Ok(regions),
+                        CombineStrategy::Merge => {
+                            merge_regions(&mut merged, regions.into_regions())
+                        }
+                    },
+                    Err(err) => last_err = Some(err),
+                }
+            }
+            self.finish_merge(merged, last_err)
+        })
+    }
+}
+
+impl CombinedRegionsProvider {
+    /// 合并后的区域列表为空时，返回最后一个接口的错误
+    fn finish_merge(
+        &self,
+        merged: Vec<qiniu_sdk::http_client::Region>,
+        last_err: Option<qiniu_sdk::http_client::ResponseError>,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        match (merged.is_empty(), last_err) {
+            (true, Some(err)) => Err(err),
+            _ => Ok(merged.into()),
+        }
+    }
+}
+
+fn merge_regions(
+    merged: &mut Vec<qiniu_sdk::http_client::Region>,
+    regions: Vec<qiniu_sdk::http_client::Region>,
+) {
+    for region in regions {
+        if !merged.contains(&region) {
+            merged.push(region);
+        }
+    }
+}
+
+fn parse_timeout_secs(timeout_secs: f64) -> PyResult<Duration> {
+    if !(0.0..u64::MAX as f64).contains(&timeout_secs) {
+        return Err(PyValueError::new_err(format!(
+            "timeout_secs must be a non-negative finite number: {}",
+            timeout_secs
+        )));
+    }
+    Ok(Duration::from_secs_f64(timeout_secs))
+}
+
+fn convert_credential_get_error(err: std::io::Error) -> PyErr {
+    QiniuApiCallError::from_err(MaybeOwned::Owned(
+        qiniu_sdk::http_client::ResponseError::new(
+            qiniu_sdk::http::ResponseErrorKind::LocalIoError.into(),
+            err,
+        ),
+    ))
+}
+
+fn make_timeout_error(timeout: Duration) -> qiniu_sdk::http_client::ResponseError {
+    qiniu_sdk::http_client::ResponseError::new(
+        qiniu_sdk::http::ResponseErrorKind::TimeoutError.into(),
+        format!("Operation timed out after {:?}", timeout),
+    )
+}
+
+#[derive(Clone, Debug)]
+struct ServiceEndpointsProvider {
+    endpoints: qiniu_sdk::http_client::RegionsProviderEndpoints<
+        Box<dyn qiniu_sdk::http_client::RegionsProvider>,
+    >,
+    service_names: [qiniu_sdk::http_client::ServiceName; 1],
+}
+
+impl qiniu_sdk::http_client::EndpointsProvider for ServiceEndpointsProvider {
+    fn get_endpoints<'e>(
+        &'e self,
+        _options: qiniu_sdk::http_client::EndpointsGetOptions<'_>,
+    ) -> qiniu_sdk::http_client::ApiResult<Cow<'e, qiniu_sdk::http_client::Endpoints>> {
+        self.endpoints.get_endpoints(
+            EndpointsGetOptions::builder()
+                .service_names(&self.service_names)
+                .build(),
+        )
+    }
+
+    fn async_get_endpoints<'a>(
+        &'a self,
+        _options: qiniu_sdk::http_client::EndpointsGetOptions<'a>,
+    ) -> BoxFuture<'a, qiniu_sdk::http_client::ApiResult<Cow<'a, qiniu_sdk::http_client::Endpoints>>>
+    {
+        self.endpoints.async_get_endpoints(
+            EndpointsGetOptions::builder()
+                .service_names(&self.service_names)
+                .build(),
+        )
+    }
+}
+
+impl RegionsProvider {
+    fn new_static(
+        regions: impl IntoIterator<Item = qiniu_sdk::http_client::Region>,
+    ) -> PyResult<Self> {
+        let mut iter = regions.into_iter();
+        if let Some(region) = iter.next() {
+            let mut provider = qiniu_sdk::http_client::StaticRegionsProvider::new(region);
+            provider.extend(iter);
+            Ok(Self(Box::new(provider)))
+        } else {
+            Err(QiniuEmptyRegionsProvider::new_err("regions is empty"))
+        }
+    }
+
+    fn make_initializer(
+        region: qiniu_sdk::http_client::Region,
+        py: Python<'_>,
+    ) -> PyResult<Py<Region>> {
+        Py::new(
+            py,
+            (Region(region.to_owned()), RegionsProvider(Box::new(region))),
+        )
+    }
+}
+
+impl From<Box<dyn qiniu_sdk::http_client::RegionsProvider>> for RegionsProvider {
+    fn from(provider: Box<dyn qiniu_sdk::http_client::RegionsProvider>) -> Self {
+        RegionsProvider(provider)
+    }
+}
+
+impl From<RegionsProvider> for Box<dyn qiniu_sdk::http_client::RegionsProvider> {
+    fn from(provider: RegionsProvider) -> Self {
+        provider.0
+    }
+}
+
+/// 七牛存储区域
+///
+/// 提供七牛不同服务的终端地址列表
+#[pyclass(extends = RegionsProvider)]
+#[pyo3(
+    text_signature = "(region_id, /, s3_region_id = None, up_preferred_endpoints = None, up_alternative_endpoints = None, io_preferred_endpoints = None, io_alternative_endpoints = None, uc_preferred_endpoints = None, uc_preferred_endpoints = None, rs_preferred_endpoints = None, rs_alternative_endpoints = None, rsf_preferred_endpoints = None, rsf_alternative_endpoints = None, s3_preferred_endpoints = None, s3_alternative_endpoints = None, api_preferred_endpoints = None, api_alternative_endpoints = None)"
+)]
+#[derive(Clone)]
+struct Region(qiniu_sdk::http_client::Region);
+
+#[pymethods]
+impl Region {
+    #[new]
+    #[args(
+        s3_region_id = "None",
         up_preferred_endpoints = "None",
         up_alternative_endpoints = "None",
         io_preferred_endpoints = "None",
@@ -781,6 +1651,233 @@ impl Region {
         encapsulate_endpoint_vec(self.0.s3_alternative_endpoints())
     }
 
+    /// 替换上传服务终端列表，返回新的区域，原区域不会被修改
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn with_up_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<Py<Region>> {
+        RegionsProvider::make_initializer(
+            self.replace_endpoints(qiniu_sdk::http_client::ServiceName::Up, &endpoints.0),
+            py,
+        )
+    }
+
+    /// 替换下载服务终端列表，返回新的区域，原区域不会被修改
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn with_io_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<Py<Region>> {
+        RegionsProvider::make_initializer(
+            self.replace_endpoints(qiniu_sdk::http_client::ServiceName::Io, &endpoints.0),
+            py,
+        )
+    }
+
+    /// 替换存储空间管理服务终端列表，返回新的区域，原区域不会被修改
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn with_uc_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<Py<Region>> {
+        RegionsProvider::make_initializer(
+            self.replace_endpoints(qiniu_sdk::http_client::ServiceName::Uc, &endpoints.0),
+            py,
+        )
+    }
+
+    /// 替换元数据管理服务终端列表，返回新的区域，原区域不会被修改
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn with_rs_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<Py<Region>> {
+        RegionsProvider::make_initializer(
+            self.replace_endpoints(qiniu_sdk::http_client::ServiceName::Rs, &endpoints.0),
+            py,
+        )
+    }
+
+    /// 替换元数据列举服务终端列表，返回新的区域，原区域不会被修改
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn with_rsf_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<Py<Region>> {
+        RegionsProvider::make_initializer(
+            self.replace_endpoints(qiniu_sdk::http_client::ServiceName::Rsf, &endpoints.0),
+            py,
+        )
+    }
+
+    /// 替换API 入口服务终端列表，返回新的区域，原区域不会被修改
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn with_api_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<Py<Region>> {
+        RegionsProvider::make_initializer(
+            self.replace_endpoints(qiniu_sdk::http_client::ServiceName::Api, &endpoints.0),
+            py,
+        )
+    }
+
+    /// 替换S3 入口服务终端列表，返回新的区域，原区域不会被修改
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn with_s3_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<Py<Region>> {
+        RegionsProvider::make_initializer(
+            self.replace_endpoints(qiniu_sdk::http_client::ServiceName::S3, &endpoints.0),
+            py,
+        )
+    }
+
+    /// 获取上传服务的首个主要终端地址
+    ///
+    /// 如果主要终端列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_preferred_upload_endpoint(&self) -> PyResult<Endpoint> {
+        self.first_preferred_endpoint(ServiceName::Up)
+    }
+
+    /// 获取下载服务的首个主要终端地址
+    ///
+    /// 如果主要终端列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_preferred_download_endpoint(&self) -> PyResult<Endpoint> {
+        self.first_preferred_endpoint(ServiceName::Io)
+    }
+
+    /// 获取存储空间管理服务的首个主要终端地址
+    ///
+    /// 如果主要终端列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_preferred_uc_endpoint(&self) -> PyResult<Endpoint> {
+        self.first_preferred_endpoint(ServiceName::Uc)
+    }
+
+    /// 获取元数据管理服务的首个主要终端地址
+    ///
+    /// 如果主要终端列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_preferred_rs_endpoint(&self) -> PyResult<Endpoint> {
+        self.first_preferred_endpoint(ServiceName::Rs)
+    }
+
+    /// 获取元数据列举服务的首个主要终端地址
+    ///
+    /// 如果主要终端列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_preferred_rsf_endpoint(&self) -> PyResult<Endpoint> {
+        self.first_preferred_endpoint(ServiceName::Rsf)
+    }
+
+    /// 获取API 入口服务的首个主要终端地址
+    ///
+    /// 如果主要终端列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_preferred_api_endpoint(&self) -> PyResult<Endpoint> {
+        self.first_preferred_endpoint(ServiceName::Api)
+    }
+
+    /// 获取S3 入口服务的首个主要终端地址
+    ///
+    /// 如果主要终端列表为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_preferred_s3_endpoint(&self) -> PyResult<Endpoint> {
+        self.first_preferred_endpoint(ServiceName::S3)
+    }
+
+    /// 通过 UC 服务终端地址查询存储空间所在的区域
+    ///
+    /// 相当于只查询一次的 `BucketRegionsQueryer`，如果获取认证信息失败、网络错误或认证失败，将抛出 `QiniuApiCallError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(uc_endpoint, credential, /, bucket, use_https = False)")]
+    #[args(use_https = "false")]
+    fn from_endpoint(
+        uc_endpoint: Endpoint,
+        credential: CredentialProvider,
+        bucket: &str,
+        use_https: bool,
+        py: Python<'_>,
+    ) -> PyResult<Py<Region>> {
+        let region = py.allow_threads(|| {
+            let credential =
+                qiniu_sdk::credential::CredentialProvider::get(&credential, Default::default())
+                    .map_err(convert_credential_get_error)?;
+            let provider = Self::make_endpoint_queryer(uc_endpoint, use_https)
+                .query(credential.access_key().to_owned(), bucket);
+            RegionsGetOptions::default().get(&provider)
+        })?;
+        RegionsProvider::make_initializer(region, py)
+    }
+
+    /// 异步通过 UC 服务终端地址查询存储空间所在的区域
+    ///
+    /// 相当于只查询一次的 `BucketRegionsQueryer`，如果获取认证信息失败、网络错误或认证失败，将抛出 `QiniuApiCallError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(uc_endpoint, credential, /, bucket, use_https = False)")]
+    #[args(use_https = "false")]
+    fn async_from_endpoint<'p>(
+        uc_endpoint: Endpoint,
+        credential: CredentialProvider,
+        bucket: String,
+        use_https: bool,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let credential = qiniu_sdk::credential::CredentialProvider::async_get(
+                &credential,
+                Default::default(),
+            )
+            .await
+            .map_err(convert_credential_get_error)?;
+            let provider = Self::make_endpoint_queryer(uc_endpoint, use_https)
+                .query(credential.access_key().to_owned(), &bucket);
+            let region = RegionsGetOptions::default().async_get(&provider).await?;
+            Python::with_gil(|py| RegionsProvider::make_initializer(region, py))
+        })
+    }
+
+    /// 检查区域的各个服务是否都配置了主要终端地址
+    ///
+    /// 返回每个缺少主要终端地址的服务的警告信息，返回空列表表示区域配置完整
+    #[pyo3(text_signature = "($self)")]
+    fn validate(&self) -> Vec<String> {
+        self.missing_services()
+            .map(|name| format!("{} service has no preferred endpoints", name))
+            .collect()
+    }
+
+    /// 严格检查区域的各个服务是否都配置了主要终端地址
+    ///
+    /// 遇到首个缺少主要终端地址的服务时，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn validate_strict(&self) -> PyResult<()> {
+        if let Some(name) = self.missing_services().next() {
+            Err(QiniuEmptyEndpoints::new_err(format!(
+                "{} service has no preferred endpoints",
+                name
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 创建仅返回该区域指定服务终端地址列表的终端地址列表获取接口
+    #[pyo3(text_signature = "($self, service)")]
+    fn to_endpoints_provider(
+        &self,
+        service: ServiceName,
+        py: Python<'_>,
+    ) -> PyResult<Py<EndpointsProvider>> {
+        Py::new(
+            py,
+            EndpointsProvider(Box::new(self.service_endpoints(service).to_owned())),
+        )
+    }
+
+    /// 终端地址是否属于该区域的任意服务，包括主要终端地址和备选终端地址
+    fn __contains__(&self, endpoint: Endpoint) -> bool {
+        [
+            ServiceName::Up,
+            ServiceName::Io,
+            ServiceName::Uc,
+            ServiceName::Rs,
+            ServiceName::Rsf,
+            ServiceName::Api,
+            ServiceName::S3,
+        ]
+        .into_iter()
+        .map(|service| self.service_endpoints(service))
+        .any(|endpoints| {
+            endpoints.preferred().contains(&endpoint.0)
+                || endpoints.alternative().contains(&endpoint.0)
+        })
+    }
+
     fn __repr__(&self) -> String {
         format!("{:?}", self.0)
     }
@@ -792,9 +1889,163 @@ impl Region {
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
         match op {
             CompareOp::Eq => (self.0 == other.0).to_object(py),
+            CompareOp::Ne => (self.0 != other.0).to_object(py),
             _ => py.NotImplemented(),
         }
     }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.region_id().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Region {
+    fn make_endpoint_queryer(
+        uc_endpoint: Endpoint,
+        use_https: bool,
+    ) -> qiniu_sdk::http_client::BucketRegionsQueryer {
+        let mut builder = qiniu_sdk::http_client::BucketRegionsQueryer::builder();
+        builder.use_https(use_https);
+        builder.uc_endpoints({
+            let mut endpoints = qiniu_sdk::http_client::EndpointsBuilder::default();
+            endpoints.add_preferred_endpoints(vec![uc_endpoint.0]);
+            endpoints.build()
+        });
+        builder.in_memory()
+    }
+
+    fn service_endpoints(&self, service: ServiceName) -> &qiniu_sdk::http_client::Endpoints {
+        match service {
+            ServiceName::Up => self.0.up(),
+            ServiceName::Io => self.0.io(),
+            ServiceName::Uc => self.0.uc(),
+            ServiceName::Rs => self.0.rs(),
+            ServiceName::Rsf => self.0.rsf(),
+            ServiceName::Api => self.0.api(),
+            ServiceName::S3 => self.0.s3(),
+        }
+    }
+
+    fn missing_services(&self) -> impl Iterator<Item = &'static str> + '_ {
+        [
+            ("up", ServiceName::Up),
+            ("download", ServiceName::Io),
+            ("uc", ServiceName::Uc),
+            ("rs", ServiceName::Rs),
+            ("rsf", ServiceName::Rsf),
+            ("api", ServiceName::Api),
+            ("s3", ServiceName::S3),
+        ]
+        .into_iter()
+        .filter(|(_, service)| self.service_endpoints(*service).preferred().is_empty())
+        .map(|(name, _)| name)
+    }
+
+    fn first_preferred_endpoint(&self, service: ServiceName) -> PyResult<Endpoint> {
+        self.service_endpoints(service)
+            .preferred()
+            .first()
+            .cloned()
+            .map(Endpoint)
+            .ok_or_else(|| QiniuEmptyEndpoints::new_err("empty preferred endpoints"))
+    }
+
+    fn replace_endpoints(
+        &self,
+        service_name: qiniu_sdk::http_client::ServiceName,
+        endpoints: &qiniu_sdk::http_client::Endpoints,
+    ) -> qiniu_sdk::http_client::Region {
+        let region = &self.0;
+        let mut builder = qiniu_sdk::http_client::Region::builder(region.region_id().to_owned());
+        builder.s3_region_id(region.s3_region_id().to_owned());
+        let up_endpoints = if service_name == qiniu_sdk::http_client::ServiceName::Up {
+            endpoints
+        } else {
+            region.up()
+        };
+        builder.add_up_preferred_endpoints(up_endpoints.preferred().to_vec());
+        builder.add_up_alternative_endpoints(up_endpoints.alternative().to_vec());
+        let io_endpoints = if service_name == qiniu_sdk::http_client::ServiceName::Io {
+            endpoints
+        } else {
+            region.io()
+        };
+        builder.add_io_preferred_endpoints(io_endpoints.preferred().to_vec());
+        builder.add_io_alternative_endpoints(io_endpoints.alternative().to_vec());
+        let uc_endpoints = if service_name == qiniu_sdk::http_client::ServiceName::Uc {
+            endpoints
+        } else {
+            region.uc()
+        };
+        builder.add_uc_preferred_endpoints(uc_endpoints.preferred().to_vec());
+        builder.add_uc_alternative_endpoints(uc_endpoints.alternative().to_vec());
+        let rs_endpoints = if service_name == qiniu_sdk::http_client::ServiceName::Rs {
+            endpoints
+        } else {
+            region.rs()
+        };
+        builder.add_rs_preferred_endpoints(rs_endpoints.preferred().to_vec());
+        builder.add_rs_alternative_endpoints(rs_endpoints.alternative().to_vec());
+        let rsf_endpoints = if service_name == qiniu_sdk::http_client::ServiceName::Rsf {
+            endpoints
+        } else {
+            region.rsf()
+        };
+        builder.add_rsf_preferred_endpoints(rsf_endpoints.preferred().to_vec());
+        builder.add_rsf_alternative_endpoints(rsf_endpoints.alternative().to_vec());
+        let api_endpoints = if service_name == qiniu_sdk::http_client::ServiceName::Api {
+            endpoints
+        } else {
+            region.api()
+        };
+        builder.add_api_preferred_endpoints(api_endpoints.preferred().to_vec());
+        builder.add_api_alternative_endpoints(api_endpoints.alternative().to_vec());
+        let s3_endpoints = if service_name == qiniu_sdk::http_client::ServiceName::S3 {
+            endpoints
+        } else {
+            region.s3()
+        };
+        builder.add_s3_preferred_endpoints(s3_endpoints.preferred().to_vec());
+        builder.add_s3_alternative_endpoints(s3_endpoints.alternative().to_vec());
+        builder.build()
+    }
+}
+
+macro_rules! impl_context_manager {
+    ($name:ident) => {
+        #[pymethods]
+        impl $name {
+            fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+                slf
+            }
+
+            /// 区域信息缓存在 `auto_persistent` 启用时已经实时持久化，因此退出时无需额外操作
+            fn __exit__(
+                &self,
+                _exc_type: Option<&PyAny>,
+                _exc_value: Option<&PyAny>,
+                _traceback: Option<&PyAny>,
+            ) {
+            }
+
+            fn __aenter__<'p>(slf: PyRef<'p, Self>, py: Python<'p>) -> PyResult<&'p PyAny> {
+                let slf: PyObject = slf.into_py(py);
+                pyo3_asyncio::async_std::future_into_py(py, async move { Ok(slf) })
+            }
+
+            fn __aexit__<'p>(
+                &self,
+                _exc_type: Option<&PyAny>,
+                _exc_value: Option<&PyAny>,
+                _traceback: Option<&PyAny>,
+                py: Python<'p>,
+            ) -> PyResult<&'p PyAny> {
+                pyo3_asyncio::async_std::future_into_py(py, async move { Ok(()) })
+            }
+        }
+    };
 }
 
 /// 七牛所有区域信息查询器
@@ -803,7 +2054,11 @@ impl Region {
     text_signature = "(credential_provider, /, auto_persistent = True, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None)"
 )]
 #[derive(Clone)]
-struct AllRegionsProvider;
+struct AllRegionsProvider {
+    settings: AllRegionsSettings,
+    uc_endpoints: Arc<RwLock<qiniu_sdk::http_client::Endpoints>>,
+    provider: SwappableRegionsProvider,
+}
 
 #[pymethods]
 impl AllRegionsProvider {
@@ -824,19 +2079,19 @@ impl AllRegionsProvider {
         cache_lifetime_secs: Option<u64>,
         shrink_interval_secs: Option<u64>,
     ) -> (Self, RegionsProvider) {
-        let builder = Self::new_builder(
+        let settings = AllRegionsSettings {
             credential_provider,
             use_https,
-            uc_endpoints,
             cache_lifetime_secs,
             shrink_interval_secs,
-        );
-        (
-            Self,
-            RegionsProvider(Box::new(
-                builder.default_load_or_create_from(auto_persistent),
-            )),
-        )
+            cache: AllRegionsCache::Path {
+                path: default_all_regions_cache_path(),
+                auto_persistent,
+            },
+        };
+        let uc_endpoints = make_uc_endpoints(uc_endpoints);
+        let provider = settings.build(&uc_endpoints);
+        Self::new_info(settings, uc_endpoints, provider)
     }
 
     #[staticmethod]
@@ -861,20 +2116,19 @@ impl AllRegionsProvider {
         shrink_interval_secs: Option<u64>,
         py: Python<'_>,
     ) -> PyResult<Py<Self>> {
-        let builder = Self::new_builder(
+        let settings = AllRegionsSettings {
             credential_provider,
             use_https,
-            uc_endpoints,
             cache_lifetime_secs,
             shrink_interval_secs,
-        );
-        Py::new(
-            py,
-            (
-                Self,
-                RegionsProvider(Box::new(builder.load_or_create_from(path, auto_persistent))),
-            ),
-        )
+            cache: AllRegionsCache::Path {
+                path,
+                auto_persistent,
+            },
+        };
+        let uc_endpoints = make_uc_endpoints(uc_endpoints);
+        let provider = settings.build(&uc_endpoints);
+        Py::new(py, Self::new_info(settings, uc_endpoints, provider))
     }
 
     #[staticmethod]
@@ -896,40 +2150,325 @@ impl AllRegionsProvider {
         shrink_interval_secs: Option<u64>,
         py: Python<'_>,
     ) -> PyResult<Py<Self>> {
-        let builder = Self::new_builder(
+        let settings = AllRegionsSettings {
             credential_provider,
             use_https,
-            uc_endpoints,
             cache_lifetime_secs,
             shrink_interval_secs,
-        );
-        Py::new(py, (Self, RegionsProvider(Box::new(builder.in_memory()))))
+            cache: AllRegionsCache::InMemory,
+        };
+        let uc_endpoints = make_uc_endpoints(uc_endpoints);
+        let provider = settings.build(&uc_endpoints);
+        Py::new(py, Self::new_info(settings, uc_endpoints, provider))
     }
-}
 
-impl AllRegionsProvider {
-    fn new_builder(
+    /// 异步创建使用内存缓存的七牛所有区域信息查询器
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(credential_provider, /, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None)"
+    )]
+    #[args(
+        use_https = "true",
+        uc_endpoints = "None",
+        cache_lifetime_secs = "None",
+        shrink_interval_secs = "None"
+    )]
+    fn async_in_memory(
         credential_provider: CredentialProvider,
         use_https: bool,
         uc_endpoints: Option<Endpoints>,
         cache_lifetime_secs: Option<u64>,
         shrink_interval_secs: Option<u64>,
-    ) -> qiniu_sdk::http_client::AllRegionsProviderBuilder {
-        let mut builder = qiniu_sdk::http_client::AllRegionsProvider::builder(credential_provider);
-        builder = builder.use_https(use_https);
-        if let Some(uc_endpoints) = uc_endpoints {
-            builder = builder.uc_endpoints(uc_endpoints.0);
+        py: Python<'_>,
+    ) -> PyResult<&PyAny> {
+        let settings = AllRegionsSettings {
+            credential_provider,
+            use_https,
+            cache_lifetime_secs,
+            shrink_interval_secs,
+            cache: AllRegionsCache::InMemory,
+        };
+        let uc_endpoints = make_uc_endpoints(uc_endpoints);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let (settings, uc_endpoints, provider) = async_std::task::spawn_blocking(move || {
+                let provider = settings.build(&uc_endpoints);
+                (settings, uc_endpoints, provider)
+            })
+            .await;
+            Python::with_gil(|py| Py::new(py, Self::new_info(settings, uc_endpoints, provider)))
+        })
+    }
+
+    /// 异步创建使用默认缓存路径的七牛所有区域信息查询器
+    ///
+    /// 读取缓存文件的操作将在后台线程中完成，不会阻塞事件循环
+    #[staticmethod]
+    #[pyo3(
+        text_signature = "(credential_provider, /, auto_persistent = True, use_https = True, uc_endpoints = None, cache_lifetime_secs = None, shrink_interval_secs = None)"
+    )]
+    #[args(
+        auto_persistent = "true",
+        use_https = "true",
+        uc_endpoints = "None",
+        cache_lifetime_secs = "None",
+        shrink_interval_secs = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn async_default_load_or_create_from(
+        credential_provider: CredentialProvider,
+        auto_persistent: bool,
+        use_https: bool,
+        uc_endpoints: Option<Endpoints>,
+        cache_lifetime_secs: Option<u64>,
+        shrink_interval_secs: Option<u64>,
+        py: Python<'_>,
+    ) -> PyResult<&PyAny> {
+        let settings = AllRegionsSettings {
+            credential_provider,
+            use_https,
+            cache_lifetime_secs,
+            shrink_interval_secs,
+            cache: AllRegionsCache::Path {
+                path: default_all_regions_cache_path(),
+                auto_persistent,
+            },
+        };
+        let uc_endpoints = make_uc_endpoints(uc_endpoints);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let (settings, uc_endpoints, provider) = async_std::task::spawn_blocking(move || {
+                let provider = settings.build(&uc_endpoints);
+                (settings, uc_endpoints, provider)
+            })
+            .await;
+            Python::with_gil(|py| Py::new(py, Self::new_info(settings, uc_endpoints, provider)))
+        })
+    }
+
+    /// 获取区域信息缓存文件路径
+    ///
+    /// 使用默认缓存路径时返回默认缓存文件路径，使用内存缓存时返回 `None`
+    #[pyo3(text_signature = "($self)")]
+    fn get_cache_path(&self) -> Option<String> {
+        if let AllRegionsCache::Path { path, .. } = &self.settings.cache {
+            Some(path.to_string_lossy().into_owned())
+        } else {
+            None
         }
-        if let Some(cache_lifetime_secs) = cache_lifetime_secs {
+    }
+
+    /// 获取缓存时长，单位为秒
+    ///
+    /// 未指定 `cache_lifetime_secs` 时返回 SDK 的默认缓存时长
+    #[pyo3(text_signature = "($self)")]
+    fn get_cache_lifetime_secs(&self) -> u64 {
+        self.settings.cache_lifetime().as_secs()
+    }
+
+    /// 获取清理间隔时长，单位为秒
+    ///
+    /// 未指定 `shrink_interval_secs` 时返回 SDK 的默认清理间隔时长
+    #[pyo3(text_signature = "($self)")]
+    fn get_shrink_interval_secs(&self) -> u64 {
+        self.settings.shrink_interval().as_secs()
+    }
+
+    /// 获取当前使用的 UC 服务终端地址列表
+    #[pyo3(text_signature = "($self)")]
+    fn get_uc_endpoints(&self) -> PyResult<Py<Endpoints>> {
+        encapsulate_endpoints(&self.uc_endpoints.read().unwrap())
+    }
+
+    /// 在运行时替换 UC 服务终端地址列表
+    ///
+    /// 之后的区域信息查询都将使用新的终端地址，区域信息缓存文件不会被清除。
+    /// 如果 `endpoints` 的主要终端地址和备选终端地址均为空，将抛出 `QiniuEmptyEndpoints` 异常
+    #[pyo3(text_signature = "($self, endpoints)")]
+    fn set_uc_endpoints(&self, endpoints: Endpoints, py: Python<'_>) -> PyResult<()> {
+        if endpoints.0.preferred().is_empty() && endpoints.0.alternative().is_empty() {
+            return Err(QiniuEmptyEndpoints::new_err("empty uc endpoints"));
+        }
+        let provider = py.allow_threads(|| self.settings.build(&endpoints.0));
+        let mut uc_endpoints = self.uc_endpoints.write().unwrap();
+        self.provider.replace(provider);
+        *uc_endpoints = endpoints.0;
+        Ok(())
+    }
+}
+
+impl_context_manager!(AllRegionsProvider);
+
+#[pymethods]
+impl AllRegionsProvider {
+    /// 获取当前所有区域信息的快照
+    ///
+    /// 返回的区域信息提供者不会再发起任何网络请求，如果获取区域信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn to_static_provider(slf: PyRef<'_, Self>, py: Python<'_>) -> PyResult<RegionsProvider> {
+        let provider: &RegionsProvider = slf.as_ref();
+        let regions = py.allow_threads(|| RegionsGetOptions::default().get_all(&*provider.0))?;
+        RegionsProvider::new_static(regions)
+    }
+
+    /// 异步获取当前所有区域信息的快照
+    ///
+    /// 返回的区域信息提供者不会再发起任何网络请求，如果获取区域信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn async_to_static_provider<'p>(slf: PyRef<'_, Self>, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let provider = slf.as_ref().0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let regions = RegionsGetOptions::default()
+                .async_get_all(&*provider)
+                .await?;
+            RegionsProvider::new_static(regions)
+        })
+    }
+}
+
+impl AllRegionsProvider {
+    fn new_info(
+        settings: AllRegionsSettings,
+        uc_endpoints: qiniu_sdk::http_client::Endpoints,
+        provider: Box<dyn qiniu_sdk::http_client::RegionsProvider>,
+    ) -> (Self, RegionsProvider) {
+        let provider = SwappableRegionsProvider::new(provider);
+        (
+            Self {
+                settings,
+                uc_endpoints: Arc::new(RwLock::new(uc_endpoints)),
+                provider: provider.to_owned(),
+            },
+            RegionsProvider(Box::new(provider)),
+        )
+    }
+}
+
+fn make_uc_endpoints(uc_endpoints: Option<Endpoints>) -> qiniu_sdk::http_client::Endpoints {
+    uc_endpoints.map_or_else(
+        || qiniu_sdk::http_client::Endpoints::public_uc_endpoints().to_owned(),
+        |endpoints| endpoints.0,
+    )
+}
+
+#[derive(Clone, Debug)]
+enum AllRegionsCache {
+    Path {
+        path: PathBuf,
+        auto_persistent: bool,
+    },
+    InMemory,
+}
+
+/// 创建七牛所有区域信息查询器的参数，替换 UC 服务终端地址时用于重新创建查询器
+#[derive(Clone, Debug)]
+struct AllRegionsSettings {
+    credential_provider: CredentialProvider,
+    use_https: bool,
+    cache_lifetime_secs: Option<u64>,
+    shrink_interval_secs: Option<u64>,
+    cache: AllRegionsCache,
+}
+
+impl AllRegionsSettings {
+    fn build(
+        &self,
+        uc_endpoints: &qiniu_sdk::http_client::Endpoints,
+    ) -> Box<dyn qiniu_sdk::http_client::RegionsProvider> {
+        let mut builder = qiniu_sdk::http_client::AllRegionsProvider::builder(
+            self.credential_provider.to_owned(),
+        )
+        .use_https(self.use_https)
+        .uc_endpoints(uc_endpoints.to_owned());
+        if let Some(cache_lifetime_secs) = self.cache_lifetime_secs {
             builder = builder.cache_lifetime(Duration::from_secs(cache_lifetime_secs));
         }
-        if let Some(shrink_interval_secs) = shrink_interval_secs {
+        if let Some(shrink_interval_secs) = self.shrink_interval_secs {
             builder = builder.shrink_interval(Duration::from_secs(shrink_interval_secs));
         }
-        builder
+        match &self.cache {
+            AllRegionsCache::Path {
+                path,
+                auto_persistent,
+            } => Box::new(builder.load_or_create_from(path, *auto_persistent)),
+            AllRegionsCache::InMemory => Box::new(builder.in_memory()),
+        }
+    }
+
+    fn cache_lifetime(&self) -> Duration {
+        Duration::from_secs(
+            self.cache_lifetime_secs
+                .unwrap_or(DEFAULT_ALL_REGIONS_CACHE_LIFETIME_SECS),
+        )
+    }
+
+    fn shrink_interval(&self) -> Duration {
+        Duration::from_secs(
+            self.shrink_interval_secs
+                .unwrap_or(DEFAULT_ALL_REGIONS_SHRINK_INTERVAL_SECS),
+        )
+    }
+}
+
+/// 可以在运行时被替换的区域信息提供者
+#[derive(Clone, Debug)]
+struct SwappableRegionsProvider(Arc<RwLock<Box<dyn qiniu_sdk::http_client::RegionsProvider>>>);
+
+impl SwappableRegionsProvider {
+    fn new(provider: Box<dyn qiniu_sdk::http_client::RegionsProvider>) -> Self {
+        Self(Arc::new(RwLock::new(provider)))
+    }
+
+    fn current(&self) -> Box<dyn qiniu_sdk::http_client::RegionsProvider> {
+        self.0.read().unwrap().to_owned()
+    }
+
+    fn replace(&self, provider: Box<dyn qiniu_sdk::http_client::RegionsProvider>) {
+        *self.0.write().unwrap() = provider;
+    }
+}
+
+impl qiniu_sdk::http_client::RegionsProvider for SwappableRegionsProvider {
+    fn get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion> {
+        self.current().get(opts)
+    }
+
+    fn get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions> {
+        self.current().get_all(opts)
+    }
+
+    fn async_get(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegion>> {
+        let provider = self.current();
+        Box::pin(async move { provider.async_get(opts).await })
+    }
+
+    fn async_get_all(
+        &self,
+        opts: qiniu_sdk::http_client::RegionsGetOptions,
+    ) -> BoxFuture<'_, qiniu_sdk::http_client::ApiResult<qiniu_sdk::http_client::GotRegions>> {
+        let provider = self.current();
+        Box::pin(async move { provider.async_get_all(opts).await })
     }
 }
 
+const DEFAULT_ALL_REGIONS_CACHE_LIFETIME_SECS: u64 = 86400;
+const DEFAULT_ALL_REGIONS_SHRINK_INTERVAL_SECS: u64 = 86400;
+const DEFAULT_ALL_REGIONS_CACHE_FILE_NAME: &str = "all-regions-cache.json";
+
+/// 与 SDK 保持一致的默认区域信息缓存文件路径
+fn default_all_regions_cache_path() -> PathBuf {
+    std::env::temp_dir()
+        .join("qiniu-rust-sdk")
+        .join(DEFAULT_ALL_REGIONS_CACHE_FILE_NAME)
+}
+
 /// 存储空间相关区域查询构建器
 #[pyclass]
 #[pyo3(
@@ -1032,6 +2571,8 @@ impl BucketRegionsQueryer {
     }
 }
 
+impl_context_manager!(BucketRegionsQueryer);
+
 impl BucketRegionsQueryer {
     fn make_queryer_builder(
         use_https: bool,
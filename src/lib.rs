@@ -6,6 +6,7 @@ mod exceptions;
 mod http;
 mod http_client;
 mod objects;
+mod processing;
 mod upload;
 mod upload_token;
 mod utils;
@@ -28,6 +29,7 @@ fn qiniu_sdk_bindings(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_submodule(objects::create_module(py)?)?;
     m.add_submodule(upload::create_module(py)?)?;
     m.add_submodule(download::create_module(py)?)?;
+    m.add_submodule(processing::create_module(py)?)?;
     m.add_submodule(utils::create_module(py)?)?;
 
     return Ok(());
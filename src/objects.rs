@@ -1,36 +1,59 @@
 use super::{
     credential::CredentialProvider,
-    exceptions::QiniuApiCallError,
+    exceptions::{
+        QiniuApiCallError, QiniuInvalidConcurrency, QiniuInvalidDomainWithPortError,
+        QiniuInvalidMethodError, QiniuInvalidTagError, QiniuIoError, QiniuJsonError,
+        QiniuTimeError, QiniuUnsupportedTypeError,
+    },
     http::{HttpResponseParts, HttpResponsePartsMut},
     http_client::{
         BucketRegionsQueryer, Endpoints, HttpClient, JsonResponse, RegionsProvider,
         RequestBuilderPartsRef,
     },
-    utils::{convert_api_call_error, convert_json_value_to_py_object, parse_mime},
+    upload_token::{make_upload_policy, UploadToken},
+    utils::{
+        convert_api_call_error, convert_json_value_to_py_object, is_unauthorized_error,
+        parse_header_name, parse_header_value, parse_method, parse_mime, parse_uri,
+    },
 };
 use anyhow::Result as AnyResult;
 use futures::{
-    lock::Mutex as AsyncMutex, stream::Peekable as AsyncPeekable, StreamExt, TryStreamExt,
+    lock::Mutex as AsyncMutex, stream::Peekable as AsyncPeekable, AsyncReadExt, StreamExt,
+    TryStreamExt,
 };
 use indexmap::IndexMap;
 use maybe_owned::MaybeOwned;
 use mime::Mime;
-use pyo3::prelude::*;
+use pyo3::{
+    exceptions::{PyOverflowError, PyStopAsyncIteration, PyValueError},
+    prelude::*,
+    pyclass::CompareOp,
+    types::PyDict,
+};
+use qiniu_sdk::http::{header::CONTENT_TYPE, HeaderMap, Method};
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    io::Read,
     mem::transmute,
     pin::Pin,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    time::{Duration, SystemTime},
 };
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "objects")?;
     m.add_class::<ObjectsManager>()?;
+    m.add_class::<ObjectsManagerBuilder>()?;
     m.add_class::<Bucket>()?;
+    m.add_class::<SignedUrl>()?;
+    m.add_class::<BucketInfo>()?;
+    m.add_class::<CorsRule>()?;
+    m.add_class::<StatResult>()?;
     m.add_class::<OperationProvider>()?;
     m.add_class::<StatObject>()?;
     m.add_class::<CopyObject>()?;
@@ -42,8 +65,13 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<ModifyObjectMetadata>()?;
     m.add_class::<ModifyObjectLifeCycle>()?;
     m.add_class::<ListVersion>()?;
+    m.add_class::<StorageClass>()?;
+    m.add_class::<BatchDeleteCondition>()?;
     m.add_class::<ObjectsIterator>()?;
     m.add_class::<AsyncObjectsIterator>()?;
+    m.add_class::<BucketAsyncListing>()?;
+    m.add_class::<ObjectIterator>()?;
+    m.add_class::<AsyncImportFromUrlIterator>()?;
     m.add_class::<BatchSizeProvider>()?;
     Ok(m)
 }
@@ -54,7 +82,11 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     text_signature = "(credential, /, use_https = None, http_client = None, uc_endpoints = None, queryer = None)"
 )]
 #[derive(Clone)]
-struct ObjectsManager(qiniu_sdk::objects::ObjectsManager);
+struct ObjectsManager {
+    inner: qiniu_sdk::objects::ObjectsManager,
+    uc: UcClient,
+    regions: Option<RegionsProvider>,
+}
 
 #[pymethods]
 impl ObjectsManager {
@@ -72,251 +104,1999 @@ impl ObjectsManager {
         uc_endpoints: Option<Endpoints>,
         queryer: Option<BucketRegionsQueryer>,
     ) -> Self {
-        let mut builder = qiniu_sdk::objects::ObjectsManager::builder(credential);
-        if let Some(use_https) = use_https {
-            builder.use_https(use_https);
-        }
-        if let Some(http_client) = http_client {
-            builder.http_client(http_client.into());
-        }
-        if let Some(uc_endpoints) = uc_endpoints {
-            builder.uc_endpoints(uc_endpoints);
+        ObjectsManagerBuilder {
+            credential,
+            use_https,
+            http_client,
+            uc_endpoints,
+            queryer,
+            regions: None,
         }
-        if let Some(queryer) = queryer {
-            builder.queryer(queryer.into());
+        .build()
+    }
+
+    /// 创建七牛对象管理构建器
+    #[staticmethod]
+    #[pyo3(text_signature = "(credential)")]
+    fn builder(credential: CredentialProvider) -> ObjectsManagerBuilder {
+        ObjectsManagerBuilder {
+            credential,
+            use_https: None,
+            http_client: None,
+            uc_endpoints: None,
+            queryer: None,
+            regions: None,
         }
-        Self(builder.build())
     }
 
     /// 获取七牛存储空间管理器
     #[pyo3(text_signature = "($self, name, /, regions = None)")]
     #[args(regions = "None")]
     fn bucket(&self, name: &str, regions: Option<RegionsProvider>) -> Bucket {
-        let bucket = if let Some(regions) = regions {
-            self.0.bucket_with_region(name, regions)
+        let bucket = if let Some(regions) = regions.or_else(|| self.regions.to_owned()) {
+            self.inner.bucket_with_region(name, regions)
         } else {
-            self.0.bucket(name)
+            self.inner.bucket(name)
         };
-        Bucket(bucket)
+        Bucket {
+            inner: bucket,
+            uc: self.uc.to_owned(),
+        }
     }
 
-    fn __str__(&self) -> String {
-        self.__repr__()
+    /// 在指定区域创建存储空间
+    ///
+    /// 存储空间名称只能包含小写字母、数字和 `-`，长度为 3 到 63 个字符，否则将抛出 `QiniuInvalidDomainWithPortError` 异常。
+    /// `storage_type` 为存储空间的默认存储类型，取值与 `StorageClass` 相同。
+    /// 如果存储空间名称非法（状态码 400）、认证失败（状态码 401）或存储空间已经存在（状态码 614），将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, name, region_id, /, private = False, storage_type = 0)")]
+    #[args(private = "false", storage_type = "0")]
+    fn create_bucket(
+        &self,
+        name: &str,
+        region_id: &str,
+        private: bool,
+        storage_type: u8,
+        py: Python<'_>,
+    ) -> PyResult<Bucket> {
+        let (path, query_pairs) = make_create_bucket_request(name, region_id, storage_type)?;
+        py.allow_threads(|| {
+            self.uc.call(Method::POST, path, query_pairs, None)?;
+            if private {
+                self.uc.call(
+                    Method::POST,
+                    "/private".to_owned(),
+                    Default::default(),
+                    Some(make_set_bucket_private_form(name)),
+                )?;
+            }
+            Ok(())
+        })?;
+        Ok(self.bucket(name, None))
     }
 
-    fn __repr__(&self) -> String {
-        format!("{:?}", self.0)
+    /// 异步在指定区域创建存储空间
+    ///
+    /// 存储空间名称只能包含小写字母、数字和 `-`，长度为 3 到 63 个字符，否则将抛出 `QiniuInvalidDomainWithPortError` 异常。
+    /// `storage_type` 为存储空间的默认存储类型，取值与 `StorageClass` 相同。
+    /// 如果存储空间名称非法（状态码 400）、认证失败（状态码 401）或存储空间已经存在（状态码 614），将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, name, region_id, /, private = False, storage_type = 0)")]
+    #[args(private = "false", storage_type = "0")]
+    fn async_create_bucket<'p>(
+        &self,
+        name: String,
+        region_id: String,
+        private: bool,
+        storage_type: u8,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let (path, query_pairs) = make_create_bucket_request(&name, &region_id, storage_type)?;
+        let objects_manager = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            objects_manager
+                .uc
+                .async_call(Method::POST, path, query_pairs, None)
+                .await?;
+            if private {
+                objects_manager
+                    .uc
+                    .async_call(
+                        Method::POST,
+                        "/private".to_owned(),
+                        Default::default(),
+                        Some(make_set_bucket_private_form(&name)),
+                    )
+                    .await?;
+            }
+            Ok(objects_manager.bucket(&name, None))
+        })
     }
-}
 
-/// 七牛存储空间管理器
-#[pyclass]
-#[derive(Clone, Debug)]
-struct Bucket(qiniu_sdk::objects::Bucket);
+    /// 列出当前用户所有的存储空间名称
+    #[pyo3(text_signature = "($self)")]
+    fn list_buckets(&self, py: Python<'_>) -> PyResult<Vec<String>> {
+        let value = py.allow_threads(|| {
+            self.uc
+                .call(Method::GET, "/buckets".to_owned(), Default::default(), None)
+        })?;
+        serde_json::from_value(value).map_err(QiniuJsonError::from_err)
+    }
 
-#[pymethods]
-impl Bucket {
-    /// 获取存储空间名称
-    #[getter]
-    fn get_name(&self) -> String {
-        self.0.name().to_string()
+    /// 异步列出当前用户所有的存储空间名称
+    #[pyo3(text_signature = "($self)")]
+    fn async_list_buckets<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let value = uc
+                .async_call(Method::GET, "/buckets".to_owned(), Default::default(), None)
+                .await?;
+            serde_json::from_value::<Vec<String>>(value).map_err(QiniuJsonError::from_err)
+        })
     }
 
-    /// 列举对象
-    #[pyo3(
-        text_signature = "($self, /, limit = None, prefix = None, marker = None, version = None, need_parts = None, before_request_callback = None, after_response_ok_callback = None, after_response_error_callback = None)"
-    )]
-    #[args(
-        limit = "None",
-        prefix = "None",
-        marker = "None",
-        version = "None",
-        need_parts = "None",
-        before_request_callback = "None",
-        after_response_ok_callback = "None",
-        after_response_error_callback = "None"
-    )]
-    #[allow(clippy::too_many_arguments)]
-    fn list(
+    /// 列出当前用户在指定区域的所有存储空间名称
+    #[pyo3(text_signature = "($self, region_id)")]
+    fn list_buckets_in_region(&self, region_id: &str, py: Python<'_>) -> PyResult<Vec<String>> {
+        let value = py.allow_threads(|| {
+            self.uc.call(
+                Method::GET,
+                "/buckets".to_owned(),
+                make_region_query_pairs(region_id),
+                None,
+            )
+        })?;
+        serde_json::from_value(value).map_err(QiniuJsonError::from_err)
+    }
+
+    /// 异步列出当前用户在指定区域的所有存储空间名称
+    #[pyo3(text_signature = "($self, region_id)")]
+    fn async_list_buckets_in_region<'p>(
         &self,
-        limit: Option<usize>,
-        prefix: Option<String>,
-        marker: Option<String>,
-        version: Option<ListVersion>,
-        need_parts: Option<bool>,
-        before_request_callback: Option<PyObject>,
-        after_response_ok_callback: Option<PyObject>,
-        after_response_error_callback: Option<PyObject>,
-    ) -> ObjectsLister {
-        let params = Arc::pin(ObjectsIteratorParams {
-            bucket: self.to_owned(),
-            limit,
-            prefix,
-            marker,
-            version,
-            need_parts,
-            before_request_callback,
-            after_response_ok_callback,
-            after_response_error_callback,
-        });
-        ObjectsLister { params }
+        region_id: String,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let value = uc
+                .async_call(
+                    Method::GET,
+                    "/buckets".to_owned(),
+                    make_region_query_pairs(&region_id),
+                    None,
+                )
+                .await?;
+            serde_json::from_value::<Vec<String>>(value).map_err(QiniuJsonError::from_err)
+        })
     }
 
-    fn __iter__(&self, py: Python<'_>) -> PyResult<ObjectsIterator> {
-        self.list(None, None, None, None, None, None, None, None)
-            .__iter__(py)
+    /// 列出当前用户在所有区域的存储空间名称
+    ///
+    /// 区域列表通过 UC 服务的 `/regions` 接口获取，返回区域 ID 到存储空间名称列表的映射
+    #[pyo3(text_signature = "($self)")]
+    fn list_all_buckets(&self, py: Python<'_>) -> PyResult<HashMap<String, Vec<String>>> {
+        py.allow_threads(|| self.uc.get_all_region_ids())?
+            .into_iter()
+            .map(|region_id| {
+                let buckets = self.list_buckets_in_region(&region_id, py)?;
+                Ok((region_id, buckets))
+            })
+            .collect()
     }
 
-    /// 获取对象元信息
-    #[pyo3(text_signature = "($self, object, /, before_request_callback = None)")]
-    #[args(before_request_callback = "None")]
-    fn stat_object(
+    /// 异步列出当前用户在所有区域的存储空间名称
+    ///
+    /// 区域列表通过 UC 服务的 `/regions` 接口获取，返回区域 ID 到存储空间名称列表的映射
+    #[pyo3(text_signature = "($self)")]
+    fn async_list_all_buckets<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let region_ids = uc.async_get_all_region_ids().await?;
+            let mut all_buckets = HashMap::with_capacity(region_ids.len());
+            for region_id in region_ids {
+                let value = uc
+                    .async_call(
+                        Method::GET,
+                        "/buckets".to_owned(),
+                        make_region_query_pairs(&region_id),
+                        None,
+                    )
+                    .await?;
+                let buckets = serde_json::from_value::<Vec<String>>(value)
+                    .map_err(QiniuJsonError::from_err)?;
+                all_buckets.insert(region_id, buckets);
+            }
+            Ok(all_buckets)
+        })
+    }
+
+    /// 批量抓取网络资源到存储空间
+    ///
+    /// `ops` 为 `(url, "bucket:key")` 元组列表，最多同时抓取 `concurrency` 个资源。
+    /// 返回结果与输入顺序一致，每一项为抓取结果或 `QiniuApiCallError` 异常对象，仅认证失败时直接抛出异常
+    #[pyo3(text_signature = "($self, ops, /, concurrency = 4)")]
+    #[args(concurrency = "4")]
+    fn batch_fetch(
         &self,
-        object: String,
-        before_request_callback: Option<PyObject>,
+        ops: Vec<(String, String)>,
+        concurrency: usize,
         py: Python<'_>,
-    ) -> PyResult<Py<StatObject>> {
-        let stat_object = StatObject {
-            entry: Entry::new(self.to_owned(), object),
-            before_request_callback,
-        };
-        let operation_provider = OperationProvider {
-            operation: qiniu_sdk::objects::OperationProvider::to_operation(
-                &mut stat_object.make_operation(),
-            ),
-        };
-        Py::new(py, (stat_object, operation_provider))
+    ) -> PyResult<Vec<PyObject>> {
+        let tasks = self.make_fetch_tasks(ops, py)?;
+        let results =
+            py.allow_threads(|| async_std::task::block_on(run_fetch_tasks(tasks, concurrency)));
+        convert_batch_fetch_results(results, py)
     }
 
-    /// 复制对象
-    #[pyo3(
-        text_signature = "($self, from_object, to_bucket, to_object, /, force = None, before_request_callback = None)"
-    )]
-    #[args(force = "None", before_request_callback = "None")]
-    fn copy_object_to(
+    /// 异步批量抓取网络资源到存储空间
+    ///
+    /// `ops` 为 `(url, "bucket:key")` 元组列表，最多同时抓取 `concurrency` 个资源。
+    /// 返回结果与输入顺序一致，每一项为抓取结果或 `QiniuApiCallError` 异常对象，仅认证失败时直接抛出异常
+    #[pyo3(text_signature = "($self, ops, /, concurrency = 4)")]
+    #[args(concurrency = "4")]
+    fn async_batch_fetch<'p>(
         &self,
-        from_object: String,
-        to_bucket: String,
-        to_object: String,
-        force: Option<bool>,
-        before_request_callback: Option<PyObject>,
-        py: Python<'_>,
-    ) -> PyResult<Py<CopyObject>> {
-        let copy_object = CopyObject {
-            from_entry: Entry::new(self.to_owned(), from_object),
-            to_entry: SimpleEntry::new(to_bucket, to_object),
-            force,
-            before_request_callback,
-        };
-        let operation_provider = OperationProvider {
-            operation: qiniu_sdk::objects::OperationProvider::to_operation(
-                &mut copy_object.make_operation(),
-            ),
-        };
-        Py::new(py, (copy_object, operation_provider))
+        ops: Vec<(String, String)>,
+        concurrency: usize,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let tasks = self.make_fetch_tasks(ops, py)?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let results = run_fetch_tasks(tasks, concurrency).await;
+            Python::with_gil(|py| convert_batch_fetch_results(results, py))
+        })
     }
 
-    /// 移动对象
-    #[pyo3(
-        text_signature = "($self, from_object, to_bucket, to_object, /, force = None, before_request_callback = None)"
-    )]
-    #[args(force = "None", before_request_callback = "None")]
-    fn move_object_to(
+    /// 列举存储空间中的所有对象，并返回 `pandas.DataFrame`
+    ///
+    /// 列名为 `key`、`hash`、`size`、`mime_type` 和 `put_time`，`put_time` 的单位为秒，`columns` 可以选择需要的列。
+    /// 如果没有安装 pandas，将返回字典列表
+    #[pyo3(text_signature = "($self, bucket, prefix = None, /, columns = None)")]
+    #[args(prefix = "None", columns = "None")]
+    fn list_as_dataframe(
         &self,
-        from_object: String,
-        to_bucket: String,
-        to_object: String,
-        force: Option<bool>,
-        before_request_callback: Option<PyObject>,
+        bucket: &str,
+        prefix: Option<String>,
+        columns: Option<Vec<String>>,
         py: Python<'_>,
-    ) -> PyResult<Py<MoveObject>> {
-        let move_object = MoveObject {
-            from_entry: Entry::new(self.to_owned(), from_object),
-            to_entry: SimpleEntry::new(to_bucket, to_object),
-            force,
-            before_request_callback,
-        };
-        let operation_provider = OperationProvider {
-            operation: qiniu_sdk::objects::OperationProvider::to_operation(
-                &mut move_object.make_operation(),
-            ),
-        };
-        Py::new(py, (move_object, operation_provider))
+    ) -> PyResult<PyObject> {
+        let columns = make_listing_columns(columns)?;
+        let mut iterator =
+            self.bucket(bucket, None)
+                .iterate_objects(prefix, None, DEFAULT_LIST_LIMIT);
+        let mut items = Vec::new();
+        loop {
+            let result = py.allow_threads(|| iterator.fetch_page())?;
+            iterator.load_page(result);
+            items.append(&mut iterator.page);
+            if iterator.last_page {
+                break;
+            }
+        }
+        convert_listing_items_to_dataframe(&items, &columns, py)
+    }
+
+    /// 异步列举存储空间中的所有对象，并返回 `pandas.DataFrame`
+    ///
+    /// 列名为 `key`、`hash`、`size`、`mime_type` 和 `put_time`，`put_time` 的单位为秒，`columns` 可以选择需要的列。
+    /// 如果没有安装 pandas，将返回字典列表
+    #[pyo3(text_signature = "($self, bucket, prefix = None, /, columns = None)")]
+    #[args(prefix = "None", columns = "None")]
+    fn async_list_as_dataframe<'p>(
+        &self,
+        bucket: &str,
+        prefix: Option<String>,
+        columns: Option<Vec<String>>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let columns = make_listing_columns(columns)?;
+        let mut iterator =
+            self.bucket(bucket, None)
+                .iterate_objects(prefix, None, DEFAULT_LIST_LIMIT);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut items = Vec::new();
+            loop {
+                let result = iterator.async_fetch_page().await?;
+                iterator.load_page(result);
+                items.append(&mut iterator.page);
+                if iterator.last_page {
+                    break;
+                }
+            }
+            Python::with_gil(|py| convert_listing_items_to_dataframe(&items, &columns, py))
+        })
+    }
+
+    /// 获取对象元信息
+    ///
+    /// 相当于 `bucket(bucket).stat_object(key).call()`，直接返回响应体字典
+    #[pyo3(text_signature = "($self, bucket, key)")]
+    fn get_file_info(&self, bucket: &str, key: &str, py: Python<'_>) -> PyResult<PyObject> {
+        let bucket = self.bucket(bucket, None);
+        let resp = py.allow_threads(|| {
+            bucket
+                .inner
+                .stat_object(key)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        let (_, body) = resp.into_parts_and_body();
+        convert_json_value_to_py_object(body.as_ref())
+    }
+
+    /// 异步获取对象元信息
+    ///
+    /// 相当于 `bucket(bucket).stat_object(key).async_call()`，直接返回响应体字典
+    #[pyo3(text_signature = "($self, bucket, key)")]
+    fn async_get_file_info<'p>(
+        &self,
+        bucket: &str,
+        key: String,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.bucket(bucket, None);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = bucket
+                .inner
+                .stat_object(&key)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            convert_json_value_to_py_object(body.as_ref())
+        })
     }
 
     /// 删除对象
-    #[pyo3(text_signature = "($self, object, /, before_request_callback = None)")]
-    #[args(before_request_callback = "None")]
-    fn delete_object(
+    #[pyo3(text_signature = "($self, bucket, key)")]
+    fn delete_file(&self, bucket: &str, key: &str, py: Python<'_>) -> PyResult<()> {
+        let bucket = self.bucket(bucket, None);
+        py.allow_threads(|| {
+            bucket
+                .inner
+                .delete_object(key)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步删除对象
+    #[pyo3(text_signature = "($self, bucket, key)")]
+    fn async_delete_file<'p>(
         &self,
-        object: String,
-        before_request_callback: Option<PyObject>,
-        py: Python<'_>,
-    ) -> PyResult<Py<DeleteObject>> {
-        let delete_object = DeleteObject {
-            entry: Entry::new(self.to_owned(), object),
-            before_request_callback,
-        };
-        let operation_provider = OperationProvider {
-            operation: qiniu_sdk::objects::OperationProvider::to_operation(
-                &mut delete_object.make_operation(),
-            ),
-        };
-        Py::new(py, (delete_object, operation_provider))
+        bucket: &str,
+        key: String,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.bucket(bucket, None);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            bucket
+                .inner
+                .delete_object(&key)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
     }
 
-    /// 解冻对象
-    #[pyo3(
-        text_signature = "($self, object, freeze_after_days, /, before_request_callback = None)"
-    )]
-    #[args(before_request_callback = "None")]
-    fn restore_archived_object(
+    /// 复制对象
+    ///
+    /// 相当于 `bucket(src_bucket).copy_object(src_key, dst_bucket, dst_key, force)`
+    #[pyo3(text_signature = "($self, src_bucket, src_key, dst_bucket, dst_key, /, force = False)")]
+    #[args(force = "false")]
+    fn copy_file(
         &self,
-        object: String,
-        freeze_after_days: usize,
-        before_request_callback: Option<PyObject>,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        force: bool,
         py: Python<'_>,
-    ) -> PyResult<Py<UnfreezeObject>> {
-        let restore_archived_object = UnfreezeObject {
-            entry: Entry::new(self.to_owned(), object),
-            freeze_after_days,
-            before_request_callback,
-        };
-        let operation_provider = OperationProvider {
-            operation: qiniu_sdk::objects::OperationProvider::to_operation(
-                &mut restore_archived_object.make_operation(),
-            ),
-        };
-        Py::new(py, (restore_archived_object, operation_provider))
+    ) -> PyResult<()> {
+        self.bucket(src_bucket, None)
+            .copy_object(src_key, dst_bucket, dst_key, force, py)
     }
 
-    /// 设置对象类型
-    #[pyo3(text_signature = "($self, object, object_type, /, before_request_callback = None)")]
-    #[args(before_request_callback = "None")]
-    fn set_object_type(
+    /// 异步复制对象
+    ///
+    /// 相当于 `bucket(src_bucket).async_copy_object(src_key, dst_bucket, dst_key, force)`
+    #[pyo3(text_signature = "($self, src_bucket, src_key, dst_bucket, dst_key, /, force = False)")]
+    #[args(force = "false")]
+    fn async_copy_file<'p>(
         &self,
-        object: String,
-        object_type: u8,
-        before_request_callback: Option<PyObject>,
-        py: Python<'_>,
-    ) -> PyResult<Py<SetObjectType>> {
-        let set_object_type = SetObjectType {
-            entry: Entry::new(self.to_owned(), object),
-            file_type: qiniu_sdk::upload_token::FileType::from(object_type),
-            before_request_callback,
-        };
-        let operation_provider = OperationProvider {
-            operation: qiniu_sdk::objects::OperationProvider::to_operation(
-                &mut set_object_type.make_operation(),
-            ),
-        };
-        Py::new(py, (set_object_type, operation_provider))
+        src_bucket: &str,
+        src_key: String,
+        dst_bucket: String,
+        dst_key: String,
+        force: bool,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        self.bucket(src_bucket, None)
+            .async_copy_object(src_key, dst_bucket, dst_key, force, py)
     }
 
-    /// 设置对象状态
-    #[pyo3(text_signature = "($self, object, disabled, /, before_request_callback = None)")]
-    #[args(before_request_callback = "None")]
-    fn modify_object_status(
+    /// 移动对象
+    ///
+    /// 相当于 `bucket(src_bucket).move_object(src_key, dst_bucket, dst_key, force)`
+    #[pyo3(text_signature = "($self, src_bucket, src_key, dst_bucket, dst_key, /, force = False)")]
+    #[args(force = "false")]
+    fn move_file(
+        &self,
+        src_bucket: &str,
+        src_key: &str,
+        dst_bucket: &str,
+        dst_key: &str,
+        force: bool,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        self.bucket(src_bucket, None)
+            .move_object(src_key, dst_bucket, dst_key, force, py)
+    }
+
+    /// 异步移动对象
+    ///
+    /// 相当于 `bucket(src_bucket).async_move_object(src_key, dst_bucket, dst_key, force)`
+    #[pyo3(text_signature = "($self, src_bucket, src_key, dst_bucket, dst_key, /, force = False)")]
+    #[args(force = "false")]
+    fn async_move_file<'p>(
+        &self,
+        src_bucket: &str,
+        src_key: String,
+        dst_bucket: String,
+        dst_key: String,
+        force: bool,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        self.bucket(src_bucket, None)
+            .async_move_object(src_key, dst_bucket, dst_key, force, py)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.inner)
+    }
+}
+
+impl ObjectsManager {
+    fn make_fetch_tasks(
+        &self,
+        ops: Vec<(String, String)>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<FetchTask>> {
+        let access_key = py
+            .allow_threads(|| {
+                qiniu_sdk::credential::CredentialProvider::get(
+                    &self.uc.credential,
+                    Default::default(),
+                )
+            })
+            .map_err(convert_credential_get_error)?
+            .access_key()
+            .to_owned();
+        ops.into_iter()
+            .map(|(url, entry)| {
+                let bucket_name = entry
+                    .split_once(':')
+                    .map(|(bucket_name, _)| bucket_name)
+                    .filter(|bucket_name| !bucket_name.is_empty())
+                    .ok_or_else(|| {
+                        PyValueError::new_err(format!("Invalid fetch entry: {:?}", entry))
+                    })?;
+                let regions = self.regions.to_owned().unwrap_or_else(|| {
+                    RegionsProvider::from(Box::new(
+                        self.inner
+                            .queryer()
+                            .query(access_key.to_owned(), bucket_name),
+                    )
+                        as Box<dyn qiniu_sdk::http_client::RegionsProvider>)
+                });
+                Ok(FetchTask {
+                    uc: self.uc.to_owned(),
+                    endpoints: qiniu_sdk::http_client::RegionsProviderEndpoints::new(regions),
+                    path: format!(
+                        "/fetch/{}/to/{}",
+                        qiniu_sdk::utils::base64::urlsafe(url.as_bytes()),
+                        qiniu_sdk::utils::base64::urlsafe(entry.as_bytes()),
+                    ),
+                })
+            })
+            .collect()
+    }
+}
+
+struct FetchTask {
+    uc: UcClient,
+    endpoints: qiniu_sdk::http_client::RegionsProviderEndpoints<RegionsProvider>,
+    path: String,
+}
+
+impl FetchTask {
+    async fn fetch(self) -> PyResult<serde_json::Value> {
+        self.uc
+            .async_call_service(
+                Method::POST,
+                &[qiniu_sdk::http_client::ServiceName::Io],
+                self.endpoints,
+                self.path,
+                Default::default(),
+                None,
+            )
+            .await
+    }
+}
+
+async fn run_fetch_tasks(
+    tasks: Vec<FetchTask>,
+    concurrency: usize,
+) -> Vec<PyResult<serde_json::Value>> {
+    futures::stream::iter(tasks)
+        .map(FetchTask::fetch)
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+fn convert_batch_fetch_results(
+    results: Vec<PyResult<serde_json::Value>>,
+    py: Python<'_>,
+) -> PyResult<Vec<PyObject>> {
+    results
+        .into_iter()
+        .map(|result| match result {
+            Ok(value) => convert_json_value_to_py_object(&value),
+            Err(err) if is_unauthorized_error(&err) => Err(err),
+            Err(err) if err.is_instance_of::<QiniuApiCallError>(py) => {
+                Ok(err.value(py).into_py(py))
+            }
+            Err(err) => Err(err),
+        })
+        .collect()
+}
+
+fn convert_import_from_url_result(
+    url: String,
+    key: String,
+    result: PyResult<serde_json::Value>,
+    py: Python<'_>,
+) -> PyResult<PyObject> {
+    let item = PyDict::new(py);
+    item.set_item("url", url)?;
+    item.set_item("key", key)?;
+    match result {
+        Ok(value) => item.set_item("result", convert_json_value_to_py_object(&value)?)?,
+        Err(err) if is_unauthorized_error(&err) => return Err(err),
+        Err(err) => item.set_item("error", err.value(py))?,
+    }
+    Ok(item.into_py(py))
+}
+
+/// 批量抓取网络资源的异步结果迭代器
+///
+/// 可以通过 `Bucket::import_from_url_async_bulk` 方法获取
+#[pyclass]
+struct AsyncImportFromUrlIterator(
+    Arc<AsyncMutex<Pin<Box<dyn futures::Stream<Item = PyResult<PyObject>> + Send>>>>,
+);
+
+#[pymethods]
+impl AsyncImportFromUrlIterator {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let results = self.0.to_owned();
+        let future = pyo3_asyncio::async_std::future_into_py(py, async move {
+            match results.lock().await.next().await {
+                Some(result) => result,
+                None => Err(PyStopAsyncIteration::new_err(())),
+            }
+        })?;
+        Ok(Some(future.into_py(py)))
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl std::fmt::Debug for AsyncImportFromUrlIterator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncImportFromUrlIterator").finish()
+    }
+}
+
+/// 七牛对象管理构建器
+///
+/// 通过 `ObjectsManager.builder()` 创建，可以注入 HTTP 客户端和区域信息提供者
+#[pyclass]
+#[derive(Clone)]
+struct ObjectsManagerBuilder {
+    credential: CredentialProvider,
+    use_https: Option<bool>,
+    http_client: Option<HttpClient>,
+    uc_endpoints: Option<Endpoints>,
+    queryer: Option<BucketRegionsQueryer>,
+    regions: Option<RegionsProvider>,
+}
+
+#[pymethods]
+impl ObjectsManagerBuilder {
+    /// 设置 HTTP 客户端
+    #[pyo3(text_signature = "($self, http_client)")]
+    fn http_client(&mut self, http_client: HttpClient) {
+        self.http_client = Some(http_client);
+    }
+
+    /// 设置区域信息提供者
+    ///
+    /// 设置后，通过 `ObjectsManager.bucket()` 获取的存储空间管理器默认使用该区域信息
+    #[pyo3(text_signature = "($self, regions_provider)")]
+    fn regions_provider(&mut self, regions_provider: RegionsProvider) {
+        self.regions = Some(regions_provider);
+    }
+
+    /// 设置是否启用 HTTPS 协议
+    #[pyo3(text_signature = "($self, use_https)")]
+    fn use_https(&mut self, use_https: bool) {
+        self.use_https = Some(use_https);
+    }
+
+    /// 设置存储空间管理终端地址
+    #[pyo3(text_signature = "($self, uc_endpoints)")]
+    fn uc_endpoints(&mut self, uc_endpoints: Endpoints) {
+        self.uc_endpoints = Some(uc_endpoints);
+    }
+
+    /// 设置存储空间相关区域查询器
+    #[pyo3(text_signature = "($self, queryer)")]
+    fn queryer(&mut self, queryer: BucketRegionsQueryer) {
+        self.queryer = Some(queryer);
+    }
+
+    /// 构建七牛对象管理器
+    #[pyo3(text_signature = "($self)")]
+    fn build(&self) -> ObjectsManager {
+        let uc = UcClient {
+            http_client: self
+                .http_client
+                .to_owned()
+                .map(Into::into)
+                .unwrap_or_default(),
+            credential: self.credential.to_owned(),
+            uc_endpoints: self
+                .uc_endpoints
+                .to_owned()
+                .map(Into::into)
+                .unwrap_or_else(|| {
+                    qiniu_sdk::http_client::Endpoints::public_uc_endpoints().to_owned()
+                }),
+            use_https: self.use_https,
+        };
+        let mut builder = qiniu_sdk::objects::ObjectsManager::builder(self.credential.to_owned());
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
+        }
+        if let Some(http_client) = self.http_client.to_owned() {
+            builder.http_client(http_client.into());
+        }
+        if let Some(uc_endpoints) = self.uc_endpoints.to_owned() {
+            builder.uc_endpoints(uc_endpoints);
+        }
+        if let Some(queryer) = self.queryer.to_owned() {
+            builder.queryer(queryer.into());
+        }
+        ObjectsManager {
+            inner: builder.build(),
+            uc,
+            regions: self.regions.to_owned(),
+        }
+    }
+}
+
+/// 七牛存储空间管理器
+#[pyclass]
+#[derive(Clone, Debug)]
+struct Bucket {
+    inner: qiniu_sdk::objects::Bucket,
+    uc: UcClient,
+}
+
+#[pymethods]
+impl Bucket {
+    /// 获取存储空间名称
+    #[getter]
+    fn get_name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// 生成私有存储空间的对象下载地址
+    ///
+    /// 下载地址将在 `expires_in` 秒后过期，如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, domain, key, /, expires_in = 3600, use_https = None)")]
+    #[args(expires_in = "3600", use_https = "None")]
+    fn make_private_url(
+        &self,
+        domain: &str,
+        key: &str,
+        expires_in: u64,
+        use_https: Option<bool>,
+        py: Python<'_>,
+    ) -> PyResult<SignedUrl> {
+        let url = parse_uri(&self.make_object_url(domain, key, use_https))?;
+        let expires_in = Duration::from_secs(expires_in);
+        let expires_at = SystemTime::now()
+            .checked_add(expires_in)
+            .ok_or_else(|| PyOverflowError::new_err("Expiration time overflows"))?;
+        let url = py
+            .allow_threads(|| {
+                qiniu_sdk::credential::CredentialProvider::get(
+                    &self.uc.credential,
+                    Default::default(),
+                )
+            })
+            .map_err(convert_credential_get_error)?
+            .sign_download_url(url, expires_in)
+            .to_string();
+        Ok(SignedUrl { url, expires_at })
+    }
+
+    /// 使用存储空间的认证信息生成上传凭证
+    ///
+    /// 如果指定了 `key`，则仅允许以该对象名称上传，上传凭证将在 `expires_in` 秒后过期，
+    /// `policy_kwargs` 将作为上传策略的字段。
+    /// 如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key = None, expires_in = 3600, /, **policy_kwargs)")]
+    #[args(key = "None", expires_in = "3600", policy_kwargs = "**")]
+    fn generate_upload_token(
+        &self,
+        key: Option<&str>,
+        expires_in: u64,
+        policy_kwargs: Option<HashMap<String, PyObject>>,
+        py: Python<'_>,
+    ) -> PyResult<Py<UploadToken>> {
+        let policy = make_upload_policy(self.inner.name(), key, expires_in, policy_kwargs)?;
+        let credential = self.uc.credential.to_owned();
+        let upload_token = py.allow_threads(|| UploadToken::sign(policy, credential))?;
+        UploadToken::from_token_string(&upload_token, py)
+    }
+
+    /// 使用存储空间的认证信息异步生成上传凭证
+    ///
+    /// 如果指定了 `key`，则仅允许以该对象名称上传，上传凭证将在 `expires_in` 秒后过期，
+    /// `policy_kwargs` 将作为上传策略的字段。
+    /// 如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key = None, expires_in = 3600, /, **policy_kwargs)")]
+    #[args(key = "None", expires_in = "3600", policy_kwargs = "**")]
+    fn async_generate_upload_token<'p>(
+        &self,
+        key: Option<&str>,
+        expires_in: u64,
+        policy_kwargs: Option<HashMap<String, PyObject>>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let policy = make_upload_policy(self.inner.name(), key, expires_in, policy_kwargs)?;
+        let credential = self.uc.credential.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let upload_token = UploadToken::async_sign(policy, credential).await?;
+            Python::with_gil(|py| UploadToken::from_token_string(&upload_token, py))
+        })
+    }
+
+    /// 生成公开存储空间的对象下载地址
+    ///
+    /// 对象名称将按照 RFC 3986 的路径规则进行编码。
+    /// 如果没有传入 `domain`，将使用存储空间绑定的第一个域名，获取域名失败将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key, /, domain = None)")]
+    #[args(domain = "None")]
+    fn object_url(&self, key: &str, domain: Option<String>, py: Python<'_>) -> PyResult<String> {
+        let domain = if let Some(domain) = domain {
+            domain
+        } else {
+            first_domain(self.get_domains(py)?)?
+        };
+        Ok(self.make_object_url(&domain, key, None))
+    }
+
+    /// 异步生成公开存储空间的对象下载地址
+    ///
+    /// 对象名称将按照 RFC 3986 的路径规则进行编码。
+    /// 如果没有传入 `domain`，将使用存储空间绑定的第一个域名，获取域名失败将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key, /, domain = None)")]
+    #[args(domain = "None")]
+    fn async_object_url<'p>(
+        &self,
+        key: String,
+        domain: Option<String>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        let query_pairs = self.make_domains_query_pairs();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let domain = if let Some(domain) = domain {
+                domain
+            } else {
+                let value = bucket
+                    .uc
+                    .async_call(Method::GET, "/v6/domain/list".to_owned(), query_pairs, None)
+                    .await?;
+                first_domain(serde_json::from_value(value).map_err(QiniuJsonError::from_err)?)?
+            };
+            Ok(bucket.make_object_url(&domain, &key, None))
+        })
+    }
+
+    /// 删除存储空间
+    ///
+    /// 如果存储空间不为空，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn drop(&self, py: Python<'_>) -> PyResult<()> {
+        let path = format!("/drop/{}", self.inner.name());
+        py.allow_threads(|| self.uc.call(Method::POST, path, Default::default(), None))?;
+        Ok(())
+    }
+
+    /// 异步删除存储空间
+    ///
+    /// 如果存储空间不为空，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn async_drop<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        let path = format!("/drop/{}", self.inner.name());
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            uc.async_call(Method::POST, path, Default::default(), None)
+                .await?;
+            Ok(())
+        })
+    }
+
+    /// 删除存储空间
+    ///
+    /// 如果认证失败（状态码 401）或存储空间不为空（状态码 403），将抛出 `QiniuApiCallError` 异常。
+    /// 如果 `force` 为 `True`，将先分页列举并删除存储空间中的所有对象，
+    /// 删除对象失败时将抛出 `QiniuApiCallError` 异常，错误信息中包含失败前已经删除的对象数量
+    #[pyo3(text_signature = "($self, /, force = False)")]
+    #[args(force = "false")]
+    fn delete_bucket(&self, force: bool, py: Python<'_>) -> PyResult<()> {
+        let path = format!("/drop/{}", self.inner.name());
+        py.allow_threads(|| {
+            if force {
+                let mut iterator = self.iterate_objects(None, None, DEFAULT_LIST_LIMIT);
+                let mut deleted = 0;
+                loop {
+                    iterator.load_page(iterator.fetch_page()?);
+                    let keys = page_keys(&iterator.page);
+                    deleted = count_force_deleted(deleted, self.batch_call(&keys, true))?;
+                    if iterator.last_page {
+                        break;
+                    }
+                }
+            }
+            self.uc.call(Method::POST, path, Default::default(), None)
+        })?;
+        Ok(())
+    }
+
+    /// 异步删除存储空间
+    ///
+    /// 如果认证失败（状态码 401）或存储空间不为空（状态码 403），将抛出 `QiniuApiCallError` 异常。
+    /// 如果 `force` 为 `True`，将先分页列举并删除存储空间中的所有对象，
+    /// 删除对象失败时将抛出 `QiniuApiCallError` 异常，错误信息中包含失败前已经删除的对象数量
+    #[pyo3(text_signature = "($self, /, force = False)")]
+    #[args(force = "false")]
+    fn async_delete_bucket<'p>(&self, force: bool, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        let path = format!("/drop/{}", self.inner.name());
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            if force {
+                let mut iterator = bucket.iterate_objects(None, None, DEFAULT_LIST_LIMIT);
+                let mut deleted = 0;
+                loop {
+                    iterator.load_page(iterator.async_fetch_page().await?);
+                    let keys = page_keys(&iterator.page);
+                    deleted =
+                        count_force_deleted(deleted, bucket.async_batch_call(&keys, true).await)?;
+                    if iterator.last_page {
+                        break;
+                    }
+                }
+            }
+            bucket
+                .uc
+                .async_call(Method::POST, path, Default::default(), None)
+                .await?;
+            Ok(())
+        })
+    }
+
+    /// 获取存储空间信息
+    ///
+    /// 如果存储空间不存在，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn get_bucket_info(&self, py: Python<'_>) -> PyResult<BucketInfo> {
+        let value = py.allow_threads(|| {
+            self.uc.call(
+                Method::GET,
+                "/v2/bucketInfo".to_owned(),
+                make_bucket_query_pairs(self.inner.name()),
+                None,
+            )
+        })?;
+        Ok(BucketInfo::from_json(&value))
+    }
+
+    /// 异步获取存储空间信息
+    ///
+    /// 如果存储空间不存在，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self)")]
+    fn async_get_bucket_info<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        let query_pairs = make_bucket_query_pairs(self.inner.name());
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let value = uc
+                .async_call(Method::GET, "/v2/bucketInfo".to_owned(), query_pairs, None)
+                .await?;
+            Ok(BucketInfo::from_json(&value))
+        })
+    }
+
+    /// 获取存储空间绑定的域名列表
+    #[pyo3(text_signature = "($self)")]
+    fn get_domains(&self, py: Python<'_>) -> PyResult<Vec<String>> {
+        let query_pairs = self.make_domains_query_pairs();
+        let value = py.allow_threads(|| {
+            self.uc
+                .call(Method::GET, "/v6/domain/list".to_owned(), query_pairs, None)
+        })?;
+        serde_json::from_value(value).map_err(QiniuJsonError::from_err)
+    }
+
+    /// 异步获取存储空间绑定的域名列表
+    #[pyo3(text_signature = "($self)")]
+    fn async_get_domains<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        let query_pairs = self.make_domains_query_pairs();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let value = uc
+                .async_call(Method::GET, "/v6/domain/list".to_owned(), query_pairs, None)
+                .await?;
+            serde_json::from_value::<Vec<String>>(value).map_err(QiniuJsonError::from_err)
+        })
+    }
+
+    /// 设置存储空间的跨域规则
+    ///
+    /// `rules` 为 `CorsRule` 或 `CorsRule` 列表，此时不能再传入其他参数。
+    /// `rules` 也可以是允许的来源列表，此时将根据 `methods` 等参数创建一条跨域规则，参数含义与 `CorsRule` 相同
+    #[pyo3(
+        text_signature = "($self, rules, /, methods = None, allowed_headers = None, expose_headers = None, max_age_secs = 3600)"
+    )]
+    #[args(
+        methods = "None",
+        allowed_headers = "None",
+        expose_headers = "None",
+        max_age_secs = "3600"
+    )]
+    fn enable_cors(
+        &self,
+        rules: CorsRulesOrOrigins,
+        methods: Option<Vec<String>>,
+        allowed_headers: Option<Vec<String>>,
+        expose_headers: Option<Vec<String>>,
+        max_age_secs: u64,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        let rules = make_cors_rules(
+            rules,
+            methods,
+            allowed_headers,
+            expose_headers,
+            max_age_secs,
+        )?;
+        let path = self.make_cors_path("set");
+        py.allow_threads(|| self.uc.call_with_json(Method::POST, path, &rules))?;
+        Ok(())
+    }
+
+    /// 异步设置存储空间的跨域规则
+    ///
+    /// `rules` 为 `CorsRule` 或 `CorsRule` 列表，此时不能再传入其他参数。
+    /// `rules` 也可以是允许的来源列表，此时将根据 `methods` 等参数创建一条跨域规则，参数含义与 `CorsRule` 相同
+    #[pyo3(
+        text_signature = "($self, rules, /, methods = None, allowed_headers = None, expose_headers = None, max_age_secs = 3600)"
+    )]
+    #[args(
+        methods = "None",
+        allowed_headers = "None",
+        expose_headers = "None",
+        max_age_secs = "3600"
+    )]
+    fn async_enable_cors<'p>(
+        &self,
+        rules: CorsRulesOrOrigins,
+        methods: Option<Vec<String>>,
+        allowed_headers: Option<Vec<String>>,
+        expose_headers: Option<Vec<String>>,
+        max_age_secs: u64,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let rules = make_cors_rules(
+            rules,
+            methods,
+            allowed_headers,
+            expose_headers,
+            max_age_secs,
+        )?;
+        let uc = self.uc.to_owned();
+        let path = self.make_cors_path("set");
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            uc.async_call_with_json(Method::POST, path, &rules).await?;
+            Ok(())
+        })
+    }
+
+    /// 获取存储空间的跨域规则
+    #[pyo3(text_signature = "($self)")]
+    fn get_cors_rules(&self, py: Python<'_>) -> PyResult<Vec<CorsRule>> {
+        let path = self.make_cors_path("get");
+        let value =
+            py.allow_threads(|| self.uc.call(Method::GET, path, Default::default(), None))?;
+        Ok(convert_cors_rules(value))
+    }
+
+    /// 异步获取存储空间的跨域规则
+    #[pyo3(text_signature = "($self)")]
+    fn async_get_cors_rules<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        let path = self.make_cors_path("get");
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let value = uc
+                .async_call(Method::GET, path, Default::default(), None)
+                .await?;
+            Ok(convert_cors_rules(value))
+        })
+    }
+
+    /// 清空存储空间的跨域规则
+    #[pyo3(text_signature = "($self)")]
+    fn disable_cors(&self, py: Python<'_>) -> PyResult<()> {
+        let path = self.make_cors_path("set");
+        py.allow_threads(|| {
+            self.uc
+                .call_with_json(Method::POST, path, &serde_json::json!([]))
+        })?;
+        Ok(())
+    }
+
+    /// 异步清空存储空间的跨域规则
+    #[pyo3(text_signature = "($self)")]
+    fn async_disable_cors<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let uc = self.uc.to_owned();
+        let path = self.make_cors_path("set");
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            uc.async_call_with_json(Method::POST, path, &serde_json::json!([]))
+                .await?;
+            Ok(())
+        })
+    }
+
+    /// 列举对象
+    #[pyo3(
+        text_signature = "($self, /, limit = None, prefix = None, marker = None, version = None, need_parts = None, before_request_callback = None, after_response_ok_callback = None, after_response_error_callback = None)"
+    )]
+    #[args(
+        limit = "None",
+        prefix = "None",
+        marker = "None",
+        version = "None",
+        need_parts = "None",
+        before_request_callback = "None",
+        after_response_ok_callback = "None",
+        after_response_error_callback = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn list(
+        &self,
+        limit: Option<usize>,
+        prefix: Option<String>,
+        marker: Option<String>,
+        version: Option<ListVersion>,
+        need_parts: Option<bool>,
+        before_request_callback: Option<PyObject>,
+        after_response_ok_callback: Option<PyObject>,
+        after_response_error_callback: Option<PyObject>,
+    ) -> ObjectsLister {
+        let params = Arc::pin(ObjectsIteratorParams {
+            bucket: self.to_owned(),
+            limit,
+            prefix,
+            marker,
+            version,
+            need_parts,
+            before_request_callback,
+            after_response_ok_callback,
+            after_response_error_callback,
+        });
+        ObjectsLister { params }
+    }
+
+    /// 异步列举对象
+    ///
+    /// 返回的列举器只能通过 `async for` 迭代，且不能被并发迭代
+    #[pyo3(
+        text_signature = "($self, /, limit = None, prefix = None, marker = None, version = None, need_parts = None, before_request_callback = None, after_response_ok_callback = None, after_response_error_callback = None)"
+    )]
+    #[args(
+        limit = "None",
+        prefix = "None",
+        marker = "None",
+        version = "None",
+        need_parts = "None",
+        before_request_callback = "None",
+        after_response_ok_callback = "None",
+        after_response_error_callback = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn async_list(
+        &self,
+        limit: Option<usize>,
+        prefix: Option<String>,
+        marker: Option<String>,
+        version: Option<ListVersion>,
+        need_parts: Option<bool>,
+        before_request_callback: Option<PyObject>,
+        after_response_ok_callback: Option<PyObject>,
+        after_response_error_callback: Option<PyObject>,
+        py: Python<'_>,
+    ) -> BucketAsyncListing {
+        let lister = self.list(
+            limit,
+            prefix,
+            marker,
+            version,
+            need_parts,
+            before_request_callback,
+            after_response_ok_callback,
+            after_response_error_callback,
+        );
+        BucketAsyncListing {
+            inner: Arc::new(lister.make_async_iterator_inner(py)),
+            _params: lister.params,
+            iterating: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// 从指定 URL 抓取资源并保存为对象
+    ///
+    /// `mime` 为对象的 MIME 类型，`meta` 为对象的自定义元信息，其键必须以 `x-qn-meta-` 开头。
+    /// 返回七牛抓取接口的响应，包含 `hash` 和 `key` 字段
+    #[pyo3(text_signature = "($self, url, key, /, mime = None, meta = None)")]
+    #[args(mime = "None", meta = "None")]
+    fn copy_from_url(
+        &self,
+        url: &str,
+        key: &str,
+        mime: Option<&str>,
+        meta: Option<HashMap<String, String>>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let headers = make_fetch_headers(mime, meta)?;
+        let value = py.allow_threads(|| {
+            self.uc.call_service_with_headers(
+                Method::POST,
+                &[qiniu_sdk::http_client::ServiceName::Io],
+                self.region()?,
+                self.make_fetch_path(url, key),
+                Default::default(),
+                headers,
+                None,
+            )
+        })?;
+        convert_json_value_to_py_object(&value)
+    }
+
+    /// 异步从指定 URL 抓取资源并保存为对象
+    ///
+    /// `mime` 为对象的 MIME 类型，`meta` 为对象的自定义元信息，其键必须以 `x-qn-meta-` 开头。
+    /// 返回七牛抓取接口的响应，包含 `hash` 和 `key` 字段
+    #[pyo3(text_signature = "($self, url, key, /, mime = None, meta = None)")]
+    #[args(mime = "None", meta = "None")]
+    fn async_copy_from_url<'p>(
+        &self,
+        url: String,
+        key: String,
+        mime: Option<&str>,
+        meta: Option<HashMap<String, String>>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let headers = make_fetch_headers(mime, meta)?;
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let value = bucket
+                .uc
+                .async_call_service_with_headers(
+                    Method::POST,
+                    &[qiniu_sdk::http_client::ServiceName::Io],
+                    bucket.async_region().await?,
+                    bucket.make_fetch_path(&url, &key),
+                    Default::default(),
+                    headers,
+                    None,
+                )
+                .await?;
+            convert_json_value_to_py_object(&value)
+        })
+    }
+
+    /// 从异步迭代器中读取 `(url, key)` 元组，批量抓取网络资源到存储空间
+    ///
+    /// 最多同时抓取 `concurrency` 个资源，返回异步迭代器，按抓取完成的顺序逐个返回结果。
+    /// 每一项结果为 `{"url": str, "key": str, "result": dict}` 或 `{"url": str, "key": str, "error": Exception}`，
+    /// 仅认证失败时，迭代中直接抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, urls_keys, /, concurrency = 8)")]
+    #[args(concurrency = "8")]
+    fn import_from_url_async_bulk(
+        &self,
+        urls_keys: &PyAny,
+        concurrency: usize,
+    ) -> PyResult<AsyncImportFromUrlIterator> {
+        let urls_keys: PyObject = urls_keys.call_method0("__aiter__")?.into();
+        let pairs = futures::stream::try_unfold(urls_keys, |urls_keys| async move {
+            let future = Python::with_gil(|py| {
+                pyo3_asyncio::async_std::into_future(
+                    urls_keys.as_ref(py).call_method0("__anext__")?,
+                )
+            })?;
+            match future.await {
+                Ok(pair) => Python::with_gil(|py| {
+                    PyResult::Ok(Some((pair.extract::<(String, String)>(py)?, urls_keys)))
+                }),
+                Err(err)
+                    if Python::with_gil(|py| err.is_instance_of::<PyStopAsyncIteration>(py)) =>
+                {
+                    Ok(None)
+                }
+                Err(err) => Err(err),
+            }
+        });
+        let bucket = self.to_owned();
+        let results = pairs
+            .map_ok(move |(url, key)| {
+                let bucket = bucket.to_owned();
+                async move {
+                    let result = bucket.async_fetch_url(&url, &key).await;
+                    PyResult::Ok((url, key, result))
+                }
+            })
+            .try_buffer_unordered(concurrency.max(1))
+            .and_then(|(url, key, result)| async move {
+                Python::with_gil(|py| convert_import_from_url_result(url, key, result, py))
+            });
+        Ok(AsyncImportFromUrlIterator(Arc::new(AsyncMutex::new(
+            Box::pin(results),
+        ))))
+    }
+
+    /// 按需分页列举对象
+    ///
+    /// 仅在当前页的对象迭代完毕后才会请求下一页，每页最多 `limit_per_page` 个对象
+    #[pyo3(text_signature = "($self, /, prefix = None, delimiter = None, limit_per_page = 1000)")]
+    #[args(prefix = "None", delimiter = "None", limit_per_page = "1000")]
+    fn iterate_objects(
+        &self,
+        prefix: Option<String>,
+        delimiter: Option<String>,
+        limit_per_page: usize,
+    ) -> ObjectIterator {
+        ObjectIterator {
+            bucket: self.to_owned(),
+            prefix,
+            delimiter,
+            limit_per_page,
+            page: Vec::new(),
+            index: 0,
+            marker: String::new(),
+            last_page: false,
+        }
+    }
+
+    /// 分页列举对象，返回列举到的对象总数
+    ///
+    /// 每收到一页对象后都将调用 `on_page(page_number, items)`，页码从 1 开始，`limit` 为每页最多的对象数量
+    #[pyo3(
+        text_signature = "($self, /, prefix = None, delimiter = None, limit = None, on_page = None)"
+    )]
+    #[args(prefix = "None", delimiter = "None", limit = "None", on_page = "None")]
+    fn list_with_progress(
+        &self,
+        prefix: Option<String>,
+        delimiter: Option<String>,
+        limit: Option<usize>,
+        on_page: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<usize> {
+        let mut iterator =
+            self.iterate_objects(prefix, delimiter, limit.unwrap_or(DEFAULT_LIST_LIMIT));
+        let mut total = 0;
+        for page_number in 1.. {
+            let result = py.allow_threads(|| iterator.fetch_page())?;
+            iterator.load_page(result);
+            total += iterator.page.len();
+            if let Some(on_page) = &on_page {
+                on_page.call1(py, (page_number, iterator.convert_page(py)?))?;
+            }
+            if iterator.last_page {
+                break;
+            }
+        }
+        Ok(total)
+    }
+
+    /// 异步分页列举对象，返回列举到的对象总数
+    ///
+    /// 每收到一页对象后都将调用并等待异步回调函数 `on_page(page_number, items)`，页码从 1 开始，`limit` 为每页最多的对象数量
+    #[pyo3(
+        text_signature = "($self, /, prefix = None, delimiter = None, limit = None, on_page = None)"
+    )]
+    #[args(prefix = "None", delimiter = "None", limit = "None", on_page = "None")]
+    fn async_list_with_progress<'p>(
+        &self,
+        prefix: Option<String>,
+        delimiter: Option<String>,
+        limit: Option<usize>,
+        on_page: Option<PyObject>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let mut iterator =
+            self.iterate_objects(prefix, delimiter, limit.unwrap_or(DEFAULT_LIST_LIMIT));
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut total = 0;
+            for page_number in 1.. {
+                let result = iterator.async_fetch_page().await?;
+                iterator.load_page(result);
+                total += iterator.page.len();
+                if let Some(on_page) = &on_page {
+                    let future = Python::with_gil(|py| {
+                        pyo3_asyncio::async_std::into_future(
+                            on_page
+                                .call1(py, (page_number, iterator.convert_page(py)?))?
+                                .as_ref(py),
+                        )
+                    })?;
+                    future.await?;
+                }
+                if iterator.last_page {
+                    break;
+                }
+            }
+            Ok(total)
+        })
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> PyResult<ObjectsIterator> {
+        self.list(None, None, None, None, None, None, None, None)
+            .__iter__(py)
+    }
+
+    /// 获取对象元信息
+    #[pyo3(text_signature = "($self, object, /, before_request_callback = None)")]
+    #[args(before_request_callback = "None")]
+    fn stat_object(
+        &self,
+        object: String,
+        before_request_callback: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Py<StatObject>> {
+        let stat_object = StatObject {
+            entry: Entry::new(self.to_owned(), object),
+            before_request_callback,
+        };
+        let operation_provider = OperationProvider {
+            operation: qiniu_sdk::objects::OperationProvider::to_operation(
+                &mut stat_object.make_operation(),
+            ),
+        };
+        Py::new(py, (stat_object, operation_provider))
+    }
+
+    /// 复制对象
+    #[pyo3(
+        text_signature = "($self, from_object, to_bucket, to_object, /, force = None, before_request_callback = None)"
+    )]
+    #[args(force = "None", before_request_callback = "None")]
+    fn copy_object_to(
+        &self,
+        from_object: String,
+        to_bucket: String,
+        to_object: String,
+        force: Option<bool>,
+        before_request_callback: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Py<CopyObject>> {
+        let copy_object = CopyObject {
+            from_entry: Entry::new(self.to_owned(), from_object),
+            to_entry: SimpleEntry::new(to_bucket, to_object),
+            force,
+            before_request_callback,
+        };
+        let operation_provider = OperationProvider {
+            operation: qiniu_sdk::objects::OperationProvider::to_operation(
+                &mut copy_object.make_operation(),
+            ),
+        };
+        Py::new(py, (copy_object, operation_provider))
+    }
+
+    /// 移动对象
+    #[pyo3(
+        text_signature = "($self, from_object, to_bucket, to_object, /, force = None, before_request_callback = None)"
+    )]
+    #[args(force = "None", before_request_callback = "None")]
+    fn move_object_to(
+        &self,
+        from_object: String,
+        to_bucket: String,
+        to_object: String,
+        force: Option<bool>,
+        before_request_callback: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Py<MoveObject>> {
+        let move_object = MoveObject {
+            from_entry: Entry::new(self.to_owned(), from_object),
+            to_entry: SimpleEntry::new(to_bucket, to_object),
+            force,
+            before_request_callback,
+        };
+        let operation_provider = OperationProvider {
+            operation: qiniu_sdk::objects::OperationProvider::to_operation(
+                &mut move_object.make_operation(),
+            ),
+        };
+        Py::new(py, (move_object, operation_provider))
+    }
+
+    /// 复制对象
+    ///
+    /// 如果 `force` 为 `False` 且目标对象已经存在，将抛出 `QiniuApiCallError` 异常，状态码为 614
+    #[pyo3(text_signature = "($self, from_key, to_bucket, to_key, /, force = False)")]
+    #[args(force = "false")]
+    fn copy_object(
+        &self,
+        from_key: &str,
+        to_bucket: &str,
+        to_key: &str,
+        force: bool,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            self.inner
+                .copy_object_to(from_key, to_bucket, to_key)
+                .is_force(force)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步复制对象
+    ///
+    /// 如果 `force` 为 `False` 且目标对象已经存在，将抛出 `QiniuApiCallError` 异常，状态码为 614
+    #[pyo3(text_signature = "($self, from_key, to_bucket, to_key, /, force = False)")]
+    #[args(force = "false")]
+    fn async_copy_object<'p>(
+        &self,
+        from_key: String,
+        to_bucket: String,
+        to_key: String,
+        force: bool,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            bucket
+                .inner
+                .copy_object_to(&from_key, &to_bucket, &to_key)
+                .is_force(force)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 移动对象
+    ///
+    /// 如果 `force` 为 `False` 且目标对象已经存在，将抛出 `QiniuApiCallError` 异常，状态码为 614
+    #[pyo3(text_signature = "($self, from_key, to_bucket, to_key, /, force = False)")]
+    #[args(force = "false")]
+    fn move_object(
+        &self,
+        from_key: &str,
+        to_bucket: &str,
+        to_key: &str,
+        force: bool,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            self.inner
+                .move_object_to(from_key, to_bucket, to_key)
+                .is_force(force)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步移动对象
+    ///
+    /// 如果 `force` 为 `False` 且目标对象已经存在，将抛出 `QiniuApiCallError` 异常，状态码为 614
+    #[pyo3(text_signature = "($self, from_key, to_bucket, to_key, /, force = False)")]
+    #[args(force = "false")]
+    fn async_move_object<'p>(
+        &self,
+        from_key: String,
+        to_bucket: String,
+        to_key: String,
+        force: bool,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            bucket
+                .inner
+                .move_object_to(&from_key, &to_bucket, &to_key)
+                .is_force(force)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 在当前存储空间内重命名对象
+    ///
+    /// 相当于目标存储空间为当前存储空间的 `move_object()`
+    #[pyo3(text_signature = "($self, key, new_key, /, force = False)")]
+    #[args(force = "false")]
+    fn rename(&self, key: &str, new_key: &str, force: bool, py: Python<'_>) -> PyResult<()> {
+        let bucket_name = self.inner.name().to_string();
+        self.move_object(key, &bucket_name, new_key, force, py)
+    }
+
+    /// 异步在当前存储空间内重命名对象
+    ///
+    /// 相当于目标存储空间为当前存储空间的 `async_move_object()`
+    #[pyo3(text_signature = "($self, key, new_key, /, force = False)")]
+    #[args(force = "false")]
+    fn async_rename<'p>(
+        &self,
+        key: String,
+        new_key: String,
+        force: bool,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket_name = self.inner.name().to_string();
+        self.async_move_object(key, bucket_name, new_key, force, py)
+    }
+
+    /// 删除对象
+    #[pyo3(text_signature = "($self, object, /, before_request_callback = None)")]
+    #[args(before_request_callback = "None")]
+    fn delete_object(
+        &self,
+        object: String,
+        before_request_callback: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Py<DeleteObject>> {
+        let delete_object = DeleteObject {
+            entry: Entry::new(self.to_owned(), object),
+            before_request_callback,
+        };
+        let operation_provider = OperationProvider {
+            operation: qiniu_sdk::objects::OperationProvider::to_operation(
+                &mut delete_object.make_operation(),
+            ),
+        };
+        Py::new(py, (delete_object, operation_provider))
+    }
+
+    /// 解冻对象
+    #[pyo3(
+        text_signature = "($self, object, freeze_after_days, /, before_request_callback = None)"
+    )]
+    #[args(before_request_callback = "None")]
+    fn restore_archived_object(
+        &self,
+        object: String,
+        freeze_after_days: usize,
+        before_request_callback: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Py<UnfreezeObject>> {
+        let restore_archived_object = UnfreezeObject {
+            entry: Entry::new(self.to_owned(), object),
+            freeze_after_days,
+            before_request_callback,
+        };
+        let operation_provider = OperationProvider {
+            operation: qiniu_sdk::objects::OperationProvider::to_operation(
+                &mut restore_archived_object.make_operation(),
+            ),
+        };
+        Py::new(py, (restore_archived_object, operation_provider))
+    }
+
+    /// 解冻归档存储类型的对象
+    ///
+    /// 解冻后的对象将在 `freeze_after_days` 天后重新冻结，如果对象不是归档存储类型，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key, /, freeze_after_days = 1)")]
+    #[args(freeze_after_days = "1")]
+    fn restore_archived(
+        &self,
+        key: &str,
+        freeze_after_days: usize,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            self.inner
+                .restore_archived_object(key, freeze_after_days)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步解冻归档存储类型的对象
+    ///
+    /// 解冻后的对象将在 `freeze_after_days` 天后重新冻结，如果对象不是归档存储类型，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key, /, freeze_after_days = 1)")]
+    #[args(freeze_after_days = "1")]
+    fn async_restore_archived<'p>(
+        &self,
+        key: String,
+        freeze_after_days: usize,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            bucket
+                .inner
+                .restore_archived_object(&key, freeze_after_days)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 获取对象的存储类型
+    #[pyo3(text_signature = "($self, key)")]
+    fn get_storage_class(&self, key: &str, py: Python<'_>) -> PyResult<StorageClass> {
+        let resp = py.allow_threads(|| {
+            self.inner
+                .stat_object(key)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        let (_, body) = resp.into_parts_and_body();
+        StorageClass::from_stat_result(body.as_ref())
+    }
+
+    /// 异步获取对象的存储类型
+    #[pyo3(text_signature = "($self, key)")]
+    fn async_get_storage_class<'p>(&self, key: String, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = bucket
+                .inner
+                .stat_object(&key)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            StorageClass::from_stat_result(body.as_ref())
+        })
+    }
+
+    /// 修改对象的存储类型
+    ///
+    /// 如果修改失败，例如对象为尚未解冻的归档存储对象，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key, storage_type)")]
+    fn change_storage_type(
+        &self,
+        key: &str,
+        storage_type: StorageClass,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        py.allow_threads(|| {
+            self.inner
+                .set_object_type(key, storage_type.into())
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步修改对象的存储类型
+    ///
+    /// 如果修改失败，例如对象为尚未解冻的归档存储对象，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, key, storage_type)")]
+    fn async_change_storage_type<'p>(
+        &self,
+        key: String,
+        storage_type: StorageClass,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            bucket
+                .inner
+                .set_object_type(&key, storage_type.into())
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 为对象设置标签
+    ///
+    /// 标签以对象自定义元信息的形式保存，与 `change_meta()` 共享 `x-qn-meta-` 命名空间，同名标签或元信息将被覆盖
+    #[pyo3(text_signature = "($self, key, tags)")]
+    fn tag(&self, key: &str, tags: HashMap<String, String>, py: Python<'_>) -> PyResult<()> {
+        validate_tags(&tags)?;
+        py.allow_threads(|| {
+            let resp = self
+                .inner
+                .stat_object(key)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            self.make_set_tags_operation(key, body.as_ref(), &tags)?
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 异步为对象设置标签
+    ///
+    /// 标签以对象自定义元信息的形式保存，与 `change_meta()` 共享 `x-qn-meta-` 命名空间，同名标签或元信息将被覆盖
+    #[pyo3(text_signature = "($self, key, tags)")]
+    fn async_tag<'p>(
+        &self,
+        key: String,
+        tags: HashMap<String, String>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        validate_tags(&tags)?;
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = bucket
+                .inner
+                .stat_object(&key)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            bucket
+                .make_set_tags_operation(&key, body.as_ref(), &tags)?
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 获取对象标签
+    ///
+    /// 将返回所有值不为空的自定义元信息，包括通过 `change_meta()` 设置的元信息
+    #[pyo3(text_signature = "($self, key)")]
+    fn get_tags(&self, key: &str, py: Python<'_>) -> PyResult<HashMap<String, String>> {
+        let resp = py.allow_threads(|| {
+            self.inner
+                .stat_object(key)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        let (_, body) = resp.into_parts_and_body();
+        Ok(tags_from_stat_result(body.as_ref()))
+    }
+
+    /// 异步获取对象标签
+    ///
+    /// 将返回所有值不为空的自定义元信息，包括通过 `change_meta()` 设置的元信息
+    #[pyo3(text_signature = "($self, key)")]
+    fn async_get_tags<'p>(&self, key: String, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = bucket
+                .inner
+                .stat_object(&key)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            Ok(tags_from_stat_result(body.as_ref()))
+        })
+    }
+
+    /// 清除对象标签
+    ///
+    /// 所有已有标签（包括通过 `change_meta()` 设置的元信息）的值都将被置空，此后 `get_tags()` 将不再返回这些标签
+    #[pyo3(text_signature = "($self, key)")]
+    fn untag(&self, key: &str, py: Python<'_>) -> PyResult<()> {
+        py.allow_threads(|| {
+            let resp = self
+                .inner
+                .stat_object(key)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            let tags = empty_tags_from_stat_result(body.as_ref());
+            if !tags.is_empty() {
+                self.make_set_tags_operation(key, body.as_ref(), &tags)?
+                    .call()
+                    .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// 异步清除对象标签
+    ///
+    /// 所有已有标签（包括通过 `change_meta()` 设置的元信息）的值都将被置空，此后 `get_tags()` 将不再返回这些标签
+    #[pyo3(text_signature = "($self, key)")]
+    fn async_untag<'p>(&self, key: String, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = bucket
+                .inner
+                .stat_object(&key)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            let tags = empty_tags_from_stat_result(body.as_ref());
+            if !tags.is_empty() {
+                bucket
+                    .make_set_tags_operation(&key, body.as_ref(), &tags)?
+                    .async_call()
+                    .await
+                    .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            }
+            Ok(())
+        })
+    }
+
+    /// 修改对象的 MIME 类型
+    ///
+    /// 如果 `mime` 不是合法的 MIME 类型，将在发出请求前抛出 `QiniuMimeParseError` 异常
+    #[pyo3(text_signature = "($self, key, mime)")]
+    fn change_mime(&self, key: &str, mime: &str, py: Python<'_>) -> PyResult<()> {
+        let mime = parse_mime(mime)?;
+        py.allow_threads(|| {
+            self.inner
+                .modify_object_metadata(key, mime)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        Ok(())
+    }
+
+    /// 异步修改对象的 MIME 类型
+    ///
+    /// 如果 `mime` 不是合法的 MIME 类型，将在发出请求前抛出 `QiniuMimeParseError` 异常
+    #[pyo3(text_signature = "($self, key, mime)")]
+    fn async_change_mime<'p>(
+        &self,
+        key: String,
+        mime: &str,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let mime = parse_mime(mime)?;
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            bucket
+                .inner
+                .modify_object_metadata(&key, mime)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 批量修改对象的自定义元信息
+    ///
+    /// `meta` 的所有键必须以 `x-qn-meta-` 开头，否则将抛出 `QiniuUnsupportedTypeError` 异常。
+    /// 对象原有的 MIME 类型保持不变，元信息与 `tag()` 设置的标签共享同一命名空间
+    #[pyo3(text_signature = "($self, key, meta)")]
+    fn change_meta(
+        &self,
+        key: &str,
+        meta: HashMap<String, String>,
+        py: Python<'_>,
+    ) -> PyResult<()> {
+        let meta = strip_metadata_prefix(meta)?;
+        py.allow_threads(|| {
+            let resp = self
+                .inner
+                .stat_object(key)
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            self.make_set_tags_operation(key, body.as_ref(), &meta)?
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 异步批量修改对象的自定义元信息
+    ///
+    /// `meta` 的所有键必须以 `x-qn-meta-` 开头，否则将抛出 `QiniuUnsupportedTypeError` 异常。
+    /// 对象原有的 MIME 类型保持不变，元信息与 `tag()` 设置的标签共享同一命名空间
+    #[pyo3(text_signature = "($self, key, meta)")]
+    fn async_change_meta<'p>(
+        &self,
+        key: String,
+        meta: HashMap<String, String>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let meta = strip_metadata_prefix(meta)?;
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = bucket
+                .inner
+                .stat_object(&key)
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (_, body) = resp.into_parts_and_body();
+            bucket
+                .make_set_tags_operation(&key, body.as_ref(), &meta)?
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            Ok(())
+        })
+    }
+
+    /// 设置对象类型
+    #[pyo3(text_signature = "($self, object, object_type, /, before_request_callback = None)")]
+    #[args(before_request_callback = "None")]
+    fn set_object_type(
+        &self,
+        object: String,
+        object_type: u8,
+        before_request_callback: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<Py<SetObjectType>> {
+        let set_object_type = SetObjectType {
+            entry: Entry::new(self.to_owned(), object),
+            file_type: qiniu_sdk::upload_token::FileType::from(object_type),
+            before_request_callback,
+        };
+        let operation_provider = OperationProvider {
+            operation: qiniu_sdk::objects::OperationProvider::to_operation(
+                &mut set_object_type.make_operation(),
+            ),
+        };
+        Py::new(py, (set_object_type, operation_provider))
+    }
+
+    /// 设置对象状态
+    #[pyo3(text_signature = "($self, object, disabled, /, before_request_callback = None)")]
+    #[args(before_request_callback = "None")]
+    fn modify_object_status(
         &self,
         object: String,
         disabled: bool,
@@ -407,39 +2187,448 @@ impl Bucket {
         Py::new(py, (modify_object_life_cycle, operation_provider))
     }
 
-    #[pyo3(
-        text_signature = "($self, before_request_callback = None, after_response_ok_callback = None)"
-    )]
-    #[args(before_request_callback = "None", after_response_ok_callback = "None")]
-    fn batch_ops(
-        &self,
-        before_request_callback: Option<PyObject>,
-        after_response_ok_callback: Option<PyObject>,
-        after_response_error_callback: Option<PyObject>,
-    ) -> BatchOperations {
-        let bucket = Arc::pin(self.to_owned());
-        #[allow(unsafe_code)]
-        let mut operations = unsafe {
-            transmute::<_, qiniu_sdk::objects::BatchOperations<'static>>(bucket.0.batch_ops())
-        };
-        if let Some(callback) = before_request_callback {
-            operations.before_request_callback(make_before_request_callback(callback));
-        }
-        if let Some(callback) = after_response_ok_callback {
-            operations.after_response_ok_callback(make_after_response_ok_callback(callback));
-        }
-        if let Some(callback) = after_response_error_callback {
-            operations.after_response_error_callback(make_after_response_error_callback(callback));
-        }
-        BatchOperations { bucket, operations }
+    /// 批量有条件删除对象
+    ///
+    /// `conditions` 必须与 `keys` 长度一致，条件为 `None` 的对象将被无条件删除。
+    /// 条件由客户端先批量获取对象信息后检查，再批量删除满足条件的对象，两次请求之间对象仍可能被修改，因此检查和删除并非原子操作。
+    /// 返回每个对象的删除结果，删除成功则为 `None`，否则为 `QiniuApiCallError` 异常，不满足条件的对象对应由客户端构造的状态码为 412 的异常，该对象不会被删除
+    #[pyo3(text_signature = "($self, keys, conditions)")]
+    fn batch_delete_with_conditions(
+        &self,
+        keys: Vec<String>,
+        conditions: Vec<Option<BatchDeleteCondition>>,
+        py: Python<'_>,
+    ) -> PyResult<Vec<Option<PyObject>>> {
+        check_batch_delete_conditions(&keys, &conditions)?;
+        let results = py.allow_threads(|| {
+            let stat_results = self.batch_call(&conditional_keys(&keys, &conditions), false);
+            let mut results = apply_batch_delete_conditions(&keys, &conditions, stat_results);
+            let delete_results = self.batch_call(&pending_keys(&keys, &results), true);
+            fill_batch_delete_results(&mut results, delete_results);
+            results
+        });
+        Ok(convert_batch_delete_results(results, py))
+    }
+
+    /// 异步批量有条件删除对象
+    ///
+    /// `conditions` 必须与 `keys` 长度一致，条件为 `None` 的对象将被无条件删除。
+    /// 条件由客户端先批量获取对象信息后检查，再批量删除满足条件的对象，两次请求之间对象仍可能被修改，因此检查和删除并非原子操作。
+    /// 返回每个对象的删除结果，删除成功则为 `None`，否则为 `QiniuApiCallError` 异常，不满足条件的对象对应由客户端构造的状态码为 412 的异常，该对象不会被删除
+    #[pyo3(text_signature = "($self, keys, conditions)")]
+    fn async_batch_delete_with_conditions<'p>(
+        &self,
+        keys: Vec<String>,
+        conditions: Vec<Option<BatchDeleteCondition>>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        check_batch_delete_conditions(&keys, &conditions)?;
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let stat_results = bucket
+                .async_batch_call(&conditional_keys(&keys, &conditions), false)
+                .await;
+            let mut results = apply_batch_delete_conditions(&keys, &conditions, stat_results);
+            let delete_results = bucket
+                .async_batch_call(&pending_keys(&keys, &results), true)
+                .await;
+            fill_batch_delete_results(&mut results, delete_results);
+            Ok(Python::with_gil(|py| {
+                convert_batch_delete_results(results, py)
+            }))
+        })
+    }
+
+    /// 批量获取对象元信息
+    ///
+    /// 返回与 `keys` 一一对应的结果列表，获取成功则为 `StatResult`，否则为 `QiniuApiCallError` 异常。
+    /// 仅在认证失败时抛出异常
+    #[pyo3(text_signature = "($self, keys)")]
+    fn stat_batch(&self, keys: Vec<String>, py: Python<'_>) -> PyResult<Vec<PyObject>> {
+        let results = py.allow_threads(|| {
+            self.batch_call(&keys.iter().map(String::as_str).collect::<Vec<_>>(), false)
+        });
+        convert_stat_batch_results(&keys, results, py)
+    }
+
+    /// 异步批量获取对象元信息
+    ///
+    /// 返回与 `keys` 一一对应的结果列表，获取成功则为 `StatResult`，否则为 `QiniuApiCallError` 异常。
+    /// 仅在认证失败时抛出异常
+    #[pyo3(text_signature = "($self, keys)")]
+    fn async_stat_batch<'p>(&self, keys: Vec<String>, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let bucket = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let results = bucket
+                .async_batch_call(&keys.iter().map(String::as_str).collect::<Vec<_>>(), false)
+                .await;
+            Python::with_gil(|py| convert_stat_batch_results(&keys, results, py))
+        })
+    }
+
+    #[pyo3(
+        text_signature = "($self, before_request_callback = None, after_response_ok_callback = None)"
+    )]
+    #[args(before_request_callback = "None", after_response_ok_callback = "None")]
+    fn batch_ops(
+        &self,
+        before_request_callback: Option<PyObject>,
+        after_response_ok_callback: Option<PyObject>,
+        after_response_error_callback: Option<PyObject>,
+    ) -> BatchOperations {
+        let bucket = Arc::pin(self.to_owned());
+        #[allow(unsafe_code)]
+        let mut operations = unsafe {
+            transmute::<_, qiniu_sdk::objects::BatchOperations<'static>>(bucket.inner.batch_ops())
+        };
+        if let Some(callback) = before_request_callback {
+            operations.before_request_callback(make_before_request_callback(callback));
+        }
+        if let Some(callback) = after_response_ok_callback {
+            operations.after_response_ok_callback(make_after_response_ok_callback(callback));
+        }
+        if let Some(callback) = after_response_error_callback {
+            operations.after_response_error_callback(make_after_response_error_callback(callback));
+        }
+        BatchOperations { bucket, operations }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Bucket(name='{}')", self.inner.name())
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.name().to_string()
+    }
+}
+
+impl Bucket {
+    fn region(&self) -> PyResult<qiniu_sdk::http_client::Region> {
+        self.inner
+            .region_provider()
+            .and_then(|provider| provider.get(Default::default()))
+            .map(|region| region.into_region())
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+    }
+
+    async fn async_region(&self) -> PyResult<qiniu_sdk::http_client::Region> {
+        let provider = self
+            .inner
+            .region_provider()
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+        provider
+            .async_get(Default::default())
+            .await
+            .map(|region| region.into_region())
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+    }
+
+    async fn async_fetch_url(&self, url: &str, key: &str) -> PyResult<serde_json::Value> {
+        self.uc
+            .async_call_service(
+                Method::POST,
+                &[qiniu_sdk::http_client::ServiceName::Io],
+                self.async_region().await?,
+                self.make_fetch_path(url, key),
+                Default::default(),
+                None,
+            )
+            .await
+    }
+
+    fn make_fetch_path(&self, url: &str, key: &str) -> String {
+        format!(
+            "/fetch/{}/to/{}",
+            qiniu_sdk::utils::base64::urlsafe(url.as_bytes()),
+            qiniu_sdk::utils::base64::urlsafe(format!("{}:{}", self.inner.name(), key).as_bytes()),
+        )
+    }
+
+    fn make_object_url(&self, domain: &str, key: &str, use_https: Option<bool>) -> String {
+        let scheme = if use_https.or(self.uc.use_https).unwrap_or(true) {
+            "https"
+        } else {
+            "http"
+        };
+        format!("{}://{}/{}", scheme, domain, encode_url_path(key))
+    }
+
+    fn batch_call(
+        &self,
+        keys: &[&str],
+        delete: bool,
+    ) -> Vec<qiniu_sdk::http_client::ApiResult<serde_json::Value>> {
+        if keys.is_empty() {
+            return Vec::new();
+        }
+        self.make_batch_ops(keys, delete)
+            .call()
+            .map(|result| result.map(serde_json::Value::from))
+            .collect()
+    }
+
+    async fn async_batch_call(
+        &self,
+        keys: &[&str],
+        delete: bool,
+    ) -> Vec<qiniu_sdk::http_client::ApiResult<serde_json::Value>> {
+        if keys.is_empty() {
+            return Vec::new();
+        }
+        self.make_batch_ops(keys, delete)
+            .async_call()
+            .map(|result| result.map(serde_json::Value::from))
+            .collect()
+            .await
+    }
+
+    fn make_batch_ops<'a>(
+        &'a self,
+        keys: &[&'a str],
+        delete: bool,
+    ) -> qiniu_sdk::objects::BatchOperations<'a> {
+        let mut ops = self.inner.batch_ops();
+        for &key in keys {
+            if delete {
+                ops.add_operation(self.inner.delete_object(key));
+            } else {
+                ops.add_operation(self.inner.stat_object(key));
+            }
+        }
+        ops
+    }
+
+    fn make_set_tags_operation(
+        &self,
+        key: &str,
+        stat_result: &serde_json::Value,
+        tags: &HashMap<String, String>,
+    ) -> PyResult<qiniu_sdk::objects::ModifyObjectMetadataBuilder> {
+        let mime_type = stat_result
+            .get("mimeType")
+            .and_then(|mime_type| mime_type.as_str())
+            .unwrap_or("application/octet-stream");
+        let mut builder = self
+            .inner
+            .modify_object_metadata(key, parse_mime(mime_type)?);
+        for (key, value) in tags {
+            builder.add_metadata(key, value);
+        }
+        Ok(builder)
+    }
+
+    fn make_domains_query_pairs(&self) -> Vec<qiniu_sdk::http_client::QueryPair<'static>> {
+        vec![("tbl".into(), self.inner.name().to_string().into())]
+    }
+
+    fn make_cors_path(&self, action: &str) -> String {
+        format!("/corsRules/{}/{}", action, self.inner.name())
+    }
+}
+
+/// 带签名的私有下载地址
+///
+/// 可以通过 `bucket.make_private_url()` 方法获取
+#[pyclass]
+#[derive(Clone, Debug)]
+struct SignedUrl {
+    url: String,
+    expires_at: SystemTime,
+}
+
+#[pymethods]
+impl SignedUrl {
+    /// 获取下载地址
+    #[getter]
+    fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    /// 获取过期时间，以 UNIX 时间戳表示
+    #[getter]
+    fn get_expires_at(&self) -> PyResult<f64> {
+        Ok(self
+            .expires_at
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(QiniuTimeError::from_err)?
+            .as_secs_f64())
+    }
+
+    /// 下载地址是否已经过期
+    #[pyo3(text_signature = "($self)")]
+    fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expires_at
+    }
+
+    fn __fspath__(&self) -> &str {
+        &self.url
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> PyObject {
+        match op {
+            CompareOp::Eq => (self.url == other.url).to_object(py),
+            CompareOp::Ne => (self.url != other.url).to_object(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.url.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __str__(&self) -> &str {
+        &self.url
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// 存储空间信息
+///
+/// 可以通过 `bucket.get_bucket_info()` 方法获取
+#[pyclass]
+#[derive(Clone, Debug)]
+struct BucketInfo {
+    region_id: String,
+    private: bool,
+    file_count: u64,
+    storage_size: u64,
+    storage_type: u64,
+}
+
+#[pymethods]
+impl BucketInfo {
+    /// 获取存储空间所在区域 ID
+    #[getter]
+    fn get_region_id(&self) -> &str {
+        &self.region_id
+    }
+
+    /// 存储空间是否为私有空间
+    #[getter]
+    fn get_private(&self) -> bool {
+        self.private
+    }
+
+    /// 获取存储空间的文件数量
+    #[getter]
+    fn get_file_count(&self) -> u64 {
+        self.file_count
+    }
+
+    /// 获取存储空间的存储量，单位为字节
+    #[getter]
+    fn get_storage_size(&self) -> u64 {
+        self.storage_size
+    }
+
+    /// 获取存储空间的默认存储类型
+    #[getter]
+    fn get_storage_type(&self) -> u64 {
+        self.storage_type
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl BucketInfo {
+    fn from_json(value: &serde_json::Value) -> Self {
+        let get_u64 = |name: &str| value.get(name).and_then(|v| v.as_u64()).unwrap_or(0);
+        Self {
+            region_id: value
+                .get("region")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_owned(),
+            private: get_u64("private") == 1,
+            file_count: get_u64("file_num"),
+            storage_size: get_u64("storage_size"),
+            storage_type: get_u64("storage_type"),
+        }
+    }
+}
+
+/// 对象元信息
+///
+/// 可以通过 `bucket.stat_batch()` 方法获取
+#[pyclass]
+#[derive(Clone, Debug)]
+struct StatResult {
+    key: String,
+    hash: String,
+    fsize: u64,
+    mime_type: String,
+    put_time: f64,
+}
+
+#[pymethods]
+impl StatResult {
+    /// 获取对象名称
+    #[getter]
+    fn get_key(&self) -> &str {
+        &self.key
+    }
+
+    /// 获取对象的 Etag
+    #[getter]
+    fn get_hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// 获取对象大小，单位为字节
+    #[getter]
+    fn get_fsize(&self) -> u64 {
+        self.fsize
+    }
+
+    /// 获取对象的 MIME 类型
+    #[getter]
+    fn get_mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// 获取对象的上传时间，为 UNIX 时间戳，单位为秒
+    #[getter]
+    fn get_put_time(&self) -> f64 {
+        self.put_time
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
     }
 
     fn __str__(&self) -> String {
         self.__repr__()
     }
+}
 
-    fn __repr__(&self) -> String {
-        format!("{:?}", self.0)
+impl StatResult {
+    fn from_json(key: &str, value: &serde_json::Value) -> Self {
+        let get_str = |name: &str| {
+            value
+                .get(name)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_owned()
+        };
+        Self {
+            key: key.to_owned(),
+            hash: get_str("hash"),
+            fsize: value.get("fsize").and_then(|v| v.as_u64()).unwrap_or(0),
+            mime_type: get_str("mimeType"),
+            // `putTime` 的单位为 100 纳秒
+            put_time: value.get("putTime").and_then(|v| v.as_u64()).unwrap_or(0) as f64 / 1e7,
+        }
     }
 }
 
@@ -485,24 +2674,241 @@ struct SimpleEntry {
     object: String,
 }
 
-impl SimpleEntry {
-    fn new(bucket: String, object: String) -> Self {
-        Self { bucket, object }
+impl SimpleEntry {
+    fn new(bucket: String, object: String) -> Self {
+        Self { bucket, object }
+    }
+}
+
+/// 对象元信息获取操作构建器
+///
+/// 可以通过 `bucket.stat_object()` 方法获取该构建器。
+#[pyclass(extends = OperationProvider)]
+#[derive(Clone, Debug)]
+struct StatObject {
+    entry: Entry,
+    before_request_callback: Option<PyObject>,
+}
+
+#[pymethods]
+impl StatObject {
+    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
+        let resp = py.allow_threads(|| {
+            self.make_operation()
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        let (parts, body) = resp.into_parts_and_body();
+        make_json_response(parts, body.as_ref(), py)
+    }
+
+    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let stat_object = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = stat_object
+                .make_operation()
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (parts, body) = resp.into_parts_and_body();
+            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+        })
+    }
+}
+
+impl StatObject {
+    fn make_operation(&self) -> qiniu_sdk::objects::StatObjectBuilder {
+        let mut builder = self.entry.bucket.inner.stat_object(&self.entry.object);
+        if let Some(callback) = &self.before_request_callback {
+            Python::with_gil(|py| {
+                builder
+                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
+            });
+        }
+        builder
+    }
+}
+
+/// 对象复制操作构建器
+///
+/// 可以通过 `bucket.copy_object_to()` 方法获取该构建器。
+#[pyclass(extends = OperationProvider)]
+#[derive(Clone, Debug)]
+struct CopyObject {
+    from_entry: Entry,
+    to_entry: SimpleEntry,
+    force: Option<bool>,
+    before_request_callback: Option<PyObject>,
+}
+
+#[pymethods]
+impl CopyObject {
+    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
+        let resp = py.allow_threads(|| {
+            self.make_operation()
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        let (parts, body) = resp.into_parts_and_body();
+        make_json_response(parts, body.as_ref(), py)
+    }
+
+    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let copy_object = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = copy_object
+                .make_operation()
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (parts, body) = resp.into_parts_and_body();
+            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+        })
+    }
+}
+
+impl CopyObject {
+    fn make_operation(&self) -> qiniu_sdk::objects::CopyObjectBuilder {
+        let mut copy_object = self.from_entry.bucket.inner.copy_object_to(
+            &self.from_entry.object,
+            &self.to_entry.bucket,
+            &self.to_entry.object,
+        );
+        if let Some(force) = self.force {
+            copy_object.is_force(force);
+        }
+        if let Some(callback) = &self.before_request_callback {
+            Python::with_gil(|py| {
+                copy_object
+                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
+            });
+        }
+        copy_object
+    }
+}
+
+/// 对象移动操作构建器
+///
+/// 可以通过 `bucket.move_object_to()` 方法获取该构建器。
+#[pyclass(extends = OperationProvider)]
+#[derive(Clone, Debug)]
+struct MoveObject {
+    from_entry: Entry,
+    to_entry: SimpleEntry,
+    force: Option<bool>,
+    before_request_callback: Option<PyObject>,
+}
+
+#[pymethods]
+impl MoveObject {
+    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
+        let resp = py.allow_threads(|| {
+            self.make_operation()
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        let (parts, body) = resp.into_parts_and_body();
+        make_json_response(parts, body.as_ref(), py)
+    }
+
+    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let move_object = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = move_object
+                .make_operation()
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (parts, body) = resp.into_parts_and_body();
+            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+        })
+    }
+}
+
+impl MoveObject {
+    fn make_operation(&self) -> qiniu_sdk::objects::MoveObjectBuilder {
+        let mut move_object = self.from_entry.bucket.inner.move_object_to(
+            &self.from_entry.object,
+            &self.to_entry.bucket,
+            &self.to_entry.object,
+        );
+        if let Some(force) = self.force {
+            move_object.is_force(force);
+        }
+        if let Some(callback) = &self.before_request_callback {
+            Python::with_gil(|py| {
+                move_object
+                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
+            });
+        }
+        move_object
+    }
+}
+
+/// 对象元信息删除操作构建器
+///
+/// 可以通过 `bucket.delete_object()` 方法获取该构建器。
+#[pyclass(extends = OperationProvider)]
+#[derive(Clone, Debug)]
+struct DeleteObject {
+    entry: Entry,
+    before_request_callback: Option<PyObject>,
+}
+
+#[pymethods]
+impl DeleteObject {
+    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
+        let resp = py.allow_threads(|| {
+            self.make_operation()
+                .call()
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        let (parts, body) = resp.into_parts_and_body();
+        make_json_response(parts, body.as_ref(), py)
+    }
+
+    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let delete_object = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = delete_object
+                .make_operation()
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (parts, body) = resp.into_parts_and_body();
+            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+        })
+    }
+}
+
+impl DeleteObject {
+    fn make_operation(&self) -> qiniu_sdk::objects::DeleteObjectBuilder {
+        let mut builder = self.entry.bucket.inner.delete_object(&self.entry.object);
+
+        if let Some(callback) = &self.before_request_callback {
+            Python::with_gil(|py| {
+                builder
+                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
+            });
+        }
+
+        builder
     }
 }
 
-/// 对象元信息获取操作构建器
+/// 对象解冻操作构建器
 ///
-/// 可以通过 `bucket.stat_object()` 方法获取该构建器。
+/// 可以通过 `bucket.restore_archived_object()` 方法获取该构建器。
 #[pyclass(extends = OperationProvider)]
 #[derive(Clone, Debug)]
-struct StatObject {
+struct UnfreezeObject {
     entry: Entry,
+    freeze_after_days: usize,
     before_request_callback: Option<PyObject>,
 }
 
 #[pymethods]
-impl StatObject {
+impl UnfreezeObject {
     fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
         let resp = py.allow_threads(|| {
             self.make_operation()
@@ -514,9 +2920,9 @@ impl StatObject {
     }
 
     fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let stat_object = self.to_owned();
+        let unfreeze_object = self.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = stat_object
+            let resp = unfreeze_object
                 .make_operation()
                 .async_call()
                 .await
@@ -527,33 +2933,38 @@ impl StatObject {
     }
 }
 
-impl StatObject {
-    fn make_operation(&self) -> qiniu_sdk::objects::StatObjectBuilder {
-        let mut builder = self.entry.bucket.0.stat_object(&self.entry.object);
+impl UnfreezeObject {
+    fn make_operation(&self) -> qiniu_sdk::objects::UnfreezeObjectBuilder {
+        let mut builder = self
+            .entry
+            .bucket
+            .inner
+            .restore_archived_object(&self.entry.object, self.freeze_after_days);
+
         if let Some(callback) = &self.before_request_callback {
             Python::with_gil(|py| {
                 builder
                     .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
             });
         }
+
         builder
     }
 }
 
-/// 对象复制操作构建器
+/// 对象类型设置操作构建器
 ///
-/// 可以通过 `bucket.copy_object_to()` 方法获取该构建器。
+/// 可以通过 `bucket.set_object_type()` 方法获取该构建器。
 #[pyclass(extends = OperationProvider)]
 #[derive(Clone, Debug)]
-struct CopyObject {
-    from_entry: Entry,
-    to_entry: SimpleEntry,
-    force: Option<bool>,
+struct SetObjectType {
+    entry: Entry,
+    file_type: qiniu_sdk::upload_token::FileType,
     before_request_callback: Option<PyObject>,
 }
 
 #[pymethods]
-impl CopyObject {
+impl SetObjectType {
     fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
         let resp = py.allow_threads(|| {
             self.make_operation()
@@ -565,9 +2976,9 @@ impl CopyObject {
     }
 
     fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let copy_object = self.to_owned();
+        let set_object_type = self.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = copy_object
+            let resp = set_object_type
                 .make_operation()
                 .async_call()
                 .await
@@ -578,40 +2989,38 @@ impl CopyObject {
     }
 }
 
-impl CopyObject {
-    fn make_operation(&self) -> qiniu_sdk::objects::CopyObjectBuilder {
-        let mut copy_object = self.from_entry.bucket.0.copy_object_to(
-            &self.from_entry.object,
-            &self.to_entry.bucket,
-            &self.to_entry.object,
-        );
-        if let Some(force) = self.force {
-            copy_object.is_force(force);
-        }
+impl SetObjectType {
+    fn make_operation(&self) -> qiniu_sdk::objects::SetObjectTypeBuilder {
+        let mut builder = self
+            .entry
+            .bucket
+            .inner
+            .set_object_type(&self.entry.object, self.file_type);
+
         if let Some(callback) = &self.before_request_callback {
             Python::with_gil(|py| {
-                copy_object
+                builder
                     .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
             });
         }
-        copy_object
+
+        builder
     }
 }
 
-/// 对象移动操作构建器
+/// 修改对象状态构建器
 ///
-/// 可以通过 `bucket.move_object_to()` 方法获取该构建器。
+/// 可以通过 `bucket::modify_object_status()` 方法获取该构建器。
 #[pyclass(extends = OperationProvider)]
 #[derive(Clone, Debug)]
-struct MoveObject {
-    from_entry: Entry,
-    to_entry: SimpleEntry,
-    force: Option<bool>,
+struct ModifyObjectStatus {
+    entry: Entry,
+    disabled: bool,
     before_request_callback: Option<PyObject>,
 }
 
 #[pymethods]
-impl MoveObject {
+impl ModifyObjectStatus {
     fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
         let resp = py.allow_threads(|| {
             self.make_operation()
@@ -623,9 +3032,9 @@ impl MoveObject {
     }
 
     fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let move_object = self.to_owned();
+        let modify_object_status = self.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = move_object
+            let resp = modify_object_status
                 .make_operation()
                 .async_call()
                 .await
@@ -636,38 +3045,40 @@ impl MoveObject {
     }
 }
 
-impl MoveObject {
-    fn make_operation(&self) -> qiniu_sdk::objects::MoveObjectBuilder {
-        let mut move_object = self.from_entry.bucket.0.move_object_to(
-            &self.from_entry.object,
-            &self.to_entry.bucket,
-            &self.to_entry.object,
-        );
-        if let Some(force) = self.force {
-            move_object.is_force(force);
-        }
+impl ModifyObjectStatus {
+    fn make_operation(&self) -> qiniu_sdk::objects::ModifyObjectStatusBuilder {
+        let mut builder = self
+            .entry
+            .bucket
+            .inner
+            .modify_object_status(&self.entry.object, self.disabled);
+
         if let Some(callback) = &self.before_request_callback {
             Python::with_gil(|py| {
-                move_object
+                builder
                     .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
             });
         }
-        move_object
+
+        builder
     }
 }
 
-/// 对象元信息删除操作构建器
+/// 修改对象元信息构建器
 ///
-/// 可以通过 `bucket.delete_object()` 方法获取该构建器。
+/// 可以通过 `bucket::modify_object_metadata()` 方法获取该构建器。
 #[pyclass(extends = OperationProvider)]
 #[derive(Clone, Debug)]
-struct DeleteObject {
+struct ModifyObjectMetadata {
     entry: Entry,
+    mime_type: Mime,
+    metadata: IndexMap<String, String>,
+    conditions: IndexMap<String, String>,
     before_request_callback: Option<PyObject>,
 }
 
 #[pymethods]
-impl DeleteObject {
+impl ModifyObjectMetadata {
     fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
         let resp = py.allow_threads(|| {
             self.make_operation()
@@ -679,9 +3090,9 @@ impl DeleteObject {
     }
 
     fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let delete_object = self.to_owned();
+        let modify_object_metadata = self.to_owned();
         pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = delete_object
+            let resp = modify_object_metadata
                 .make_operation()
                 .async_call()
                 .await
@@ -692,34 +3103,45 @@ impl DeleteObject {
     }
 }
 
-impl DeleteObject {
-    fn make_operation(&self) -> qiniu_sdk::objects::DeleteObjectBuilder {
-        let mut builder = self.entry.bucket.0.delete_object(&self.entry.object);
-
+impl ModifyObjectMetadata {
+    fn make_operation(&self) -> qiniu_sdk::objects::ModifyObjectMetadataBuilder {
+        let mut builder = self
+            .entry
+            .bucket
+            .inner
+            .modify_object_metadata(&self.entry.object, self.mime_type.to_owned());
+        for (key, value) in &self.metadata {
+            builder.add_metadata(key, value);
+        }
+        for (key, value) in &self.conditions {
+            builder.add_condition(key, value);
+        }
         if let Some(callback) = &self.before_request_callback {
             Python::with_gil(|py| {
                 builder
                     .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
             });
         }
-
         builder
     }
 }
 
-/// 对象解冻操作构建器
+/// 修改对象生命周期构建器
 ///
-/// 可以通过 `bucket.restore_archived_object()` 方法获取该构建器。
+/// 可以通过 `bucket::modify_object_life_cycle()` 方法获取该构建器。
 #[pyclass(extends = OperationProvider)]
 #[derive(Clone, Debug)]
-struct UnfreezeObject {
+struct ModifyObjectLifeCycle {
     entry: Entry,
-    freeze_after_days: usize,
+    ia_after_days: Option<isize>,
+    archive_after_days: Option<isize>,
+    deep_archive_after_days: Option<isize>,
+    delete_after_days: Option<isize>,
     before_request_callback: Option<PyObject>,
 }
 
 #[pymethods]
-impl UnfreezeObject {
+impl ModifyObjectLifeCycle {
     fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
         let resp = py.allow_threads(|| {
             self.make_operation()
@@ -730,321 +3152,993 @@ impl UnfreezeObject {
         make_json_response(parts, body.as_ref(), py)
     }
 
-    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let unfreeze_object = self.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = unfreeze_object
-                .make_operation()
-                .async_call()
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
-            let (parts, body) = resp.into_parts_and_body();
-            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
+        let modify_object_metadata = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let resp = modify_object_metadata
+                .make_operation()
+                .async_call()
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            let (parts, body) = resp.into_parts_and_body();
+            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+        })
+    }
+}
+
+impl ModifyObjectLifeCycle {
+    fn make_operation(&self) -> qiniu_sdk::objects::ModifyObjectLifeCycleBuilder {
+        let mut builder = self
+            .entry
+            .bucket
+            .inner
+            .modify_object_life_cycle(&self.entry.object);
+        if let Some(ia_after_days) = self.ia_after_days {
+            builder.ia_after_days(ia_after_days.into());
+        }
+        if let Some(archive_after_days) = self.archive_after_days {
+            builder.archive_after_days(archive_after_days.into());
+        }
+        if let Some(deep_archive_after_days) = self.deep_archive_after_days {
+            builder.deep_archive_after_days(deep_archive_after_days.into());
+        }
+        if let Some(delete_after_days) = self.delete_after_days {
+            builder.delete_after_days(delete_after_days.into());
+        }
+        if let Some(callback) = &self.before_request_callback {
+            Python::with_gil(|py| {
+                builder
+                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
+            });
+        }
+        builder
+    }
+}
+
+fn make_json_response(
+    parts: qiniu_sdk::http::ResponseParts,
+    body: &serde_json::Value,
+    py: Python<'_>,
+) -> PyResult<Py<JsonResponse>> {
+    let json = JsonResponse::from(convert_json_value_to_py_object(body)?);
+    Py::new(py, (json, HttpResponseParts::from(parts)))
+}
+
+fn make_before_request_callback(
+    callback: PyObject,
+) -> impl FnMut(&mut qiniu_sdk::http_client::RequestBuilderParts<'_>) -> AnyResult<()>
+       + Send
+       + Sync
+       + 'static {
+    move |parts| {
+        Python::with_gil(|py| callback.call1(py, (RequestBuilderPartsRef::new(parts),)))?;
+        Ok(())
+    }
+}
+
+fn make_after_response_ok_callback(
+    callback: PyObject,
+) -> impl FnMut(&mut qiniu_sdk::http::ResponseParts) -> AnyResult<()> + Send + Sync + 'static {
+    move |parts| {
+        Python::with_gil(|py| callback.call1(py, (HttpResponsePartsMut::from(parts),)))?;
+        Ok(())
+    }
+}
+
+fn make_after_response_error_callback(
+    callback: PyObject,
+) -> impl FnMut(&qiniu_sdk::http_client::ResponseError) -> AnyResult<()> + Send + Sync + 'static {
+    move |error| {
+        let error: &'static qiniu_sdk::http_client::ResponseError = unsafe { transmute(error) };
+        let error = QiniuApiCallError::from_err(MaybeOwned::Borrowed(error));
+        let error = convert_api_call_error(&error)?;
+        Python::with_gil(|py| callback.call1(py, (error,)))?;
+        Ok(())
+    }
+}
+
+fn make_set_bucket_private_form(bucket: &str) -> Vec<(String, Option<String>)> {
+    vec![
+        ("bucket".to_owned(), Some(bucket.to_owned())),
+        ("private".to_owned(), Some("1".to_owned())),
+    ]
+}
+
+/// 批量删除条件
+///
+/// 只有当对象的 Etag 和大小与条件一致时，对象才会被删除
+#[pyclass]
+#[derive(Clone, Debug, Default)]
+#[pyo3(text_signature = "(/, etag = None, size = None)")]
+struct BatchDeleteCondition {
+    etag: Option<String>,
+    size: Option<u64>,
+}
+
+#[pymethods]
+impl BatchDeleteCondition {
+    #[new]
+    #[args(etag = "None", size = "None")]
+    fn new(etag: Option<String>, size: Option<u64>) -> Self {
+        Self { etag, size }
+    }
+
+    /// 获取 Etag 条件
+    #[getter]
+    fn get_etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// 获取对象大小条件
+    #[getter]
+    fn get_size(&self) -> Option<u64> {
+        self.size
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl BatchDeleteCondition {
+    fn is_satisfied_by(&self, stat_result: &serde_json::Value) -> bool {
+        self.etag.as_ref().map_or(true, |etag| {
+            stat_result.get("hash").and_then(|hash| hash.as_str()) == Some(etag.as_str())
+        }) && self.size.map_or(true, |size| {
+            stat_result.get("fsize").and_then(|fsize| fsize.as_u64()) == Some(size)
         })
     }
 }
 
-impl UnfreezeObject {
-    fn make_operation(&self) -> qiniu_sdk::objects::UnfreezeObjectBuilder {
-        let mut builder = self
-            .entry
-            .bucket
-            .0
-            .restore_archived_object(&self.entry.object, self.freeze_after_days);
+fn conditional_keys<'a>(
+    keys: &'a [String],
+    conditions: &[Option<BatchDeleteCondition>],
+) -> Vec<&'a str> {
+    keys.iter()
+        .zip(conditions.iter())
+        .filter(|(_, condition)| condition.is_some())
+        .map(|(key, _)| key.as_str())
+        .collect()
+}
 
-        if let Some(callback) = &self.before_request_callback {
-            Python::with_gil(|py| {
-                builder
-                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
-            });
+fn pending_keys<'a>(
+    keys: &'a [String],
+    results: &[Option<qiniu_sdk::http_client::ApiResult<()>>],
+) -> Vec<&'a str> {
+    keys.iter()
+        .zip(results.iter())
+        .filter(|(_, result)| result.is_none())
+        .map(|(key, _)| key.as_str())
+        .collect()
+}
+
+/// 根据对象元信息检查删除条件，不满足条件的对象将直接得到删除失败的结果
+fn apply_batch_delete_conditions(
+    keys: &[String],
+    conditions: &[Option<BatchDeleteCondition>],
+    stat_results: Vec<qiniu_sdk::http_client::ApiResult<serde_json::Value>>,
+) -> Vec<Option<qiniu_sdk::http_client::ApiResult<()>>> {
+    let mut results = (0..keys.len()).map(|_| None).collect::<Vec<_>>();
+    let conditional = conditions
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, condition)| condition.as_ref().map(|condition| (idx, condition)));
+    for ((idx, condition), stat_result) in conditional.zip(stat_results) {
+        match stat_result {
+            Ok(stat_result) if condition.is_satisfied_by(&stat_result) => {}
+            Ok(_) => results[idx] = Some(Err(make_precondition_failed_error(&keys[idx]))),
+            Err(err) => results[idx] = Some(Err(err)),
         }
+    }
+    results
+}
 
-        builder
+fn fill_batch_delete_results(
+    results: &mut [Option<qiniu_sdk::http_client::ApiResult<()>>],
+    delete_results: Vec<qiniu_sdk::http_client::ApiResult<serde_json::Value>>,
+) {
+    let pending = results.iter_mut().filter(|result| result.is_none());
+    for (result, delete_result) in pending.zip(delete_results) {
+        *result = Some(delete_result.map(|_| ()));
     }
 }
 
-/// 对象类型设置操作构建器
-///
-/// 可以通过 `bucket.set_object_type()` 方法获取该构建器。
-#[pyclass(extends = OperationProvider)]
-#[derive(Clone, Debug)]
-struct SetObjectType {
-    entry: Entry,
-    file_type: qiniu_sdk::upload_token::FileType,
-    before_request_callback: Option<PyObject>,
+fn check_batch_delete_conditions(
+    keys: &[String],
+    conditions: &[Option<BatchDeleteCondition>],
+) -> PyResult<()> {
+    if keys.len() == conditions.len() {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "The length of conditions ({}) must be equal to the length of keys ({})",
+            conditions.len(),
+            keys.len()
+        )))
+    }
 }
 
-#[pymethods]
-impl SetObjectType {
-    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
-        let resp = py.allow_threads(|| {
-            self.make_operation()
-                .call()
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-        })?;
-        let (parts, body) = resp.into_parts_and_body();
-        make_json_response(parts, body.as_ref(), py)
+const LISTING_COLUMNS: [&str; 5] = ["key", "hash", "size", "mime_type", "put_time"];
+
+fn make_listing_columns(columns: Option<Vec<String>>) -> PyResult<Vec<String>> {
+    if let Some(columns) = columns {
+        if let Some(column) = columns
+            .iter()
+            .find(|column| !LISTING_COLUMNS.contains(&column.as_str()))
+        {
+            return Err(PyValueError::new_err(format!("Unknown column: {}", column)));
+        }
+        Ok(columns)
+    } else {
+        Ok(LISTING_COLUMNS
+            .iter()
+            .map(|&column| column.to_owned())
+            .collect())
     }
+}
 
-    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let set_object_type = self.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = set_object_type
-                .make_operation()
-                .async_call()
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
-            let (parts, body) = resp.into_parts_and_body();
-            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+fn convert_listing_items_to_dataframe(
+    items: &[serde_json::Value],
+    columns: &[String],
+    py: Python<'_>,
+) -> PyResult<PyObject> {
+    let rows = items
+        .iter()
+        .map(|item| {
+            let stat = StatResult::from_json(
+                item.get("key")
+                    .and_then(|key| key.as_str())
+                    .unwrap_or_default(),
+                item,
+            );
+            let row = PyDict::new(py);
+            for column in columns {
+                match column.as_str() {
+                    "key" => row.set_item(column, &stat.key)?,
+                    "hash" => row.set_item(column, &stat.hash)?,
+                    "size" => row.set_item(column, stat.fsize)?,
+                    "mime_type" => row.set_item(column, &stat.mime_type)?,
+                    "put_time" => row.set_item(column, stat.put_time)?,
+                    _ => unreachable!(),
+                }
+            }
+            Ok(row.to_object(py))
         })
+        .collect::<PyResult<Vec<_>>>()?;
+    let pandas_spec = py
+        .import("importlib.util")?
+        .call_method1("find_spec", ("pandas",))?;
+    if pandas_spec.is_none() {
+        return Ok(rows.into_py(py));
     }
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("columns", columns)?;
+    Ok(py
+        .import("pandas")?
+        .call_method("DataFrame", (rows,), Some(kwargs))?
+        .into_py(py))
 }
 
-impl SetObjectType {
-    fn make_operation(&self) -> qiniu_sdk::objects::SetObjectTypeBuilder {
-        let mut builder = self
-            .entry
-            .bucket
-            .0
-            .set_object_type(&self.entry.object, self.file_type);
+fn page_keys(page: &[serde_json::Value]) -> Vec<&str> {
+    page.iter()
+        .filter_map(|entry| entry.get("key").and_then(|key| key.as_str()))
+        .collect()
+}
 
-        if let Some(callback) = &self.before_request_callback {
-            Python::with_gil(|py| {
-                builder
-                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
-            });
+/// 累加强制删除存储空间时已经删除的对象数量，遇到第一个删除失败的对象时返回错误
+fn count_force_deleted(
+    deleted: usize,
+    results: Vec<qiniu_sdk::http_client::ApiResult<serde_json::Value>>,
+) -> PyResult<usize> {
+    let mut deleted = deleted;
+    for result in results {
+        match result {
+            Ok(_) => deleted += 1,
+            Err(err) => {
+                return Err(QiniuApiCallError::from_err(MaybeOwned::Owned(
+                    qiniu_sdk::http_client::ResponseError::new(
+                        err.kind(),
+                        format!("{} objects were deleted before failure: {}", deleted, err),
+                    ),
+                )))
+            }
         }
-
-        builder
     }
+    Ok(deleted)
 }
 
-/// 修改对象状态构建器
-///
-/// 可以通过 `bucket::modify_object_status()` 方法获取该构建器。
-#[pyclass(extends = OperationProvider)]
-#[derive(Clone, Debug)]
-struct ModifyObjectStatus {
-    entry: Entry,
-    disabled: bool,
-    before_request_callback: Option<PyObject>,
+fn convert_credential_get_error(err: std::io::Error) -> PyErr {
+    QiniuApiCallError::from_err(MaybeOwned::Owned(
+        qiniu_sdk::http_client::ResponseError::new(
+            qiniu_sdk::http::ResponseErrorKind::LocalIoError.into(),
+            err,
+        ),
+    ))
 }
 
-#[pymethods]
-impl ModifyObjectStatus {
-    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
-        let resp = py.allow_threads(|| {
-            self.make_operation()
-                .call()
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-        })?;
-        let (parts, body) = resp.into_parts_and_body();
-        make_json_response(parts, body.as_ref(), py)
+fn make_precondition_failed_error(key: &str) -> qiniu_sdk::http_client::ResponseError {
+    qiniu_sdk::http_client::ResponseError::new(
+        qiniu_sdk::http_client::ResponseErrorKind::StatusCodeError(
+            qiniu_sdk::http::StatusCode::PRECONDITION_FAILED,
+        ),
+        format!("Condition of {} is not satisfied", key),
+    )
+}
+
+fn convert_stat_batch_results(
+    keys: &[String],
+    results: Vec<qiniu_sdk::http_client::ApiResult<serde_json::Value>>,
+    py: Python<'_>,
+) -> PyResult<Vec<PyObject>> {
+    keys.iter()
+        .zip(results)
+        .map(|(key, result)| match result {
+            Ok(value) => Ok(StatResult::from_json(key, &value).into_py(py)),
+            Err(err) => {
+                let err = QiniuApiCallError::from_err(MaybeOwned::Owned(err));
+                if is_unauthorized_error(&err) {
+                    Err(err)
+                } else {
+                    Ok(err.value(py).into_py(py))
+                }
+            }
+        })
+        .collect()
+}
+
+fn convert_batch_delete_results(
+    results: Vec<Option<qiniu_sdk::http_client::ApiResult<()>>>,
+    py: Python<'_>,
+) -> Vec<Option<PyObject>> {
+    results
+        .into_iter()
+        .map(|result| {
+            result.and_then(|result| result.err()).map(|err| {
+                QiniuApiCallError::from_err(MaybeOwned::Owned(err))
+                    .value(py)
+                    .into_py(py)
+            })
+        })
+        .collect()
+}
+
+fn strip_metadata_prefix(meta: HashMap<String, String>) -> PyResult<HashMap<String, String>> {
+    const METADATA_PREFIX: &str = "x-qn-meta-";
+
+    meta.into_iter()
+        .map(|(key, value)| {
+            if let Some(stripped) = key.strip_prefix(METADATA_PREFIX) {
+                Ok((stripped.to_owned(), value))
+            } else {
+                Err(QiniuUnsupportedTypeError::new_err(format!(
+                    "Metadata key must start with `{}`: {:?}",
+                    METADATA_PREFIX, key
+                )))
+            }
+        })
+        .collect()
+}
+
+fn validate_tags(tags: &HashMap<String, String>) -> PyResult<()> {
+    const MAX_TAG_KEY_LEN: usize = 50;
+    const MAX_TAG_VALUE_LEN: usize = 256;
+
+    for (key, value) in tags {
+        if key.is_empty() || key.len() > MAX_TAG_KEY_LEN {
+            return Err(QiniuInvalidTagError::new_err(format!(
+                "Tag key length must be between 1 and {}: {:?}",
+                MAX_TAG_KEY_LEN, key
+            )));
+        }
+        if !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(QiniuInvalidTagError::new_err(format!(
+                "Tag key must only contain letters, digits, `-` and `_`: {:?}",
+                key
+            )));
+        }
+        if value.chars().count() > MAX_TAG_VALUE_LEN {
+            return Err(QiniuInvalidTagError::new_err(format!(
+                "Value of tag {:?} must not be longer than {} characters",
+                key, MAX_TAG_VALUE_LEN
+            )));
+        }
     }
+    Ok(())
+}
 
-    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let modify_object_status = self.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = modify_object_status
-                .make_operation()
-                .async_call()
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
-            let (parts, body) = resp.into_parts_and_body();
-            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
+fn tags_from_stat_result(stat_result: &serde_json::Value) -> HashMap<String, String> {
+    stat_result
+        .get("x-qn-meta")
+        .and_then(|metadata| metadata.as_object())
+        .map(|metadata| {
+            metadata
+                .iter()
+                .filter_map(|(key, value)| {
+                    value
+                        .as_str()
+                        .filter(|value| !value.is_empty())
+                        .map(|value| (key.to_owned(), value.to_owned()))
+                })
+                .collect()
         })
+        .unwrap_or_default()
+}
+
+fn empty_tags_from_stat_result(stat_result: &serde_json::Value) -> HashMap<String, String> {
+    tags_from_stat_result(stat_result)
+        .into_keys()
+        .map(|key| (key, String::new()))
+        .collect()
+}
+
+fn encode_url_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for &b in path.as_bytes() {
+        if b.is_ascii_alphanumeric() || b"-_.~/".contains(&b) {
+            encoded.push(b as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", b));
+        }
     }
+    encoded
 }
 
-impl ModifyObjectStatus {
-    fn make_operation(&self) -> qiniu_sdk::objects::ModifyObjectStatusBuilder {
-        let mut builder = self
-            .entry
-            .bucket
-            .0
-            .modify_object_status(&self.entry.object, self.disabled);
+fn first_domain(domains: Vec<String>) -> PyResult<String> {
+    domains.into_iter().next().ok_or_else(|| {
+        QiniuApiCallError::from_err(MaybeOwned::Owned(
+            qiniu_sdk::http_client::ResponseError::new(
+                qiniu_sdk::http::ResponseErrorKind::UnknownError.into(),
+                "No domain is bound to the bucket",
+            ),
+        ))
+    })
+}
 
-        if let Some(callback) = &self.before_request_callback {
-            Python::with_gil(|py| {
-                builder
-                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
-            });
+/// 分页列举时每页默认的最大对象数量
+const DEFAULT_LIST_LIMIT: usize = 1000;
+
+fn make_fetch_headers(
+    mime: Option<&str>,
+    meta: Option<HashMap<String, String>>,
+) -> PyResult<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    if let Some(mime) = mime {
+        headers.insert(
+            CONTENT_TYPE,
+            parse_header_value(parse_mime(mime)?.as_ref())?,
+        );
+    }
+    for (name, value) in meta.unwrap_or_default() {
+        if !name.to_ascii_lowercase().starts_with("x-qn-meta-") {
+            return Err(QiniuUnsupportedTypeError::new_err(format!(
+                "Metadata key must start with `x-qn-meta-`: {}",
+                name
+            )));
         }
+        headers.insert(parse_header_name(&name)?, parse_header_value(&value)?);
+    }
+    Ok(headers)
+}
 
-        builder
+fn make_bucket_query_pairs(bucket_name: &str) -> Vec<qiniu_sdk::http_client::QueryPair<'static>> {
+    vec![("bucket".into(), bucket_name.to_owned().into())]
+}
+
+fn make_region_query_pairs(region_id: &str) -> Vec<qiniu_sdk::http_client::QueryPair<'static>> {
+    vec![("region".into(), region_id.to_owned().into())]
+}
+
+/// 在客户端检查存储空间名称和存储类型，生成创建存储空间的请求路径和查询参数
+fn make_create_bucket_request(
+    name: &str,
+    region_id: &str,
+    storage_type: u8,
+) -> PyResult<(String, Vec<qiniu_sdk::http_client::QueryPair<'static>>)> {
+    if !(3..=63).contains(&name.len())
+        || !name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    {
+        return Err(QiniuInvalidDomainWithPortError::new_err(format!(
+            "invalid bucket name {:?}, only 3 to 63 lowercase letters, digits and hyphens are allowed",
+            name
+        )));
+    }
+    if storage_type > StorageClass::ArchiveInfrequentAccess as u8 {
+        return Err(PyValueError::new_err(format!(
+            "invalid storage type {}",
+            storage_type
+        )));
     }
+    let mut query_pairs = Vec::new();
+    if storage_type != StorageClass::Standard as u8 {
+        query_pairs.push(("storage_type".into(), storage_type.to_string().into()));
+    }
+    Ok((
+        format!("/mkbucketv3/{}/region/{}", name, region_id),
+        query_pairs,
+    ))
 }
 
-/// 修改对象元信息构建器
-///
-/// 可以通过 `bucket::modify_object_metadata()` 方法获取该构建器。
-#[pyclass(extends = OperationProvider)]
+/// 调用 UC 服务的存储空间管理接口
 #[derive(Clone, Debug)]
-struct ModifyObjectMetadata {
-    entry: Entry,
-    mime_type: Mime,
-    metadata: IndexMap<String, String>,
-    conditions: IndexMap<String, String>,
-    before_request_callback: Option<PyObject>,
+struct UcClient {
+    http_client: qiniu_sdk::http_client::HttpClient,
+    credential: CredentialProvider,
+    uc_endpoints: qiniu_sdk::http_client::Endpoints,
+    use_https: Option<bool>,
 }
 
-#[pymethods]
-impl ModifyObjectMetadata {
-    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
-        let resp = py.allow_threads(|| {
-            self.make_operation()
-                .call()
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-        })?;
-        let (parts, body) = resp.into_parts_and_body();
-        make_json_response(parts, body.as_ref(), py)
+impl UcClient {
+    fn call(
+        &self,
+        method: Method,
+        path: String,
+        query_pairs: Vec<qiniu_sdk::http_client::QueryPair<'static>>,
+        form: Option<Vec<(String, Option<String>)>>,
+    ) -> PyResult<serde_json::Value> {
+        self.call_service(
+            method,
+            &[qiniu_sdk::http_client::ServiceName::Uc],
+            self.uc_endpoints.to_owned(),
+            path,
+            query_pairs,
+            form,
+        )
     }
 
-    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let modify_object_metadata = self.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = modify_object_metadata
-                .make_operation()
-                .async_call()
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
-            let (parts, body) = resp.into_parts_and_body();
-            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
-        })
+    fn call_service(
+        &self,
+        method: Method,
+        services: &[qiniu_sdk::http_client::ServiceName],
+        endpoints: impl qiniu_sdk::http_client::EndpointsProvider,
+        path: String,
+        query_pairs: Vec<qiniu_sdk::http_client::QueryPair<'static>>,
+        form: Option<Vec<(String, Option<String>)>>,
+    ) -> PyResult<serde_json::Value> {
+        self.call_service_with_headers(
+            method,
+            services,
+            endpoints,
+            path,
+            query_pairs,
+            Default::default(),
+            form,
+        )
     }
-}
 
-impl ModifyObjectMetadata {
-    fn make_operation(&self) -> qiniu_sdk::objects::ModifyObjectMetadataBuilder {
+    #[allow(clippy::too_many_arguments)]
+    fn call_service_with_headers(
+        &self,
+        method: Method,
+        services: &[qiniu_sdk::http_client::ServiceName],
+        endpoints: impl qiniu_sdk::http_client::EndpointsProvider,
+        path: String,
+        query_pairs: Vec<qiniu_sdk::http_client::QueryPair<'static>>,
+        headers: HeaderMap,
+        form: Option<Vec<(String, Option<String>)>>,
+    ) -> PyResult<serde_json::Value> {
+        let mut builder = self.http_client.new_request(method, services, endpoints);
+        builder
+            .headers(Cow::Owned(headers))
+            .path(path)
+            .query_pairs(query_pairs)
+            .accept_json()
+            .authorization(qiniu_sdk::http_client::Authorization::v2(
+                self.credential.to_owned(),
+            ));
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
+        }
+        if let Some(form) = form {
+            builder.post_form(form);
+        }
+        let mut body = Vec::new();
+        builder
+            .call()
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .into_body()
+            .read_to_end(&mut body)
+            .map_err(QiniuIoError::from_err)?;
+        parse_json_body(&body)
+    }
+
+    async fn async_call(
+        &self,
+        method: Method,
+        path: String,
+        query_pairs: Vec<qiniu_sdk::http_client::QueryPair<'static>>,
+        form: Option<Vec<(String, Option<String>)>>,
+    ) -> PyResult<serde_json::Value> {
+        self.async_call_service(
+            method,
+            &[qiniu_sdk::http_client::ServiceName::Uc],
+            self.uc_endpoints.to_owned(),
+            path,
+            query_pairs,
+            form,
+        )
+        .await
+    }
+
+    async fn async_call_service(
+        &self,
+        method: Method,
+        services: &[qiniu_sdk::http_client::ServiceName],
+        endpoints: impl qiniu_sdk::http_client::EndpointsProvider,
+        path: String,
+        query_pairs: Vec<qiniu_sdk::http_client::QueryPair<'static>>,
+        form: Option<Vec<(String, Option<String>)>>,
+    ) -> PyResult<serde_json::Value> {
+        self.async_call_service_with_headers(
+            method,
+            services,
+            endpoints,
+            path,
+            query_pairs,
+            Default::default(),
+            form,
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn async_call_service_with_headers(
+        &self,
+        method: Method,
+        services: &[qiniu_sdk::http_client::ServiceName],
+        endpoints: impl qiniu_sdk::http_client::EndpointsProvider,
+        path: String,
+        query_pairs: Vec<qiniu_sdk::http_client::QueryPair<'static>>,
+        headers: HeaderMap,
+        form: Option<Vec<(String, Option<String>)>>,
+    ) -> PyResult<serde_json::Value> {
         let mut builder = self
-            .entry
-            .bucket
-            .0
-            .modify_object_metadata(&self.entry.object, self.mime_type.to_owned());
-        for (key, value) in &self.metadata {
-            builder.add_metadata(key, value);
+            .http_client
+            .new_async_request(method, services, endpoints);
+        builder
+            .headers(Cow::Owned(headers))
+            .path(path)
+            .query_pairs(query_pairs)
+            .accept_json()
+            .authorization(qiniu_sdk::http_client::Authorization::v2(
+                self.credential.to_owned(),
+            ));
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
         }
-        for (key, value) in &self.conditions {
-            builder.add_condition(key, value);
+        if let Some(form) = form {
+            builder.post_form(form);
         }
-        if let Some(callback) = &self.before_request_callback {
-            Python::with_gil(|py| {
-                builder
-                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
-            });
+        let mut body = Vec::new();
+        builder
+            .call()
+            .await
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .into_body()
+            .read_to_end(&mut body)
+            .await
+            .map_err(QiniuIoError::from_err)?;
+        parse_json_body(&body)
+    }
+
+    fn call_with_json(
+        &self,
+        method: Method,
+        path: String,
+        json: &serde_json::Value,
+    ) -> PyResult<serde_json::Value> {
+        let mut builder = self.http_client.new_request(
+            method,
+            &[qiniu_sdk::http_client::ServiceName::Uc],
+            self.uc_endpoints.to_owned(),
+        );
+        builder
+            .path(path)
+            .accept_json()
+            .authorization(qiniu_sdk::http_client::Authorization::v2(
+                self.credential.to_owned(),
+            ))
+            .json(json)
+            .map_err(QiniuJsonError::from_err)?;
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
+        }
+        let mut body = Vec::new();
+        builder
+            .call()
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .into_body()
+            .read_to_end(&mut body)
+            .map_err(QiniuIoError::from_err)?;
+        parse_json_body(&body)
+    }
+
+    async fn async_call_with_json(
+        &self,
+        method: Method,
+        path: String,
+        json: &serde_json::Value,
+    ) -> PyResult<serde_json::Value> {
+        let mut builder = self.http_client.new_async_request(
+            method,
+            &[qiniu_sdk::http_client::ServiceName::Uc],
+            self.uc_endpoints.to_owned(),
+        );
+        builder
+            .path(path)
+            .accept_json()
+            .authorization(qiniu_sdk::http_client::Authorization::v2(
+                self.credential.to_owned(),
+            ))
+            .json(json)
+            .map_err(QiniuJsonError::from_err)?;
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
         }
+        let mut body = Vec::new();
         builder
+            .call()
+            .await
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .into_body()
+            .read_to_end(&mut body)
+            .await
+            .map_err(QiniuIoError::from_err)?;
+        parse_json_body(&body)
+    }
+
+    fn get_all_region_ids(&self) -> PyResult<Vec<String>> {
+        qiniu_sdk::http_client::RegionsProvider::get_all(
+            &self.all_regions_provider(),
+            Default::default(),
+        )
+        .map(convert_region_ids)
+        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+    }
+
+    async fn async_get_all_region_ids(&self) -> PyResult<Vec<String>> {
+        qiniu_sdk::http_client::RegionsProvider::async_get_all(
+            &self.all_regions_provider(),
+            Default::default(),
+        )
+        .await
+        .map(convert_region_ids)
+        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+    }
+
+    fn all_regions_provider(&self) -> qiniu_sdk::http_client::AllRegionsProvider {
+        let mut builder =
+            qiniu_sdk::http_client::AllRegionsProvider::builder(self.credential.to_owned())
+                .uc_endpoints(self.uc_endpoints.to_owned());
+        if let Some(use_https) = self.use_https {
+            builder = builder.use_https(use_https);
+        }
+        builder.in_memory()
     }
 }
 
-/// 修改对象生命周期构建器
+fn convert_region_ids(regions: qiniu_sdk::http_client::GotRegions) -> Vec<String> {
+    regions
+        .into_regions()
+        .into_iter()
+        .map(|region| region.region_id().to_owned())
+        .collect()
+}
+
+/// 存储空间的跨域规则
 ///
-/// 可以通过 `bucket::modify_object_life_cycle()` 方法获取该构建器。
-#[pyclass(extends = OperationProvider)]
+/// `allowed_origins` 为允许的来源列表，`*` 表示允许所有来源，如果包含非法的 URL，将抛出 `QiniuInvalidURLError` 异常。
+/// `allowed_methods` 为允许的 HTTP 方法列表，如果包含非法的方法，将抛出 `QiniuInvalidMethodError` 异常
+#[pyclass]
 #[derive(Clone, Debug)]
-struct ModifyObjectLifeCycle {
-    entry: Entry,
-    ia_after_days: Option<isize>,
-    archive_after_days: Option<isize>,
-    deep_archive_after_days: Option<isize>,
-    delete_after_days: Option<isize>,
-    before_request_callback: Option<PyObject>,
+#[pyo3(
+    text_signature = "(allowed_origins, allowed_methods, /, allowed_headers = None, expose_headers = None, max_age_secs = 3600)"
+)]
+struct CorsRule {
+    allowed_origins: Vec<String>,
+    allowed_methods: Vec<String>,
+    allowed_headers: Vec<String>,
+    expose_headers: Vec<String>,
+    max_age_secs: u64,
 }
 
 #[pymethods]
-impl ModifyObjectLifeCycle {
-    fn call(&self, py: Python<'_>) -> PyResult<Py<JsonResponse>> {
-        let resp = py.allow_threads(|| {
-            self.make_operation()
-                .call()
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-        })?;
-        let (parts, body) = resp.into_parts_and_body();
-        make_json_response(parts, body.as_ref(), py)
+impl CorsRule {
+    #[new]
+    #[args(
+        allowed_headers = "None",
+        expose_headers = "None",
+        max_age_secs = "3600"
+    )]
+    fn new(
+        allowed_origins: Vec<String>,
+        allowed_methods: Vec<String>,
+        allowed_headers: Option<Vec<String>>,
+        expose_headers: Option<Vec<String>>,
+        max_age_secs: u64,
+    ) -> PyResult<Self> {
+        for origin in allowed_origins
+            .iter()
+            .filter(|origin| origin.as_str() != "*")
+        {
+            parse_uri(origin)?;
+        }
+        let allowed_methods = allowed_methods
+            .iter()
+            .map(|method| parse_cors_method(method))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Self {
+            allowed_origins,
+            allowed_methods,
+            allowed_headers: allowed_headers.unwrap_or_default(),
+            expose_headers: expose_headers.unwrap_or_default(),
+            max_age_secs,
+        })
     }
 
-    fn async_call<'p>(&self, py: Python<'p>) -> PyResult<&'p PyAny> {
-        let modify_object_metadata = self.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            let resp = modify_object_metadata
-                .make_operation()
-                .async_call()
-                .await
-                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
-            let (parts, body) = resp.into_parts_and_body();
-            Python::with_gil(|py| make_json_response(parts, body.as_ref(), py))
-        })
+    /// 获取允许的来源列表
+    #[getter]
+    fn get_allowed_origins(&self) -> Vec<String> {
+        self.allowed_origins.to_owned()
+    }
+
+    /// 获取允许的 HTTP 方法列表
+    #[getter]
+    fn get_allowed_methods(&self) -> Vec<String> {
+        self.allowed_methods.to_owned()
+    }
+
+    /// 获取允许的请求头列表
+    #[getter]
+    fn get_allowed_headers(&self) -> Vec<String> {
+        self.allowed_headers.to_owned()
+    }
+
+    /// 获取暴露给客户端的响应头列表
+    #[getter]
+    fn get_expose_headers(&self) -> Vec<String> {
+        self.expose_headers.to_owned()
+    }
+
+    /// 获取预检请求的缓存时长，单位为秒
+    #[getter]
+    fn get_max_age_secs(&self) -> u64 {
+        self.max_age_secs
+    }
+
+    /// 转换为字典，键与属性名相同
+    #[pyo3(text_signature = "($self)")]
+    fn to_dict<'p>(&self, py: Python<'p>) -> PyResult<&'p PyDict> {
+        let dict = PyDict::new(py);
+        dict.set_item("allowed_origins", &self.allowed_origins)?;
+        dict.set_item("allowed_methods", &self.allowed_methods)?;
+        dict.set_item("allowed_headers", &self.allowed_headers)?;
+        dict.set_item("expose_headers", &self.expose_headers)?;
+        dict.set_item("max_age_secs", self.max_age_secs)?;
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
     }
 }
 
-impl ModifyObjectLifeCycle {
-    fn make_operation(&self) -> qiniu_sdk::objects::ModifyObjectLifeCycleBuilder {
-        let mut builder = self
-            .entry
-            .bucket
-            .0
-            .modify_object_life_cycle(&self.entry.object);
-        if let Some(ia_after_days) = self.ia_after_days {
-            builder.ia_after_days(ia_after_days.into());
-        }
-        if let Some(archive_after_days) = self.archive_after_days {
-            builder.archive_after_days(archive_after_days.into());
-        }
-        if let Some(deep_archive_after_days) = self.deep_archive_after_days {
-            builder.deep_archive_after_days(deep_archive_after_days.into());
-        }
-        if let Some(delete_after_days) = self.delete_after_days {
-            builder.delete_after_days(delete_after_days.into());
-        }
-        if let Some(callback) = &self.before_request_callback {
-            Python::with_gil(|py| {
-                builder
-                    .before_request_callback(make_before_request_callback(callback.clone_ref(py)));
-            });
+impl CorsRule {
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "allowed_origin": self.allowed_origins,
+            "allowed_method": self.allowed_methods,
+            "allowed_header": self.allowed_headers,
+            "exposed_header": self.expose_headers,
+            "max_age": self.max_age_secs,
+        })
+    }
+
+    fn from_json(value: &serde_json::Value) -> Self {
+        let get_strings = |name: &str| {
+            value
+                .get(name)
+                .and_then(|v| v.as_array())
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(|v| v.as_str())
+                        .map(|v| v.to_owned())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Self {
+            allowed_origins: get_strings("allowed_origin"),
+            allowed_methods: get_strings("allowed_method"),
+            allowed_headers: get_strings("allowed_header"),
+            expose_headers: get_strings("exposed_header"),
+            max_age_secs: value.get("max_age").and_then(|v| v.as_u64()).unwrap_or(0),
         }
-        builder
     }
 }
 
-fn make_json_response(
-    parts: qiniu_sdk::http::ResponseParts,
-    body: &serde_json::Value,
-    py: Python<'_>,
-) -> PyResult<Py<JsonResponse>> {
-    let json = JsonResponse::from(convert_json_value_to_py_object(body)?);
-    Py::new(py, (json, HttpResponseParts::from(parts)))
+#[derive(FromPyObject)]
+enum CorsRulesOrOrigins {
+    Rule(CorsRule),
+    Rules(Vec<CorsRule>),
+    Origins(Vec<String>),
 }
 
-fn make_before_request_callback(
-    callback: PyObject,
-) -> impl FnMut(&mut qiniu_sdk::http_client::RequestBuilderParts<'_>) -> AnyResult<()>
-       + Send
-       + Sync
-       + 'static {
-    move |parts| {
-        Python::with_gil(|py| callback.call1(py, (RequestBuilderPartsRef::new(parts),)))?;
-        Ok(())
+fn parse_cors_method(method: &str) -> PyResult<String> {
+    const CORS_METHODS: [Method; 7] = [
+        Method::GET,
+        Method::HEAD,
+        Method::POST,
+        Method::PUT,
+        Method::DELETE,
+        Method::OPTIONS,
+        Method::PATCH,
+    ];
+    let method = parse_method(method)?;
+    if CORS_METHODS.contains(&method) {
+        Ok(method.to_string())
+    } else {
+        Err(QiniuInvalidMethodError::new_err(format!(
+            "Unsupported CORS method: {}",
+            method
+        )))
     }
 }
 
-fn make_after_response_ok_callback(
-    callback: PyObject,
-) -> impl FnMut(&mut qiniu_sdk::http::ResponseParts) -> AnyResult<()> + Send + Sync + 'static {
-    move |parts| {
-        Python::with_gil(|py| callback.call1(py, (HttpResponsePartsMut::from(parts),)))?;
-        Ok(())
-    }
+/// 生成存储空间跨域规则，在调用接口前检查来源和方法是否合法
+fn make_cors_rules(
+    rules: CorsRulesOrOrigins,
+    methods: Option<Vec<String>>,
+    allowed_headers: Option<Vec<String>>,
+    expose_headers: Option<Vec<String>>,
+    max_age_secs: u64,
+) -> PyResult<serde_json::Value> {
+    let rules = match rules {
+        CorsRulesOrOrigins::Origins(origins) => vec![CorsRule::new(
+            origins,
+            methods.ok_or_else(|| PyValueError::new_err("methods must be specified"))?,
+            allowed_headers,
+            expose_headers,
+            max_age_secs,
+        )?],
+        _ if methods.is_some() || allowed_headers.is_some() || expose_headers.is_some() => {
+            return Err(PyValueError::new_err(
+                "CorsRule can't be specified with other arguments",
+            ))
+        }
+        CorsRulesOrOrigins::Rule(rule) => vec![rule],
+        CorsRulesOrOrigins::Rules(rules) => rules,
+    };
+    Ok(serde_json::Value::Array(
+        rules.iter().map(CorsRule::to_json).collect(),
+    ))
 }
 
-fn make_after_response_error_callback(
-    callback: PyObject,
-) -> impl FnMut(&qiniu_sdk::http_client::ResponseError) -> AnyResult<()> + Send + Sync + 'static {
-    move |error| {
-        let error: &'static qiniu_sdk::http_client::ResponseError = unsafe { transmute(error) };
-        let error = QiniuApiCallError::from_err(MaybeOwned::Borrowed(error));
-        let error = convert_api_call_error(&error)?;
-        Python::with_gil(|py| callback.call1(py, (error,)))?;
-        Ok(())
+fn convert_cors_rules(value: serde_json::Value) -> Vec<CorsRule> {
+    value
+        .as_array()
+        .map(|rules| rules.iter().map(CorsRule::from_json).collect())
+        .unwrap_or_default()
+}
+
+fn parse_json_body(body: &[u8]) -> PyResult<serde_json::Value> {
+    if body.is_empty() {
+        Ok(serde_json::Value::Null)
+    } else {
+        serde_json::from_slice(body).map_err(QiniuJsonError::from_err)
     }
 }
 
@@ -1068,12 +4162,8 @@ impl ObjectsLister {
     }
 
     fn __aiter__(&self, py: Python<'_>) -> PyResult<AsyncObjectsIterator> {
-        let stream = self.make_list_builder(py).stream().peekable();
         Ok(AsyncObjectsIterator {
-            inner: Arc::new(AsyncObjectsIteratorInner {
-                stream: AsyncMutex::new(stream),
-                ended: AtomicBool::new(false),
-            }),
+            inner: Arc::new(self.make_async_iterator_inner(py)),
             _params: self.params.to_owned(),
         })
     }
@@ -1088,9 +4178,16 @@ impl ObjectsLister {
 }
 
 impl ObjectsLister {
+    fn make_async_iterator_inner(&self, py: Python<'_>) -> AsyncObjectsIteratorInner {
+        AsyncObjectsIteratorInner {
+            stream: AsyncMutex::new(self.make_list_builder(py).stream().peekable()),
+            ended: AtomicBool::new(false),
+        }
+    }
+
     fn make_list_builder(&self, py: Python<'_>) -> qiniu_sdk::objects::ListBuilder<'static> {
         let mut list_builder: qiniu_sdk::objects::ListBuilder<'static> = {
-            let builder = self.params.bucket.0.list();
+            let builder = self.params.bucket.inner.list();
             unsafe { transmute(builder) }
         };
         if let Some(limit) = self.params.limit {
@@ -1190,30 +4287,139 @@ impl AsyncObjectsIterator {
             return None;
         }
         let inner = self.inner.to_owned();
-        pyo3_asyncio::async_std::future_into_py(py, async move {
-            let mut stream = inner.stream.lock().await;
-            let entry = stream
-                .try_next()
-                .await
-                .map(|entry| {
-                    entry.map(|entry| {
-                        convert_json_value_to_py_object(&serde_json::Value::from(entry))
-                    })
-                })
-                .transpose()
-                .map(|result| {
-                    result
-                        .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
-                        .and_then(|res| res)
-                })
-                .transpose()?;
-            if Pin::new(&mut *stream).peek_mut().await.is_none() {
-                inner.ended.store(true, Ordering::SeqCst);
+        pyo3_asyncio::async_std::future_into_py(py, async move { inner.next_entry().await })
+            .ok()
+            .map(|any| any.into_py(py))
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl AsyncObjectsIteratorInner {
+    async fn next_entry(&self) -> PyResult<Option<PyObject>> {
+        let mut stream = self.stream.lock().await;
+        let entry = stream
+            .try_next()
+            .await
+            .map(|entry| {
+                entry.map(|entry| convert_json_value_to_py_object(&serde_json::Value::from(entry)))
+            })
+            .transpose()
+            .map(|result| {
+                result
+                    .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                    .and_then(|res| res)
+            })
+            .transpose()?;
+        if Pin::new(&mut *stream).peek_mut().await.is_none() {
+            self.ended.store(true, Ordering::SeqCst);
+        }
+        Ok(entry)
+    }
+}
+
+/// 存储空间异步列举器
+///
+/// 可以通过 `Bucket::async_list` 方法获取，列举游标将在多次 `__anext__` 调用之间保持。
+/// 同一个列举器不能被并发迭代，否则将抛出 `QiniuInvalidConcurrency` 异常
+#[pyclass]
+#[derive(Debug)]
+struct BucketAsyncListing {
+    _params: Pin<Arc<ObjectsIteratorParams>>,
+    inner: Arc<AsyncObjectsIteratorInner>,
+    iterating: Arc<AtomicBool>,
+}
+
+#[pymethods]
+impl BucketAsyncListing {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        if self.inner.ended.load(Ordering::SeqCst) {
+            return Ok(None);
+        }
+        if self
+            .iterating
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return Err(QiniuInvalidConcurrency::new_err(
+                "BucketAsyncListing can not be iterated concurrently",
+            ));
+        }
+        let inner = self.inner.to_owned();
+        let iterating = self.iterating.to_owned();
+        let future = pyo3_asyncio::async_std::future_into_py(py, async move {
+            let entry = inner.next_entry().await;
+            iterating.store(false, Ordering::SeqCst);
+            entry?.ok_or_else(|| PyStopAsyncIteration::new_err(()))
+        });
+        if future.is_err() {
+            self.iterating.store(false, Ordering::SeqCst);
+        }
+        Ok(Some(future?.into_py(py)))
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// 分页对象迭代器
+///
+/// 可以通过 `Bucket::iterate_objects` 方法获取，仅在当前页迭代完毕后才请求下一页
+#[pyclass]
+#[derive(Debug)]
+struct ObjectIterator {
+    bucket: Bucket,
+    prefix: Option<String>,
+    delimiter: Option<String>,
+    limit_per_page: usize,
+    page: Vec<serde_json::Value>,
+    index: usize,
+    marker: String,
+    last_page: bool,
+}
+
+#[pymethods]
+impl ObjectIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        loop {
+            if let Some(entry) = self.page.get(self.index) {
+                self.index += 1;
+                return convert_json_value_to_py_object(entry).map(Some);
             }
-            Ok(entry)
-        })
-        .ok()
-        .map(|any| any.into_py(py))
+            if self.last_page {
+                return Ok(None);
+            }
+            let result = py.allow_threads(|| self.fetch_page())?;
+            self.load_page(result);
+        }
+    }
+
+    /// 重置迭代器，下次迭代将从第一页重新开始
+    #[pyo3(text_signature = "($self)")]
+    fn reset(&mut self) {
+        self.page.clear();
+        self.index = 0;
+        self.marker.clear();
+        self.last_page = false;
     }
 
     fn __str__(&self) -> String {
@@ -1225,6 +4431,73 @@ impl AsyncObjectsIterator {
     }
 }
 
+impl ObjectIterator {
+    fn fetch_page(&self) -> PyResult<serde_json::Value> {
+        let region = self.bucket.region()?;
+        self.bucket.uc.call_service(
+            Method::GET,
+            &[qiniu_sdk::http_client::ServiceName::Rsf],
+            region,
+            "/list".to_owned(),
+            self.make_list_query_pairs(),
+            None,
+        )
+    }
+
+    async fn async_fetch_page(&self) -> PyResult<serde_json::Value> {
+        let region = self.bucket.async_region().await?;
+        self.bucket
+            .uc
+            .async_call_service(
+                Method::GET,
+                &[qiniu_sdk::http_client::ServiceName::Rsf],
+                region,
+                "/list".to_owned(),
+                self.make_list_query_pairs(),
+                None,
+            )
+            .await
+    }
+
+    fn load_page(&mut self, mut result: serde_json::Value) {
+        self.page = match result.get_mut("items").map(serde_json::Value::take) {
+            Some(serde_json::Value::Array(items)) => items,
+            _ => Vec::new(),
+        };
+        self.index = 0;
+        self.marker = result
+            .get("marker")
+            .and_then(|marker| marker.as_str())
+            .unwrap_or_default()
+            .to_owned();
+        self.last_page = self.marker.is_empty();
+    }
+
+    fn convert_page(&self, py: Python<'_>) -> PyResult<PyObject> {
+        Ok(self
+            .page
+            .iter()
+            .map(convert_json_value_to_py_object)
+            .collect::<PyResult<Vec<_>>>()?
+            .into_py(py))
+    }
+
+    fn make_list_query_pairs(&self) -> Vec<qiniu_sdk::http_client::QueryPair<'static>> {
+        let mut query_pairs = make_bucket_query_pairs(self.bucket.inner.name());
+        query_pairs.push(("limit".into(), self.limit_per_page.to_string().into()));
+        if !self.marker.is_empty() {
+            query_pairs.push(("marker".into(), self.marker.to_owned().into()));
+        }
+        if let Some(prefix) = &self.prefix {
+            query_pairs.push(("prefix".into(), prefix.to_owned().into()));
+        }
+        if let Some(delimiter) = &self.delimiter {
+            query_pairs.push(("delimiter".into(), delimiter.to_owned().into()));
+        }
+        query_pairs
+    }
+}
+
 /// 列举 API 版本
 ///
 /// 目前支持 V1 和 V2，默认为 V2
@@ -1257,6 +4530,59 @@ impl From<qiniu_sdk::objects::ListVersion> for ListVersion {
     }
 }
 
+/// 对象存储类型
+#[pyclass]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum StorageClass {
+    /// 标准存储
+    Standard = 0,
+
+    /// 低频存储
+    InfrequentAccess = 1,
+
+    /// 归档存储
+    Archive = 2,
+
+    /// 深度归档存储
+    DeepArchive = 3,
+
+    /// 归档直读存储
+    ArchiveInfrequentAccess = 4,
+}
+
+#[pymethods]
+impl StorageClass {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl StorageClass {
+    fn from_stat_result(value: &serde_json::Value) -> PyResult<Self> {
+        match value.get("type").and_then(|t| t.as_u64()).unwrap_or(0) {
+            0 => Ok(Self::Standard),
+            1 => Ok(Self::InfrequentAccess),
+            2 => Ok(Self::Archive),
+            3 => Ok(Self::DeepArchive),
+            4 => Ok(Self::ArchiveInfrequentAccess),
+            t => Err(QiniuUnsupportedTypeError::new_err(format!(
+                "Unrecognized storage class: {}",
+                t
+            ))),
+        }
+    }
+}
+
+impl From<StorageClass> for qiniu_sdk::upload_token::FileType {
+    fn from(storage_type: StorageClass) -> Self {
+        qiniu_sdk::upload_token::FileType::from(storage_type as u8)
+    }
+}
+
 /// 最大批量操作数获取接口
 #[pyclass(subclass)]
 #[derive(Clone, Debug)]
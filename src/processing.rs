@@ -0,0 +1,266 @@
+use super::{
+    credential::CredentialProvider,
+    exceptions::{QiniuApiCallError, QiniuIoError, QiniuJsonError},
+    http_client::{Endpoints, HttpClient},
+    utils::convert_json_value_to_py_object,
+};
+use futures::AsyncReadExt;
+use maybe_owned::MaybeOwned;
+use pyo3::{exceptions::PyValueError, prelude::*};
+use qiniu_sdk::http::Method;
+use std::{io::Read, thread::sleep, time::Duration};
+
+pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "processing")?;
+    m.add_class::<PersistentOpsManager>()?;
+    m.add_class::<PfopStatus>()?;
+    Ok(m)
+}
+
+const DEFAULT_API_DOMAIN: &str = "api.qiniu.com";
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 七牛持久化处理管理器
+///
+/// 用于等待持久化处理完成，轮询间隔将按照指数退避的方式增长
+#[pyclass]
+#[pyo3(
+    text_signature = "(credential, /, poll_interval_secs = 5, max_polls = 120, use_https = None, http_client = None, api_endpoints = None)"
+)]
+#[derive(Clone)]
+struct PersistentOpsManager {
+    http_client: qiniu_sdk::http_client::HttpClient,
+    credential: CredentialProvider,
+    api_endpoints: qiniu_sdk::http_client::Endpoints,
+    use_https: Option<bool>,
+    poll_interval: Duration,
+    max_polls: usize,
+}
+
+#[pymethods]
+impl PersistentOpsManager {
+    #[new]
+    #[args(
+        poll_interval_secs = "5.0",
+        max_polls = "120",
+        use_https = "None",
+        http_client = "None",
+        api_endpoints = "None"
+    )]
+    fn new(
+        credential: CredentialProvider,
+        poll_interval_secs: f64,
+        max_polls: usize,
+        use_https: Option<bool>,
+        http_client: Option<HttpClient>,
+        api_endpoints: Option<Endpoints>,
+    ) -> PyResult<Self> {
+        if !(0.0..u64::MAX as f64).contains(&poll_interval_secs) {
+            return Err(PyValueError::new_err(format!(
+                "poll_interval_secs must be a non-negative finite number: {}",
+                poll_interval_secs
+            )));
+        }
+        Ok(Self {
+            http_client: http_client.map(Into::into).unwrap_or_default(),
+            credential,
+            api_endpoints: api_endpoints
+                .map(Into::into)
+                .unwrap_or_else(default_api_endpoints),
+            use_https,
+            poll_interval: Duration::from_secs_f64(poll_interval_secs),
+            max_polls,
+        })
+    }
+
+    /// 等待持久化处理完成，返回持久化处理结果
+    ///
+    /// 如果轮询 `max_polls` 次后仍未完成，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, persistent_id)")]
+    fn wait_for_completion(&self, persistent_id: &str, py: Python<'_>) -> PyResult<PyObject> {
+        let value = py.allow_threads(|| {
+            let mut interval = self.poll_interval;
+            for polled in 1..=self.max_polls {
+                let value = self.query(persistent_id)?;
+                if is_terminal_result(&value) {
+                    return Ok(value);
+                }
+                if polled < self.max_polls {
+                    sleep(interval);
+                    interval = next_poll_interval(interval);
+                }
+            }
+            Err(make_poll_timeout_error(persistent_id, self.max_polls))
+        })?;
+        convert_json_value_to_py_object(&value)
+    }
+
+    /// 异步等待持久化处理完成，返回持久化处理结果
+    ///
+    /// 如果轮询 `max_polls` 次后仍未完成，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, persistent_id)")]
+    fn async_wait_for_completion<'p>(
+        &self,
+        persistent_id: String,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let manager = self.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let mut interval = manager.poll_interval;
+            for polled in 1..=manager.max_polls {
+                let value = manager.async_query(&persistent_id).await?;
+                if is_terminal_result(&value) {
+                    return convert_json_value_to_py_object(&value);
+                }
+                if polled < manager.max_polls {
+                    async_std::task::sleep(interval).await;
+                    interval = next_poll_interval(interval);
+                }
+            }
+            Err(make_poll_timeout_error(&persistent_id, manager.max_polls))
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PersistentOpsManager(poll_interval={:?}, max_polls={})",
+            self.poll_interval, self.max_polls
+        )
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+impl PersistentOpsManager {
+    fn query(&self, persistent_id: &str) -> PyResult<serde_json::Value> {
+        let mut builder = self.http_client.new_request(
+            Method::GET,
+            &[qiniu_sdk::http_client::ServiceName::Api],
+            self.api_endpoints.to_owned(),
+        );
+        builder
+            .path("/status/get/prefop")
+            .query_pairs(make_persistent_id_query_pairs(persistent_id))
+            .accept_json()
+            .authorization(qiniu_sdk::http_client::Authorization::v2(
+                self.credential.to_owned(),
+            ));
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
+        }
+        let mut body = Vec::new();
+        builder
+            .call()
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .into_body()
+            .read_to_end(&mut body)
+            .map_err(QiniuIoError::from_err)?;
+        serde_json::from_slice(&body).map_err(QiniuJsonError::from_err)
+    }
+
+    async fn async_query(&self, persistent_id: &str) -> PyResult<serde_json::Value> {
+        let mut builder = self.http_client.new_async_request(
+            Method::GET,
+            &[qiniu_sdk::http_client::ServiceName::Api],
+            self.api_endpoints.to_owned(),
+        );
+        builder
+            .path("/status/get/prefop")
+            .query_pairs(make_persistent_id_query_pairs(persistent_id))
+            .accept_json()
+            .authorization(qiniu_sdk::http_client::Authorization::v2(
+                self.credential.to_owned(),
+            ));
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
+        }
+        let mut body = Vec::new();
+        builder
+            .call()
+            .await
+            .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?
+            .into_body()
+            .read_to_end(&mut body)
+            .await
+            .map_err(QiniuIoError::from_err)?;
+        serde_json::from_slice(&body).map_err(QiniuJsonError::from_err)
+    }
+}
+
+/// 持久化处理状态
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PfopStatus {
+    /// 处理成功
+    Success = 0,
+    /// 等待处理
+    Pending = 1,
+    /// 正在处理
+    Processing = 2,
+    /// 处理失败
+    Failure = 3,
+    /// 回调失败
+    NotifyFailure = 4,
+}
+
+#[pymethods]
+impl PfopStatus {
+    /// 是否已经处理结束，处理结束后状态将不再改变
+    #[pyo3(text_signature = "($self)")]
+    fn is_terminal(&self) -> bool {
+        matches!(self, Self::Success | Self::Failure | Self::NotifyFailure)
+    }
+}
+
+impl PfopStatus {
+    fn from_code(code: u64) -> Option<Self> {
+        match code {
+            0 => Some(Self::Success),
+            1 => Some(Self::Pending),
+            2 => Some(Self::Processing),
+            3 => Some(Self::Failure),
+            4 => Some(Self::NotifyFailure),
+            _ => None,
+        }
+    }
+}
+
+fn default_api_endpoints() -> qiniu_sdk::http_client::Endpoints {
+    let mut builder = qiniu_sdk::http_client::EndpointsBuilder::default();
+    builder.add_preferred_endpoints(vec![qiniu_sdk::http_client::Endpoint::new_from_domain(
+        DEFAULT_API_DOMAIN,
+    )]);
+    builder.build()
+}
+
+fn make_persistent_id_query_pairs(
+    persistent_id: &str,
+) -> Vec<qiniu_sdk::http_client::QueryPair<'static>> {
+    vec![("id".into(), persistent_id.to_owned().into())]
+}
+
+fn is_terminal_result(value: &serde_json::Value) -> bool {
+    value
+        .get("code")
+        .and_then(|code| code.as_u64())
+        .and_then(PfopStatus::from_code)
+        .map_or(false, |status| status.is_terminal())
+}
+
+fn next_poll_interval(interval: Duration) -> Duration {
+    interval.saturating_mul(2).min(MAX_POLL_INTERVAL)
+}
+
+fn make_poll_timeout_error(persistent_id: &str, max_polls: usize) -> PyErr {
+    QiniuApiCallError::from_err(MaybeOwned::Owned(
+        qiniu_sdk::http_client::ResponseError::new(
+            qiniu_sdk::http::ResponseErrorKind::TimeoutError.into(),
+            format!(
+                "Persistent operation {} is not completed after {} polls",
+                persistent_id, max_polls
+            ),
+        ),
+    ))
+}
@@ -1,24 +1,30 @@
 use super::{
     credential::CredentialProvider,
     exceptions::{
-        QiniuApiCallError, QiniuInvalidConcurrency, QiniuInvalidLimitation, QiniuInvalidMultiply,
-        QiniuInvalidObjectSize, QiniuInvalidPartSize, QiniuInvalidSourceKeyLengthError,
-        QiniuIoError,
+        QiniuApiCallError, QiniuBodySizeMissingError, QiniuInvalidConcurrency,
+        QiniuInvalidLimitation, QiniuInvalidMultiply, QiniuInvalidObjectSize, QiniuInvalidPartSize,
+        QiniuInvalidSourceKeyLengthError, QiniuIoError, QiniuIsahcError, QiniuUnsupportedTypeError,
     },
     http::HttpResponsePartsMut,
     http_client::{
-        BucketRegionsQueryer, Endpoints, HttpClient, RegionsProvider, RequestBuilderPartsRef,
+        Backoff, BucketRegionsQueryer, Endpoint, Endpoints, HttpClient, RegionsProvider,
+        RequestBuilderPartsRef, SelectorConfig,
     },
     upload_token::{on_policy_generated_callback, UploadTokenProvider},
     utils::{
-        convert_api_call_error, convert_json_value_to_py_object, parse_mime, AsyncReader,
-        PythonIoBase, Reader,
+        convert_api_call_error, convert_json_value_to_py_object, is_unauthorized_error,
+        make_io_error_from_py_err, parse_mime, AsyncReader, PythonIoBase, Reader,
     },
 };
 use anyhow::Result as AnyResult;
-use futures::{lock::Mutex as AsyncMutex, AsyncReadExt, AsyncWriteExt};
+use futures::{lock::Mutex as AsyncMutex, AsyncReadExt, AsyncWriteExt, StreamExt, TryStreamExt};
 use maybe_owned::MaybeOwned;
-use pyo3::{exceptions::PyIOError, prelude::*, types::PyBytes};
+use once_cell::sync::Lazy;
+use pyo3::{
+    exceptions::{PyIOError, PyStopAsyncIteration, PyValueError},
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
 use qiniu_sdk::{
     etag::GenericArray,
     prelude::{
@@ -29,7 +35,19 @@ use qiniu_sdk::{
 };
 use sha1::{digest::OutputSizeUser, Sha1};
 use std::{
-    collections::HashMap, io::Read, mem::transmute, num::NonZeroU64, sync::Arc, time::Duration,
+    collections::HashMap,
+    fs::File,
+    io::{Error as IoError, ErrorKind as IoErrorKind, Read, Result as IoResult},
+    mem::transmute,
+    net::IpAddr,
+    num::{NonZeroU16, NonZeroU64, NonZeroUsize},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
 };
 
 pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
@@ -80,6 +98,7 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<ConcurrentMultiPartsUploaderScheduler>()?;
     m.add_class::<UploadingProgressInfo>()?;
     m.add_class::<UploadedPartInfo>()?;
+    m.add_class::<UploadLogEntry>()?;
     m.add_class::<MultiPartsUploaderSchedulerPrefer>()?;
     m.add_class::<SinglePartUploaderPrefer>()?;
     m.add_class::<MultiPartsUploaderPrefer>()?;
@@ -90,7 +109,10 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
 /// 上传凭证签发器
 #[pyclass]
 #[derive(Clone, Debug)]
-struct UploadTokenSigner(qiniu_sdk::upload::UploadTokenSigner);
+struct UploadTokenSigner(
+    qiniu_sdk::upload::UploadTokenSigner,
+    Option<(CredentialProvider, String)>,
+);
 
 #[pymethods]
 impl UploadTokenSigner {
@@ -98,7 +120,10 @@ impl UploadTokenSigner {
     #[staticmethod]
     #[pyo3(text_signature = "(upload_token_provider)")]
     fn new_upload_token_provider(upload_token_provider: UploadTokenProvider) -> Self {
-        Self(qiniu_sdk::upload::UploadTokenSigner::new_upload_token_provider(upload_token_provider))
+        Self(
+            qiniu_sdk::upload::UploadTokenSigner::new_upload_token_provider(upload_token_provider),
+            None,
+        )
     }
 
     /// 根据认证信息提供者和存储空间名称创建上传凭证签发器
@@ -114,14 +139,14 @@ impl UploadTokenSigner {
         on_policy_generated: Option<PyObject>,
     ) -> Self {
         let mut builder = qiniu_sdk::upload::UploadTokenSigner::new_credential_provider_builder(
-            credential,
-            bucket_name,
+            credential.to_owned(),
+            bucket_name.to_owned(),
             Duration::from_secs(lifetime_secs),
         );
         if let Some(callback) = on_policy_generated {
             builder = builder.on_policy_generated(on_policy_generated_callback(callback));
         }
-        Self(builder.build())
+        Self(builder.build(), Some((credential, bucket_name)))
     }
 
     fn __str__(&self) -> String {
@@ -1564,7 +1589,13 @@ impl AsyncDataSourceReader {
 #[pyo3(
     text_signature = "(signer, http_client = None, use_https = None, queryer = None, uc_endpoints = None)"
 )]
-struct UploadManager(qiniu_sdk::upload::UploadManager);
+struct UploadManager {
+    inner: qiniu_sdk::upload::UploadManager,
+    bucket: Option<qiniu_sdk::objects::Bucket>,
+    config: UploadManagerConfig,
+    upload_logger: Option<PyObject>,
+    http_client_options: UploadHttpClientOptions,
+}
 
 #[pymethods]
 impl UploadManager {
@@ -1583,20 +1614,86 @@ impl UploadManager {
         queryer: Option<BucketRegionsQueryer>,
         uc_endpoints: Option<Endpoints>,
     ) -> Self {
-        let mut builder = qiniu_sdk::upload::UploadManager::builder(signer.0);
-        if let Some(http_client) = http_client {
-            builder.http_client(http_client.into());
-        }
-        if let Some(use_https) = use_https {
-            builder.use_https(use_https);
-        }
-        if let Some(queryer) = queryer {
-            builder.queryer(queryer.into());
+        let config = UploadManagerConfig {
+            signer,
+            http_client,
+            use_https,
+            queryer: queryer.map(Into::into),
+            uc_endpoints: uc_endpoints.map(Into::into),
+        };
+        let (inner, bucket) = config.build(config.http_client.to_owned().map(Into::into));
+        Self {
+            inner,
+            bucket,
+            config,
+            upload_logger: None,
+            http_client_options: Default::default(),
         }
-        if let Some(uc_endpoints) = uc_endpoints {
-            builder.uc_endpoints(uc_endpoints);
+    }
+
+    /// 设置进程级别的默认区域信息获取接口
+    ///
+    /// 上传时如果没有指定 `region_provider`，则使用该接口获取区域信息，传入 `None` 则清除默认接口
+    #[staticmethod]
+    #[pyo3(text_signature = "(provider)")]
+    fn set_default_regions_provider(provider: Option<RegionsProvider>) {
+        *DEFAULT_REGIONS_PROVIDER
+            .lock()
+            .unwrap_or_else(|err| err.into_inner()) = provider;
+    }
+
+    /// 获取进程级别的默认区域信息获取接口
+    #[staticmethod]
+    #[pyo3(text_signature = "()")]
+    fn get_default_regions_provider() -> Option<RegionsProvider> {
+        default_regions_provider()
+    }
+
+    /// 创建带有上传日志回调的上传管理器
+    ///
+    /// 每次上传请求都将以 `UploadLogEntry` 作为参数调用 `callback`，分片上传成功后还会额外记录分片的编号、偏移量和大小
+    #[pyo3(text_signature = "($self, callback)")]
+    fn with_upload_logger(&self, callback: PyObject) -> Self {
+        Self {
+            upload_logger: Some(callback),
+            ..self.to_owned()
         }
-        Self(builder.build())
+    }
+
+    /// 创建使用指定终端地址选择策略的上传管理器
+    ///
+    /// 将在原有 HTTP 客户端设置的基础上使用 `config` 创建的选择器和退避时长提供者
+    #[pyo3(text_signature = "($self, config)")]
+    fn with_selector_config(&self, config: SelectorConfig) -> PyResult<Self> {
+        self.with_http_client_options(UploadHttpClientOptions {
+            selector_config: Some(config),
+            ..self.http_client_options.to_owned()
+        })
+    }
+
+    /// 创建使用指定重试次数的上传管理器
+    ///
+    /// 每个终端地址最多重试 `n` 次，之后切换到其他终端地址继续上传，如果 `n` 小于 0 将抛出 `QiniuInvalidConcurrency` 异常。
+    /// 将在原有 HTTP 客户端设置的基础上修改重试器
+    #[pyo3(text_signature = "($self, n)")]
+    fn with_max_retry(&self, n: i64) -> PyResult<Self> {
+        let max_retry = usize::try_from(n)
+            .map_err(|_| QiniuInvalidConcurrency::new_err("Invalid retry count"))?;
+        self.with_http_client_options(UploadHttpClientOptions {
+            max_retry: Some(max_retry),
+            ..self.http_client_options.to_owned()
+        })
+    }
+
+    /// 创建使用指定退避时长提供者的上传管理器
+    ///
+    /// 优先于 `with_selector_config()` 设置的退避时长，将在原有 HTTP 客户端设置的基础上修改退避时长提供者
+    #[pyo3(text_signature = "($self, strategy)")]
+    fn with_retry_backoff(&self, strategy: Backoff) -> PyResult<Self> {
+        self.with_http_client_options(UploadHttpClientOptions {
+            retry_backoff: Some(strategy),
+            ..self.http_client_options.to_owned()
+        })
     }
 
     /// 创建表单上传器
@@ -1616,7 +1713,7 @@ impl UploadManager {
         response_ok: Option<PyObject>,
         response_error: Option<PyObject>,
     ) -> FormUploader {
-        let mut uploader = self.0.form_uploader();
+        let mut uploader = self.inner.form_uploader();
         if let Some(before_request) = before_request {
             uploader.on_before_request(on_before_request(before_request));
         }
@@ -1629,6 +1726,7 @@ impl UploadManager {
         if let Some(response_error) = response_error {
             uploader.on_response_error(on_error(response_error));
         }
+        self.attach_upload_logger(&mut uploader);
         FormUploader(uploader)
     }
 
@@ -1652,7 +1750,7 @@ impl UploadManager {
         response_error: Option<PyObject>,
         part_uploaded: Option<PyObject>,
     ) -> MultiPartsV1Uploader {
-        let mut uploader = self.0.multi_parts_v1_uploader(resumable_recorder);
+        let mut uploader = self.inner.multi_parts_v1_uploader(resumable_recorder);
         if let Some(before_request) = before_request {
             uploader.on_before_request(on_before_request(before_request));
         }
@@ -1668,6 +1766,7 @@ impl UploadManager {
         if let Some(part_uploaded) = part_uploaded {
             uploader.on_part_uploaded(on_part_uploaded(part_uploaded));
         }
+        self.attach_multi_parts_upload_logger(&mut uploader);
         MultiPartsV1Uploader(uploader)
     }
 
@@ -1692,7 +1791,7 @@ impl UploadManager {
         response_error: Option<PyObject>,
         part_uploaded: Option<PyObject>,
     ) -> MultiPartsV2Uploader {
-        let mut uploader = self.0.multi_parts_v2_uploader(resumable_recorder);
+        let mut uploader = self.inner.multi_parts_v2_uploader(resumable_recorder);
         if let Some(before_request) = before_request {
             uploader.on_before_request(on_before_request(before_request));
         }
@@ -1708,6 +1807,7 @@ impl UploadManager {
         if let Some(part_uploaded) = part_uploaded {
             uploader.on_part_uploaded(on_part_uploaded(part_uploaded));
         }
+        self.attach_multi_parts_upload_logger(&mut uploader);
         MultiPartsV2Uploader(uploader)
     }
 
@@ -1739,7 +1839,7 @@ impl UploadManager {
         response_error: Option<PyObject>,
         part_uploaded: Option<PyObject>,
     ) -> AutoUploader {
-        let mut builder = self.0.auto_uploader_builder();
+        let mut builder = self.inner.auto_uploader_builder();
         if let Some(concurrency_provider) = concurrency_provider {
             builder.concurrency_provider(concurrency_provider);
         }
@@ -1768,8 +1868,1052 @@ impl UploadManager {
         if let Some(part_uploaded) = part_uploaded {
             uploader.on_part_uploaded(on_part_uploaded(part_uploaded));
         }
+        self.attach_multi_parts_upload_logger(&mut uploader);
         AutoUploader(uploader)
     }
+
+    /// 通过表单上传文件，同时附带额外的表单字段
+    ///
+    /// `extra_fields` 中以 `x-qn-meta-` 开头的字段将作为对象元信息，以 `x:` 开头的字段将作为自定义变量，
+    /// 其他字段将抛出 `QiniuUnsupportedTypeError` 异常
+    #[pyo3(
+        text_signature = "($self, path, extra_fields, /, key = None, region_provider = None, file_name = None, content_type = None)"
+    )]
+    #[args(
+        key = "None",
+        region_provider = "None",
+        file_name = "None",
+        content_type = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_file_with_form_fields(
+        &self,
+        path: &str,
+        extra_fields: HashMap<String, String>,
+        key: Option<&str>,
+        region_provider: Option<RegionsProvider>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let (metadata, custom_vars) = split_extra_form_fields(extra_fields)?;
+        let object_params = make_object_params(
+            region_provider,
+            key,
+            file_name,
+            content_type,
+            Some(metadata),
+            Some(custom_vars),
+            None,
+        )?;
+        py.allow_threads(|| {
+            let mut uploader = self.inner.form_uploader();
+            self.attach_upload_logger(&mut uploader);
+            uploader
+                .upload_path(path, object_params)
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+
+    /// 通过表单异步上传文件，同时附带额外的表单字段
+    ///
+    /// `extra_fields` 中以 `x-qn-meta-` 开头的字段将作为对象元信息，以 `x:` 开头的字段将作为自定义变量，
+    /// 其他字段将抛出 `QiniuUnsupportedTypeError` 异常
+    #[pyo3(
+        text_signature = "($self, path, extra_fields, /, key = None, region_provider = None, file_name = None, content_type = None)"
+    )]
+    #[args(
+        key = "None",
+        region_provider = "None",
+        file_name = "None",
+        content_type = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn async_upload_file_with_form_fields<'p>(
+        &self,
+        path: String,
+        extra_fields: HashMap<String, String>,
+        key: Option<&str>,
+        region_provider: Option<RegionsProvider>,
+        file_name: Option<&str>,
+        content_type: Option<&str>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let (metadata, custom_vars) = split_extra_form_fields(extra_fields)?;
+        let object_params = make_object_params(
+            region_provider,
+            key,
+            file_name,
+            content_type,
+            Some(metadata),
+            Some(custom_vars),
+            None,
+        )?;
+        let mut uploader = self.inner.form_uploader();
+        self.attach_upload_logger(&mut uploader);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            uploader
+                .async_upload_path(&path, object_params)
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+
+    /// 上传不可寻址的数据流，例如生成器、套接字或压缩流
+    ///
+    /// 必须传入数据流的尺寸 `size`，否则将抛出 `QiniuBodySizeMissingError` 异常。
+    /// 仅在调用 `stream.read()` 时持有 GIL
+    #[pyo3(
+        text_signature = "($self, stream, size = None, key = None, file_name = None, mime = None, progress = None)"
+    )]
+    #[args(
+        size = "None",
+        key = "None",
+        file_name = "None",
+        mime = "None",
+        progress = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_stream(
+        &self,
+        stream: PyObject,
+        size: Option<u64>,
+        key: Option<&str>,
+        file_name: Option<&str>,
+        mime: Option<&str>,
+        progress: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let size = size.ok_or_else(make_stream_size_missing_error)?;
+        let object_params = make_auto_uploader_object_params(
+            None, key, file_name, mime, None, None, None, None, None, None,
+        )?;
+        let uploader = self.stream_uploader(size, progress);
+        py.allow_threads(|| {
+            uploader
+                .upload_reader(PythonIoBase::new(stream).take(size), object_params)
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+
+    /// 异步上传不可寻址的数据流，例如生成器、套接字或压缩流
+    ///
+    /// 必须传入数据流的尺寸 `size`，否则将抛出 `QiniuBodySizeMissingError` 异常
+    #[pyo3(
+        text_signature = "($self, stream, size = None, key = None, file_name = None, mime = None, progress = None)"
+    )]
+    #[args(
+        size = "None",
+        key = "None",
+        file_name = "None",
+        mime = "None",
+        progress = "None"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    fn async_upload_stream<'p>(
+        &self,
+        stream: PyObject,
+        size: Option<u64>,
+        key: Option<&str>,
+        file_name: Option<&str>,
+        mime: Option<&str>,
+        progress: Option<PyObject>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let size = size.ok_or_else(make_stream_size_missing_error)?;
+        let object_params = make_auto_uploader_object_params(
+            None, key, file_name, mime, None, None, None, None, None, None,
+        )?;
+        let uploader = self.stream_uploader(size, progress);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            uploader
+                .async_upload_reader(
+                    PythonIoBase::new(stream).into_async_read().take(size),
+                    object_params,
+                )
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+
+    /// 异步上传异步生成器产生的数据
+    ///
+    /// 通过 `await gen.__anext__()` 依次读取 `bytes` 数据块，直到抛出 `StopAsyncIteration` 异常为止。
+    /// `size` 为数据总尺寸，超过该尺寸的数据将被忽略
+    #[pyo3(
+        text_signature = "($self, gen, size, /, key = None, file_name = None, mime = None, progress = None)"
+    )]
+    #[args(key = "None", file_name = "None", mime = "None", progress = "None")]
+    #[allow(clippy::too_many_arguments)]
+    fn upload_file_async_generator<'p>(
+        &self,
+        gen: PyObject,
+        size: u64,
+        key: Option<&str>,
+        file_name: Option<&str>,
+        mime: Option<&str>,
+        progress: Option<PyObject>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let object_params = make_auto_uploader_object_params(
+            None, key, file_name, mime, None, None, None, None, None, None,
+        )?;
+        let uploader = self.stream_uploader(size, progress);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            uploader
+                .async_upload_reader(AsyncGeneratorReader::new(gen).take(size), object_params)
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+
+    /// 上传目录中的所有文件
+    ///
+    /// 对象名称为 `key_prefix` 与文件相对路径的拼接，`file_filter` 接受文件相对路径，返回 `True` 表示上传该文件。
+    /// 最多同时上传 `concurrency` 个文件，部分文件上传失败不会中断其他文件的上传。
+    /// 返回 `{"succeeded": int, "failed": [{"path": str, "key": str, "error": Exception}]}`，仅认证失败时直接抛出 `QiniuApiCallError` 异常
+    #[pyo3(
+        text_signature = "($self, path, key_prefix = '', /, concurrency = 4, file_filter = None, on_progress = None)"
+    )]
+    #[args(
+        key_prefix = "\"\"",
+        concurrency = "4",
+        file_filter = "None",
+        on_progress = "None"
+    )]
+    fn upload_directory(
+        &self,
+        path: PathBuf,
+        key_prefix: &str,
+        concurrency: usize,
+        file_filter: Option<PyObject>,
+        on_progress: Option<PyObject>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let files = collect_directory_files(&path, key_prefix, file_filter, py)?;
+        let uploader = self.plain_auto_uploader(on_progress);
+        let results = py.allow_threads(|| {
+            async_std::task::block_on(upload_directory_files(&uploader, files, concurrency))
+        });
+        convert_upload_directory_results(results, py)
+    }
+
+    /// 异步上传目录中的所有文件
+    ///
+    /// 对象名称为 `key_prefix` 与文件相对路径的拼接，`file_filter` 接受文件相对路径，返回 `True` 表示上传该文件。
+    /// 最多同时上传 `concurrency` 个文件，部分文件上传失败不会中断其他文件的上传。
+    /// 返回 `{"succeeded": int, "failed": [{"path": str, "key": str, "error": Exception}]}`，仅认证失败时直接抛出 `QiniuApiCallError` 异常
+    #[pyo3(
+        text_signature = "($self, path, key_prefix = '', /, concurrency = 4, file_filter = None, on_progress = None)"
+    )]
+    #[args(
+        key_prefix = "\"\"",
+        concurrency = "4",
+        file_filter = "None",
+        on_progress = "None"
+    )]
+    fn async_upload_directory<'p>(
+        &self,
+        path: PathBuf,
+        key_prefix: &str,
+        concurrency: usize,
+        file_filter: Option<PyObject>,
+        on_progress: Option<PyObject>,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let files = collect_directory_files(&path, key_prefix, file_filter, py)?;
+        let uploader = self.plain_auto_uploader(on_progress);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let results = upload_directory_files(&uploader, files, concurrency).await;
+            Python::with_gil(|py| convert_upload_directory_results(results, py))
+        })
+    }
+
+    /// 上传文件，如果存储空间中已经存在相同 Etag 的对象则跳过上传
+    ///
+    /// 跳过上传时返回 `None`，对象不存在（状态码 612）或 Etag 不同时将正常上传并返回上传结果。
+    /// 仅支持通过 `UploadTokenSigner.new_credential_provider()` 创建的上传管理器，且仅比较 Etag V1
+    #[pyo3(text_signature = "($self, path, key, /, skip_on_match = True)")]
+    #[args(skip_on_match = "true")]
+    fn upload_file_check_duplicate(
+        &self,
+        path: PathBuf,
+        key: &str,
+        skip_on_match: bool,
+        py: Python<'_>,
+    ) -> PyResult<Option<PyObject>> {
+        let bucket = self.duplicate_check_bucket()?;
+        let uploader = self.plain_auto_uploader(None);
+        let result = py.allow_threads(|| {
+            if skip_on_match {
+                let local_etag = file_etag(&path)?;
+                let remote_etag = match bucket.stat_object(key).call() {
+                    Ok(resp) => remote_etag_of(resp.into_parts_and_body().1.as_ref()),
+                    Err(err) if is_object_not_found_error(&err) => None,
+                    Err(err) => return Err(QiniuApiCallError::from_err(MaybeOwned::Owned(err))),
+                };
+                if remote_etag.as_deref() == Some(local_etag.as_str()) {
+                    return Ok(None);
+                }
+            }
+            uploader
+                .upload_path(&path, make_duplicate_check_object_params(key)?)
+                .map(Some)
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+        })?;
+        result
+            .map(|value| convert_json_value_to_py_object(&value))
+            .transpose()
+    }
+
+    /// 异步上传文件，如果存储空间中已经存在相同 Etag 的对象则跳过上传
+    ///
+    /// 跳过上传时返回 `None`，对象不存在（状态码 612）或 Etag 不同时将正常上传并返回上传结果。
+    /// 仅支持通过 `UploadTokenSigner.new_credential_provider()` 创建的上传管理器，且仅比较 Etag V1
+    #[pyo3(text_signature = "($self, path, key, /, skip_on_match = True)")]
+    #[args(skip_on_match = "true")]
+    fn async_upload_file_check_duplicate<'p>(
+        &self,
+        path: PathBuf,
+        key: String,
+        skip_on_match: bool,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let bucket = self.duplicate_check_bucket()?;
+        let uploader = self.plain_auto_uploader(None);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            if skip_on_match {
+                let local_etag = {
+                    let path = path.to_owned();
+                    async_std::task::spawn_blocking(move || file_etag(&path)).await?
+                };
+                let remote_etag = match bucket.stat_object(&key).async_call().await {
+                    Ok(resp) => remote_etag_of(resp.into_parts_and_body().1.as_ref()),
+                    Err(err) if is_object_not_found_error(&err) => None,
+                    Err(err) => return Err(QiniuApiCallError::from_err(MaybeOwned::Owned(err))),
+                };
+                if remote_etag.as_deref() == Some(local_etag.as_str()) {
+                    return Ok(None);
+                }
+            }
+            let value = uploader
+                .async_upload_path(&path, make_duplicate_check_object_params(&key)?)
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            convert_json_value_to_py_object(&value).map(Some)
+        })
+    }
+
+    /// 上传文件，并根据文件内容推测 MIME 类型
+    ///
+    /// 读取文件前 `magic_bytes` 个字节推测 MIME 类型，无法推测时使用 `application/octet-stream`。
+    /// 如果无法读取文件，将抛出 `QiniuIoError` 异常
+    #[pyo3(text_signature = "($self, path, key = None, /, magic_bytes = 512)")]
+    #[args(key = "None", magic_bytes = "512")]
+    fn upload_file_with_content_type_detection(
+        &self,
+        path: PathBuf,
+        key: Option<&str>,
+        magic_bytes: u64,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let uploader = self.plain_auto_uploader(None);
+        py.allow_threads(|| {
+            let content_type = detect_content_type(&path, magic_bytes)?;
+            uploader
+                .upload_path(
+                    &path,
+                    make_content_type_detection_object_params(key, &content_type)?,
+                )
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+
+    /// 异步上传文件，并根据文件内容推测 MIME 类型
+    ///
+    /// 读取文件前 `magic_bytes` 个字节推测 MIME 类型，无法推测时使用 `application/octet-stream`。
+    /// 如果无法读取文件，将抛出 `QiniuIoError` 异常
+    #[pyo3(text_signature = "($self, path, key = None, /, magic_bytes = 512)")]
+    #[args(key = "None", magic_bytes = "512")]
+    fn async_upload_file_with_content_type_detection<'p>(
+        &self,
+        path: PathBuf,
+        key: Option<String>,
+        magic_bytes: u64,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let uploader = self.plain_auto_uploader(None);
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let content_type = {
+                let path = path.to_owned();
+                async_std::task::spawn_blocking(move || detect_content_type(&path, magic_bytes))
+                    .await?
+            };
+            let value = uploader
+                .async_upload_path(
+                    &path,
+                    make_content_type_detection_object_params(key.as_deref(), &content_type)?,
+                )
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))?;
+            convert_json_value_to_py_object(&value)
+        })
+    }
+
+    /// 分片上传文件，每个分片的数据在上传前都会经过 `on_chunk` 变换
+    ///
+    /// 调用 `on_chunk(part_number, data)` 时持有 GIL，分片编号从 1 开始，返回的 `bytes` 将作为编号为 `part_number` 的分片上传，
+    /// 其长度可以与原始数据不同，但不能为空，上传的对象内容为所有变换后分片的拼接。
+    /// `chunk_size` 必须是 1 MB 的整数倍，否则将抛出 `ValueError` 异常
+    #[pyo3(text_signature = "($self, path, key, chunk_size, on_chunk)")]
+    fn upload_file_in_chunks(
+        &self,
+        path: PathBuf,
+        key: &str,
+        chunk_size: u64,
+        on_chunk: PyObject,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let scheduler = self.chunks_uploader_scheduler(chunk_size)?;
+        let object_params = make_object_params(None, Some(key), None, None, None, None, None)?;
+        py.allow_threads(|| {
+            let file = File::open(&path).map_err(QiniuIoError::from_err)?;
+            scheduler
+                .upload(
+                    Box::new(ChunkTransformDataSource::new(file, chunk_size, on_chunk)),
+                    object_params,
+                )
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+
+    /// 异步分片上传文件，每个分片的数据在上传前都会经过 `on_chunk` 变换
+    ///
+    /// `on_chunk(part_number, data)` 必须是异步函数，分片编号从 1 开始，返回的 `bytes` 将作为编号为 `part_number` 的分片上传，
+    /// 其长度可以与原始数据不同，但不能为空，上传的对象内容为所有变换后分片的拼接。
+    /// `chunk_size` 必须是 1 MB 的整数倍，否则将抛出 `ValueError` 异常
+    #[pyo3(text_signature = "($self, path, key, chunk_size, on_chunk)")]
+    fn async_upload_file_in_chunks<'p>(
+        &self,
+        path: PathBuf,
+        key: &str,
+        chunk_size: u64,
+        on_chunk: PyObject,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let scheduler = self.chunks_uploader_scheduler(chunk_size)?;
+        let object_params = make_object_params(None, Some(key), None, None, None, None, None)?;
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let file = async_std::fs::File::open(&path)
+                .await
+                .map_err(QiniuIoError::from_err)?;
+            scheduler
+                .async_upload(
+                    Box::new(AsyncChunkTransformDataSource::new(
+                        file, chunk_size, on_chunk,
+                    )),
+                    object_params,
+                )
+                .await
+                .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err)))
+                .and_then(|v| convert_json_value_to_py_object(&v))
+        })
+    }
+}
+
+impl UploadManager {
+    fn with_http_client_options(&self, options: UploadHttpClientOptions) -> PyResult<Self> {
+        let mut builder = if let Some(http_client) = &self.config.http_client {
+            http_client.to_builder()
+        } else {
+            qiniu_sdk::http_client::HttpClient::build_isahc().map_err(QiniuIsahcError::from_err)?
+        };
+        if let Some(config) = &options.selector_config {
+            builder.chooser(config.chooser());
+            builder.backoff(config.backoff());
+        }
+        if let Some(backoff) = &options.retry_backoff {
+            builder.backoff(backoff.to_owned());
+        }
+        if let Some(max_retry) = options.max_retry {
+            builder.request_retrier(
+                qiniu_sdk::http_client::LimitedRetrier::limit_current_endpoint(
+                    qiniu_sdk::http_client::ErrorRetrier,
+                    max_retry,
+                ),
+            );
+        }
+        if let Some(use_https) = self.config.use_https {
+            builder.use_https(use_https);
+        }
+        let (inner, bucket) = self.config.build(Some(builder.build()));
+        Ok(Self {
+            inner,
+            bucket,
+            http_client_options: options,
+            ..self.to_owned()
+        })
+    }
+
+    fn duplicate_check_bucket(&self) -> PyResult<qiniu_sdk::objects::Bucket> {
+        self.bucket.to_owned().ok_or_else(|| {
+            PyValueError::new_err(
+                "UploadManager must be created by UploadTokenSigner.new_credential_provider() to check duplicate objects",
+            )
+        })
+    }
+
+    fn plain_auto_uploader(&self, progress: Option<PyObject>) -> qiniu_sdk::upload::AutoUploader {
+        let mut uploader = self.inner.auto_uploader_builder().build();
+        if let Some(progress) = progress {
+            uploader.on_upload_progress(on_upload_progress(progress));
+        }
+        self.attach_multi_parts_upload_logger(&mut uploader);
+        uploader
+    }
+
+    fn stream_uploader(
+        &self,
+        size: u64,
+        progress: Option<PyObject>,
+    ) -> qiniu_sdk::upload::AutoUploader {
+        let mut builder = self.inner.auto_uploader_builder();
+        if size > STREAM_MULTI_PARTS_THRESHOLD {
+            builder.resumable_policy_provider(ResumablePolicyProvider(Box::new(
+                qiniu_sdk::upload::AlwaysMultiParts,
+            )));
+        } else {
+            builder.resumable_policy_provider(ResumablePolicyProvider(Box::new(
+                qiniu_sdk::upload::AlwaysSinglePart,
+            )));
+        }
+        let mut uploader = builder.build();
+        if let Some(progress) = progress {
+            uploader.on_upload_progress(on_upload_progress(progress));
+        }
+        self.attach_multi_parts_upload_logger(&mut uploader);
+        uploader
+    }
+
+    fn chunks_uploader_scheduler(
+        &self,
+        chunk_size: u64,
+    ) -> PyResult<Box<dyn qiniu_sdk::upload::MultiPartsUploaderScheduler<Sha1>>> {
+        if chunk_size == 0 || chunk_size % MIN_CHUNK_SIZE != 0 {
+            return Err(PyValueError::new_err(format!(
+                "chunk_size must be a positive multiple of {} bytes",
+                MIN_CHUNK_SIZE
+            )));
+        }
+        let mut uploader = self
+            .inner
+            .multi_parts_v2_uploader(ResumableRecorder(Box::new(
+                qiniu_sdk::upload::DummyResumableRecorder::new(),
+            )));
+        self.attach_multi_parts_upload_logger(&mut uploader);
+        Ok(Box::new(
+            qiniu_sdk::upload::SerialMultiPartsUploaderScheduler::new(uploader),
+        ))
+    }
+
+    fn attach_upload_logger(&self, uploader: &mut impl UploaderWithCallbacks) {
+        if let Some(upload_logger) = &self.upload_logger {
+            UploadLogger::new(upload_logger.to_owned()).attach(uploader);
+        }
+    }
+
+    fn attach_multi_parts_upload_logger(
+        &self,
+        uploader: &mut impl MultiPartsUploaderWithCallbacks,
+    ) {
+        if let Some(upload_logger) = &self.upload_logger {
+            let upload_logger = UploadLogger::new(upload_logger.to_owned());
+            upload_logger.attach(uploader);
+            uploader.on_part_uploaded(upload_logger.on_part_uploaded());
+        }
+    }
+}
+
+/// 上传管理器重新创建 HTTP 客户端时使用的选项
+#[derive(Debug, Clone, Default)]
+struct UploadHttpClientOptions {
+    selector_config: Option<SelectorConfig>,
+    max_retry: Option<usize>,
+    retry_backoff: Option<Backoff>,
+}
+
+#[derive(Debug, Clone)]
+struct UploadManagerConfig {
+    signer: UploadTokenSigner,
+    http_client: Option<HttpClient>,
+    use_https: Option<bool>,
+    queryer: Option<qiniu_sdk::http_client::BucketRegionsQueryer>,
+    uc_endpoints: Option<qiniu_sdk::http_client::Endpoints>,
+}
+
+impl UploadManagerConfig {
+    fn build(
+        &self,
+        http_client: Option<qiniu_sdk::http_client::HttpClient>,
+    ) -> (
+        qiniu_sdk::upload::UploadManager,
+        Option<qiniu_sdk::objects::Bucket>,
+    ) {
+        let bucket = self.signer.1.to_owned().map(|(credential, bucket_name)| {
+            let mut builder = qiniu_sdk::objects::ObjectsManager::builder(credential);
+            if let Some(http_client) = http_client.to_owned() {
+                builder.http_client(http_client);
+            }
+            if let Some(use_https) = self.use_https {
+                builder.use_https(use_https);
+            }
+            if let Some(queryer) = self.queryer.to_owned() {
+                builder.queryer(queryer);
+            }
+            if let Some(uc_endpoints) = self.uc_endpoints.to_owned() {
+                builder.uc_endpoints(uc_endpoints);
+            }
+            builder.build().bucket(bucket_name)
+        });
+        let mut builder = qiniu_sdk::upload::UploadManager::builder(self.signer.0.to_owned());
+        if let Some(http_client) = http_client {
+            builder.http_client(http_client);
+        }
+        if let Some(use_https) = self.use_https {
+            builder.use_https(use_https);
+        }
+        if let Some(queryer) = self.queryer.to_owned() {
+            builder.queryer(queryer);
+        }
+        if let Some(uc_endpoints) = self.uc_endpoints.to_owned() {
+            builder.uc_endpoints(uc_endpoints);
+        }
+        (builder.build(), bucket)
+    }
+}
+
+const STREAM_MULTI_PARTS_THRESHOLD: u64 = 1 << 22;
+const MIN_CHUNK_SIZE: u64 = 1 << 20;
+
+/// 分片读取文件，并在持有 GIL 时调用 `on_chunk` 变换每个分片的数据
+///
+/// 每个变换后的数据都将作为一个独立的分片上传，因此变换可以改变数据的长度
+#[derive(Debug, Clone)]
+struct ChunkTransformDataSource {
+    state: Arc<Mutex<ChunkTransformState<File>>>,
+    chunk_size: u64,
+    on_chunk: PyObject,
+}
+
+impl ChunkTransformDataSource {
+    fn new(file: File, chunk_size: u64, on_chunk: PyObject) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ChunkTransformState::new(file))),
+            chunk_size,
+            on_chunk,
+        }
+    }
+}
+
+impl qiniu_sdk::upload::DataSource<Sha1> for ChunkTransformDataSource {
+    fn slice(
+        &self,
+        _size: qiniu_sdk::upload::PartSize,
+    ) -> IoResult<Option<qiniu_sdk::upload::DataSourceReader>> {
+        let mut state = self.state.lock().unwrap();
+        let mut chunk = Vec::new();
+        (&mut state.file)
+            .take(self.chunk_size)
+            .read_to_end(&mut chunk)?;
+        if chunk.is_empty() {
+            return Ok(None);
+        }
+        let part_number = state.part_number + 1;
+        let data = Python::with_gil(|py| {
+            let data = self
+                .on_chunk
+                .call1(py, (part_number, PyBytes::new(py, &chunk)))?;
+            Ok(data.extract::<&PyBytes>(py)?.as_bytes().to_vec())
+        })
+        .map_err(make_io_error_from_py_err)?;
+        let (part_number, offset) = state.advance(data.len())?;
+        Ok(Some(qiniu_sdk::upload::DataSourceReader::unseekable(
+            part_number,
+            data,
+            offset,
+        )))
+    }
+
+    fn source_key(&self) -> IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>> {
+        Ok(None)
+    }
+
+    fn total_size(&self) -> IoResult<Option<u64>> {
+        Ok(None)
+    }
+}
+
+/// 异步分片读取文件，并等待异步函数 `on_chunk` 变换每个分片的数据
+///
+/// 每个变换后的数据都将作为一个独立的分片上传，因此变换可以改变数据的长度
+#[derive(Debug, Clone)]
+struct AsyncChunkTransformDataSource {
+    state: Arc<AsyncMutex<ChunkTransformState<async_std::fs::File>>>,
+    chunk_size: u64,
+    on_chunk: PyObject,
+}
+
+impl AsyncChunkTransformDataSource {
+    fn new(file: async_std::fs::File, chunk_size: u64, on_chunk: PyObject) -> Self {
+        Self {
+            state: Arc::new(AsyncMutex::new(ChunkTransformState::new(file))),
+            chunk_size,
+            on_chunk,
+        }
+    }
+}
+
+impl qiniu_sdk::upload::AsyncDataSource<Sha1> for AsyncChunkTransformDataSource {
+    fn slice(
+        &self,
+        _size: qiniu_sdk::upload::PartSize,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::AsyncDataSourceReader>>>
+    {
+        Box::pin(async move {
+            let mut state = self.state.lock().await;
+            let mut chunk = Vec::new();
+            (&mut state.file)
+                .take(self.chunk_size)
+                .read_to_end(&mut chunk)
+                .await?;
+            if chunk.is_empty() {
+                return Ok(None);
+            }
+            let part_number = state.part_number + 1;
+            let data = async {
+                let future = Python::with_gil(|py| {
+                    pyo3_asyncio::async_std::into_future(
+                        self.on_chunk
+                            .call1(py, (part_number, PyBytes::new(py, &chunk)))?
+                            .as_ref(py),
+                    )
+                })?;
+                let data = future.await?;
+                Python::with_gil(|py| {
+                    Ok::<_, PyErr>(data.extract::<&PyBytes>(py)?.as_bytes().to_vec())
+                })
+            }
+            .await
+            .map_err(make_io_error_from_py_err)?;
+            let (part_number, offset) = state.advance(data.len())?;
+            Ok(Some(qiniu_sdk::upload::AsyncDataSourceReader::unseekable(
+                part_number,
+                data,
+                offset,
+            )))
+        })
+    }
+
+    fn source_key(
+        &self,
+    ) -> futures::future::BoxFuture<IoResult<Option<qiniu_sdk::upload::SourceKey<Sha1>>>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn total_size(&self) -> futures::future::BoxFuture<IoResult<Option<u64>>> {
+        Box::pin(async { Ok(None) })
+    }
+}
+
+/// 记录已经变换的分片编号和变换后数据的总长度
+#[derive(Debug)]
+struct ChunkTransformState<F> {
+    file: F,
+    part_number: usize,
+    offset: u64,
+}
+
+impl<F> ChunkTransformState<F> {
+    fn new(file: F) -> Self {
+        Self {
+            file,
+            part_number: 0,
+            offset: 0,
+        }
+    }
+
+    /// 记录一个长度为 `len` 的变换后分片，返回其分片编号和在对象中的偏移量
+    fn advance(&mut self, len: usize) -> IoResult<(NonZeroUsize, u64)> {
+        if len == 0 {
+            return Err(IoError::new(
+                IoErrorKind::InvalidData,
+                "on_chunk must not return empty data",
+            ));
+        }
+        self.part_number += 1;
+        let offset = self.offset;
+        self.offset += len as u64;
+        Ok((NonZeroUsize::new(self.part_number).unwrap(), offset))
+    }
+}
+
+/// 依次等待 Python 异步生成器产生的数据块，直到生成器抛出 `StopAsyncIteration` 异常
+struct AsyncGeneratorReader(Mutex<Pin<Box<dyn futures::AsyncRead + Send>>>);
+
+impl AsyncGeneratorReader {
+    fn new(gen: PyObject) -> Self {
+        let chunks = futures::stream::try_unfold(gen, |gen| async move {
+            let chunk = async {
+                let future = Python::with_gil(|py| {
+                    pyo3_asyncio::async_std::into_future(gen.as_ref(py).call_method0("__anext__")?)
+                })?;
+                match future.await {
+                    Ok(data) => Python::with_gil(|py| {
+                        Ok(Some(data.extract::<&PyBytes>(py)?.as_bytes().to_vec()))
+                    }),
+                    Err(err)
+                        if Python::with_gil(|py| {
+                            err.is_instance_of::<PyStopAsyncIteration>(py)
+                        }) =>
+                    {
+                        Ok(None)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            .await
+            .map_err(make_io_error_from_py_err)?;
+            IoResult::Ok(chunk.map(|chunk| (chunk, gen)))
+        });
+        Self(Mutex::new(Box::pin(Box::pin(chunks).into_async_read())))
+    }
+}
+
+impl futures::AsyncRead for AsyncGeneratorReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> std::task::Poll<IoResult<usize>> {
+        futures::AsyncRead::poll_read(self.0.lock().unwrap().as_mut(), cx, buf)
+    }
+}
+
+impl std::fmt::Debug for AsyncGeneratorReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncGeneratorReader").finish()
+    }
+}
+
+fn file_etag(path: &Path) -> PyResult<String> {
+    File::open(path)
+        .and_then(qiniu_sdk::etag::etag_of)
+        .map_err(QiniuIoError::from_err)
+}
+
+fn remote_etag_of(stat_result: &serde_json::Value) -> Option<String> {
+    stat_result
+        .get("hash")
+        .and_then(|hash| hash.as_str())
+        .map(ToOwned::to_owned)
+}
+
+fn is_object_not_found_error(err: &qiniu_sdk::http_client::ResponseError) -> bool {
+    matches!(
+        err.kind(),
+        qiniu_sdk::http_client::ResponseErrorKind::StatusCodeError(status_code)
+            if status_code.as_u16() == 612
+    )
+}
+
+fn detect_content_type(path: &Path, magic_bytes: u64) -> PyResult<String> {
+    let mut buf = Vec::new();
+    File::open(path)
+        .and_then(|file| file.take(magic_bytes).read_to_end(&mut buf))
+        .map_err(QiniuIoError::from_err)?;
+    Ok(infer::get(&buf)
+        .map_or(mime::APPLICATION_OCTET_STREAM.as_ref(), |t| t.mime_type())
+        .to_owned())
+}
+
+fn make_content_type_detection_object_params(
+    key: Option<&str>,
+    content_type: &str,
+) -> PyResult<qiniu_sdk::upload::AutoUploaderObjectParams> {
+    make_auto_uploader_object_params(
+        None,
+        key,
+        None,
+        Some(content_type),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+fn make_duplicate_check_object_params(
+    key: &str,
+) -> PyResult<qiniu_sdk::upload::AutoUploaderObjectParams> {
+    make_auto_uploader_object_params(
+        None,
+        Some(key),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+#[derive(Debug)]
+struct DirectoryFile {
+    path: PathBuf,
+    relative_path: String,
+    key: String,
+}
+
+fn collect_directory_files(
+    dir: &Path,
+    key_prefix: &str,
+    file_filter: Option<PyObject>,
+    py: Python<'_>,
+) -> PyResult<Vec<DirectoryFile>> {
+    let mut paths = Vec::new();
+    py.allow_threads(|| walk_directory(dir, &mut paths))
+        .map_err(QiniuIoError::from_err)?;
+    paths.sort();
+    let mut files = Vec::with_capacity(paths.len());
+    for path in paths {
+        let relative_path = path
+            .strip_prefix(dir)
+            .unwrap_or(&path)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if let Some(file_filter) = &file_filter {
+            if !file_filter.call1(py, (&relative_path,))?.is_true(py)? {
+                continue;
+            }
+        }
+        files.push(DirectoryFile {
+            key: format!("{}{}", key_prefix, relative_path),
+            path,
+            relative_path,
+        });
+    }
+    Ok(files)
+}
+
+fn walk_directory(dir: &Path, paths: &mut Vec<PathBuf>) -> IoResult<()> {
+    for entry in dir.read_dir()? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            walk_directory(&path, paths)?;
+        } else if path.is_file() {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+async fn upload_directory_files(
+    uploader: &qiniu_sdk::upload::AutoUploader,
+    files: Vec<DirectoryFile>,
+    concurrency: usize,
+) -> Vec<(DirectoryFile, PyResult<()>)> {
+    futures::stream::iter(files)
+        .map(|file| async move {
+            let result = match make_auto_uploader_object_params(
+                None,
+                Some(&file.key),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ) {
+                Ok(object_params) => uploader
+                    .async_upload_path(&file.path, object_params)
+                    .await
+                    .map(|_| ())
+                    .map_err(|err| QiniuApiCallError::from_err(MaybeOwned::Owned(err))),
+                Err(err) => Err(err),
+            };
+            (file, result)
+        })
+        .buffer_unordered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+fn convert_upload_directory_results(
+    results: Vec<(DirectoryFile, PyResult<()>)>,
+    py: Python<'_>,
+) -> PyResult<PyObject> {
+    let mut succeeded = 0usize;
+    let mut failed = Vec::new();
+    for (file, result) in results {
+        match result {
+            Ok(()) => succeeded += 1,
+            Err(err) if is_unauthorized_error(&err) => return Err(err),
+            Err(err) => {
+                let failure = PyDict::new(py);
+                failure.set_item("path", file.relative_path)?;
+                failure.set_item("key", file.key)?;
+                failure.set_item("error", err.value(py))?;
+                failed.push(failure);
+            }
+        }
+    }
+    let summary = PyDict::new(py);
+    summary.set_item("succeeded", succeeded)?;
+    summary.set_item("failed", failed)?;
+    Ok(summary.into_py(py))
+}
+
+fn make_stream_size_missing_error() -> PyErr {
+    QiniuBodySizeMissingError::new_err("`size` must be passed")
+}
+
+type FormFields = HashMap<String, String>;
+
+fn split_extra_form_fields(extra_fields: FormFields) -> PyResult<(FormFields, FormFields)> {
+    const METADATA_PREFIX: &str = "x-qn-meta-";
+    const CUSTOM_VAR_PREFIX: &str = "x:";
+
+    let mut metadata = HashMap::new();
+    let mut custom_vars = HashMap::new();
+    for (key, value) in extra_fields.into_iter() {
+        if let Some(key) = key.strip_prefix(METADATA_PREFIX) {
+            metadata.insert(key.to_owned(), value);
+        } else if let Some(key) = key.strip_prefix(CUSTOM_VAR_PREFIX) {
+            custom_vars.insert(key.to_owned(), value);
+        } else {
+            return Err(QiniuUnsupportedTypeError::new_err(format!(
+                "Unsupported form field: {}",
+                key
+            )));
+        }
+    }
+    Ok((metadata, custom_vars))
 }
 
 /// 表单上传器
@@ -2313,6 +3457,16 @@ impl ConcurrentMultiPartsUploaderScheduler {
     }
 }
 
+/// 进程级别的默认区域信息获取接口，上传时未指定区域信息获取接口则使用该接口
+static DEFAULT_REGIONS_PROVIDER: Lazy<Mutex<Option<RegionsProvider>>> = Lazy::new(Default::default);
+
+fn default_regions_provider() -> Option<RegionsProvider> {
+    DEFAULT_REGIONS_PROVIDER
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .to_owned()
+}
+
 fn make_object_params(
     region_provider: Option<RegionsProvider>,
     object_name: Option<&str>,
@@ -2323,7 +3477,7 @@ fn make_object_params(
     uploaded_part_ttl_secs: Option<u64>,
 ) -> PyResult<qiniu_sdk::upload::ObjectParams> {
     let mut builder = qiniu_sdk::upload::ObjectParams::builder();
-    if let Some(region_provider) = region_provider {
+    if let Some(region_provider) = region_provider.or_else(default_regions_provider) {
         builder.region_provider(region_provider);
     }
     if let Some(object_name) = object_name {
@@ -2439,6 +3593,172 @@ impl UploadedPartInfo {
     }
 }
 
+/// 上传日志条目
+///
+/// 记录一次上传请求使用的服务器地址、重试次数以及错误信息，或是一个上传成功的分片的编号、偏移量和大小
+#[pyclass]
+#[derive(Clone, Debug)]
+struct UploadLogEntry {
+    server_ip: Option<IpAddr>,
+    server_port: Option<NonZeroU16>,
+    part_number: Option<u64>,
+    part_offset: Option<u64>,
+    part_size: Option<u64>,
+    retry_count: usize,
+    error: Option<String>,
+    success: bool,
+}
+
+#[pymethods]
+impl UploadLogEntry {
+    /// 获取本次请求的服务器终端地址
+    #[getter]
+    fn get_endpoint(&self) -> Option<Endpoint> {
+        self.server_ip.map(|server_ip| {
+            qiniu_sdk::http_client::Endpoint::from(qiniu_sdk::http_client::IpAddrWithPort::new(
+                server_ip,
+                self.server_port,
+            ))
+            .into()
+        })
+    }
+
+    /// 获取本次请求的服务器 IP 地址
+    #[getter]
+    fn get_server_ip(&self) -> Option<String> {
+        self.server_ip.map(|ip| ip.to_string())
+    }
+
+    /// 获取本次请求的服务器端口号
+    #[getter]
+    fn get_server_port(&self) -> Option<u16> {
+        self.server_port.map(|port| port.get())
+    }
+
+    /// 获取上传成功的分片编号，从 1 开始
+    ///
+    /// 按分片偏移量和已上传分片的最大大小推算，无法推算时返回 `None`
+    #[getter]
+    fn get_part_number(&self) -> Option<u64> {
+        self.part_number
+    }
+
+    /// 获取上传成功的分片在数据中的偏移量
+    #[getter]
+    fn get_part_offset(&self) -> Option<u64> {
+        self.part_offset
+    }
+
+    /// 获取上传成功的分片大小
+    #[getter]
+    fn get_part_size(&self) -> Option<u64> {
+        self.part_size
+    }
+
+    /// 获取本次请求前在当前终端地址上已经重试的次数
+    ///
+    /// 仅请求失败的条目记录重试次数，其他条目总是返回 0
+    #[getter]
+    fn get_retry_count(&self) -> usize {
+        self.retry_count
+    }
+
+    /// 获取错误信息
+    #[getter]
+    fn get_error(&self) -> Option<String> {
+        self.error.to_owned()
+    }
+
+    /// 本次尝试是否成功
+    #[getter]
+    fn get_success(&self) -> bool {
+        self.success
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn __str__(&self) -> String {
+        self.__repr__()
+    }
+}
+
+#[derive(Clone)]
+struct UploadLogger {
+    callback: PyObject,
+    max_part_size: Arc<AtomicU64>,
+}
+
+impl UploadLogger {
+    fn new(callback: PyObject) -> Self {
+        Self {
+            callback,
+            max_part_size: Default::default(),
+        }
+    }
+
+    fn attach(&self, uploader: &mut impl UploaderWithCallbacks) {
+        let logger = self.to_owned();
+        uploader.on_response_ok(move |parts| {
+            logger.log(UploadLogEntry {
+                server_ip: parts.server_ip(),
+                server_port: parts.server_port(),
+                part_number: None,
+                part_offset: None,
+                part_size: None,
+                retry_count: 0,
+                error: None,
+                success: true,
+            })
+        });
+        let logger = self.to_owned();
+        uploader.on_response_error(move |error| {
+            logger.log(UploadLogEntry {
+                server_ip: error.server_ip(),
+                server_port: error.server_port(),
+                part_number: None,
+                part_offset: None,
+                part_size: None,
+                retry_count: error
+                    .retried()
+                    .map_or(0, |retried| retried.retried_on_current_endpoint()),
+                error: Some(error.to_string()),
+                success: false,
+            })
+        });
+    }
+
+    fn on_part_uploaded(
+        &self,
+    ) -> impl Fn(&dyn UploadedPart) -> AnyResult<()> + Send + Sync + 'static {
+        let logger = self.to_owned();
+        move |part| {
+            let part_size = part.size().get();
+            let max_part_size = logger
+                .max_part_size
+                .fetch_max(part_size, Ordering::SeqCst)
+                .max(part_size);
+            logger.log(UploadLogEntry {
+                server_ip: None,
+                server_port: None,
+                part_number: (part.offset() % max_part_size == 0)
+                    .then(|| part.offset() / max_part_size + 1),
+                part_offset: Some(part.offset()),
+                part_size: Some(part_size),
+                retry_count: 0,
+                error: None,
+                success: true,
+            })
+        }
+    }
+
+    fn log(&self, entry: UploadLogEntry) -> AnyResult<()> {
+        Python::with_gil(|py| self.callback.call1(py, (entry,)))?;
+        Ok(())
+    }
+}
+
 fn on_upload_progress(
     callback: PyObject,
 ) -> impl Fn(&qiniu_sdk::upload::UploadingProgressInfo) -> AnyResult<()> + Send + Sync + 'static {
@@ -2793,7 +4113,7 @@ fn make_auto_uploader_object_params(
     multi_parts_uploader_prefer: Option<MultiPartsUploaderPrefer>,
 ) -> PyResult<qiniu_sdk::upload::AutoUploaderObjectParams> {
     let mut builder = qiniu_sdk::upload::AutoUploaderObjectParams::builder();
-    if let Some(region_provider) = region_provider {
+    if let Some(region_provider) = region_provider.or_else(default_regions_provider) {
         builder.region_provider(region_provider);
     }
     if let Some(object_name) = object_name {
@@ -1,13 +1,20 @@
 use super::{
     credential::CredentialProvider,
     exceptions::{
-        QiniuBase64Error, QiniuCallbackError, QiniuIoError, QiniuJsonError, QiniuTimeError,
+        QiniuApiCallError, QiniuBase64Error, QiniuCallbackError, QiniuInvalidObjectSize,
+        QiniuInvalidURLError, QiniuIoError, QiniuJsonError, QiniuTimeError,
         QiniuUploadTokenFormatError,
     },
-    utils::{convert_json_value_to_py_object, convert_py_any_to_json_value},
+    utils::{convert_json_value_to_py_object, convert_py_any_to_json_value, parse_uri},
 };
 use anyhow::Result as AnyResult;
-use pyo3::prelude::*;
+use maybe_owned::MaybeOwned;
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    pyclass::CompareOp,
+    types::{PyDict, PyTuple},
+};
 use qiniu_sdk::{
     prelude::UploadTokenProviderExt,
     upload_token::{
@@ -17,8 +24,9 @@ use qiniu_sdk::{
 };
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{hash_map::DefaultHasher, HashMap},
     future::Future,
+    hash::{Hash, Hasher},
     mem::transmute,
     pin::Pin,
     time::{Duration, SystemTime},
@@ -37,6 +45,7 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<FromUploadPolicy>()?;
     m.add_class::<BucketUploadTokenProvider>()?;
     m.add_class::<ObjectUploadTokenProvider>()?;
+    m.add_class::<UploadToken>()?;
     Ok(m)
 }
 
@@ -106,6 +115,31 @@ impl UploadPolicy {
         )
     }
 
+    /// 生成仅允许上传指定对象的上传策略，上传凭证将在 `expires_in` 秒后过期
+    #[staticmethod]
+    #[args(expires_in = "3600")]
+    #[pyo3(text_signature = "(bucket, key, /, expires_in = 3600)")]
+    fn for_object(bucket: &str, key: &str, expires_in: u64) -> PyResult<Self> {
+        Ok(UploadPolicyBuilder::new_for_object(bucket, key, expires_in, None)?.build())
+    }
+
+    /// 生成允许上传任意对象到指定存储空间的上传策略，上传凭证将在 `expires_in` 秒后过期
+    #[staticmethod]
+    #[args(expires_in = "3600")]
+    #[pyo3(text_signature = "(bucket, /, expires_in = 3600)")]
+    fn for_bucket(bucket: &str, expires_in: u64) -> PyResult<Self> {
+        Ok(UploadPolicyBuilder::new_for_bucket(bucket, expires_in, None)?.build())
+    }
+
+    /// 为指定的存储空间创建上传策略构建器
+    ///
+    /// 默认的上传凭证有效期为 1 小时，所有设置方法均返回构建器本身，可以链式调用
+    #[staticmethod]
+    #[pyo3(text_signature = "(bucket)")]
+    fn builder(bucket: &str) -> PyResult<UploadPolicyBuilder> {
+        UploadPolicyBuilder::new_for_bucket(bucket, 3600, None)
+    }
+
     /// 解析 JSON 格式的上传凭证
     #[staticmethod]
     #[pyo3(text_signature = "(json)")]
@@ -254,6 +288,55 @@ impl UploadPolicy {
         self.0.as_json()
     }
 
+    /// 合并两个上传策略，返回新的上传策略
+    ///
+    /// `other` 中的字段优先，而 `mimeLimit` 字段则会被拼接。
+    /// 如果两个上传策略的存储空间不一致，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, other)")]
+    fn merge(&self, other: &UploadPolicy) -> PyResult<Self> {
+        const MIME_LIMIT_KEY: &str = "mimeLimit";
+
+        let mut merged: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&self.0.as_json()).map_err(QiniuJsonError::from_err)?;
+        let other: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&other.0.as_json()).map_err(QiniuJsonError::from_err)?;
+        if let (Some(bucket), Some(other_bucket)) =
+            (self.0.bucket(), bucket_from_policy_json(&other))
+        {
+            if bucket != other_bucket {
+                return Err(QiniuApiCallError::from_err(MaybeOwned::Owned(
+                    qiniu_sdk::http_client::ResponseError::new(
+                        qiniu_sdk::http::ResponseErrorKind::UnknownError.into(),
+                        format!(
+                            "Cannot merge upload policies for different buckets: {} and {}",
+                            bucket, other_bucket
+                        ),
+                    ),
+                )));
+            }
+        }
+        for (key, value) in other {
+            match (key.as_str(), merged.get(&key), &value) {
+                (
+                    MIME_LIMIT_KEY,
+                    Some(serde_json::Value::String(mime_limit)),
+                    serde_json::Value::String(other_mime_limit),
+                ) => {
+                    let mime_limit = format!("{};{}", mime_limit, other_mime_limit);
+                    merged.insert(key, mime_limit.into());
+                }
+                _ => {
+                    merged.insert(key, value);
+                }
+            }
+        }
+        let policy = qiniu_sdk::upload_token::UploadPolicy::from_json(
+            serde_json::Value::Object(merged).to_string(),
+        )
+        .map_err(QiniuJsonError::from_err)?;
+        Ok(UploadPolicy(policy))
+    }
+
     /// 根据指定的上传策略字段获取相应的值
     #[pyo3(text_signature = "($self, key)")]
     fn get(&self, key: &str) -> PyResult<Option<PyObject>> {
@@ -303,39 +386,48 @@ macro_rules! impl_upload_policy_builder {
         impl $name {
             /// 指定上传凭证有效期
             #[pyo3(text_signature = "($self, lifetime)")]
-            fn token_lifetime(&mut self, lifetime_secs: u64) {
-                self.0.token_lifetime(Duration::from_secs(lifetime_secs));
+            fn token_lifetime(
+                mut slf: PyRefMut<'_, Self>,
+                lifetime_secs: u64,
+            ) -> PyRefMut<'_, Self> {
+                slf.0.token_lifetime(Duration::from_secs(lifetime_secs));
+                slf
             }
 
             /// 指定上传凭证过期时间
             #[pyo3(text_signature = "($self, deadline)")]
-            fn token_deadline(&mut self, timestamp: u64) {
-                self.0
+            fn token_deadline(mut slf: PyRefMut<'_, Self>, timestamp: u64) -> PyRefMut<'_, Self> {
+                slf.0
                     .token_deadline(SystemTime::UNIX_EPOCH + Duration::from_secs(timestamp));
+                slf
             }
 
             /// 仅允许创建新的对象，不允许覆盖和修改同名对象
             #[pyo3(text_signature = "($self)")]
-            fn insert_only(&mut self) {
-                self.0.insert_only();
+            fn insert_only(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+                slf.0.insert_only();
+                slf
             }
 
             /// 启用 MIME 类型自动检测
             #[pyo3(text_signature = "($self)")]
-            fn enable_mime_detection(&mut self) {
-                self.0.enable_mime_detection();
+            fn enable_mime_detection(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+                slf.0.enable_mime_detection();
+                slf
             }
 
             /// 禁用 MIME 类型自动检测
             #[pyo3(text_signature = "($self)")]
-            fn disable_mime_detection(&mut self) {
-                self.0.disable_mime_detection();
+            fn disable_mime_detection(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+                slf.0.disable_mime_detection();
+                slf
             }
 
             /// 设置文件类型
             #[pyo3(text_signature = "($self, file_type)")]
-            fn file_type(&mut self, file_type: u8) {
-                self.0.file_type(FileType::from(file_type));
+            fn file_type(mut slf: PyRefMut<'_, Self>, file_type: u8) -> PyRefMut<'_, Self> {
+                slf.0.file_type(FileType::from(file_type));
+                slf
             }
 
             /// Web 端文件上传成功后，浏览器执行 303 跳转的 URL
@@ -345,13 +437,15 @@ macro_rules! impl_upload_policy_builder {
             /// `<queryString>` 包含 `return_body()` 内容。
             /// 如不设置 `return_url`，则直接将 `return_body()` 的内容返回给客户端
             #[pyo3(text_signature = "($self, url)")]
-            fn return_url(&mut self, url: &str) {
-                self.0.return_url(url);
+            fn return_url(mut slf: PyRefMut<'_, Self>, url: &str) -> PyRefMut<'_, Self> {
+                slf.0.return_url(url);
+                slf
             }
 
             #[pyo3(text_signature = "($self, body)")]
-            fn return_body(&mut self, body: &str) {
-                self.0.return_body(body);
+            fn return_body(mut slf: PyRefMut<'_, Self>, body: &str) -> PyRefMut<'_, Self> {
+                slf.0.return_body(body);
+                slf
             }
 
             /// 上传成功后，自定义七牛云最终返回给上传端（在指定 `return_url()` 时是携带在跳转路径参数中）的数据
@@ -361,8 +455,15 @@ macro_rules! impl_upload_policy_builder {
             /// 例如 `{"key": $(key), "hash": $(etag), "w": $(imageInfo.width), "h": $(imageInfo.height)}`
             #[args(host = "\"\"", body = "\"\"", body_type = "\"\"")]
             #[pyo3(text_signature = "($self, urls, host = '', body = '', body_type = '')")]
-            fn callback(&mut self, urls: Vec<String>, host: &str, body: &str, body_type: &str) {
-                self.0.callback(urls, host, body, body_type);
+            fn callback(
+                mut slf: PyRefMut<'_, Self>,
+                urls: Vec<String>,
+                host: &str,
+                body: &str,
+                body_type: &str,
+            ) -> PyRefMut<'_, Self> {
+                slf.0.callback(urls, host, body, body_type);
+                slf
             }
 
             /// 自定义对象名称
@@ -372,8 +473,13 @@ macro_rules! impl_upload_policy_builder {
             /// `force` 为 `true` 时，将强制按 `save_as` 字段的内容命名
             #[args(force = "false")]
             #[pyo3(text_signature = "($self, save_as, force = False)")]
-            fn save_as(&mut self, save_as: &str, force: bool) {
-                self.0.save_as(save_as, force);
+            fn save_as(
+                mut slf: PyRefMut<'_, Self>,
+                save_as: &str,
+                force: bool,
+            ) -> PyRefMut<'_, Self> {
+                slf.0.save_as(save_as, force);
+                slf
             }
 
             /// 限定上传文件尺寸的范围
@@ -381,19 +487,24 @@ macro_rules! impl_upload_policy_builder {
             /// 单位为字节
             #[args(min = "None", max = "None")]
             #[pyo3(text_signature = "($self, min = None, max = None)")]
-            fn file_size_limitation(&mut self, min: Option<u64>, max: Option<u64>) {
+            fn file_size_limitation(
+                mut slf: PyRefMut<'_, Self>,
+                min: Option<u64>,
+                max: Option<u64>,
+            ) -> PyRefMut<'_, Self> {
                 match (min, max) {
                     (Some(min), Some(max)) => {
-                        self.0.file_size_limitation(min..=max);
+                        slf.0.file_size_limitation(min..=max);
                     }
                     (Some(min), None) => {
-                        self.0.file_size_limitation(min..);
+                        slf.0.file_size_limitation(min..);
                     }
                     (None, Some(max)) => {
-                        self.0.file_size_limitation(..=max);
+                        slf.0.file_size_limitation(..=max);
                     }
                     _ => {}
                 }
+                slf
             }
 
             /// 限定用户上传的文件类型
@@ -401,16 +512,41 @@ macro_rules! impl_upload_policy_builder {
             /// 指定本字段值，七牛服务器会侦测文件内容以判断 MIME 类型，再用判断值跟指定值进行匹配，
             /// 匹配成功则允许上传，匹配失败则返回 403 状态码
             #[pyo3(text_signature = "($self, content_types)")]
-            fn mime_types(&mut self, content_types: Vec<String>) {
-                self.0.mime_types(content_types);
+            fn mime_types(
+                mut slf: PyRefMut<'_, Self>,
+                content_types: Vec<String>,
+            ) -> PyRefMut<'_, Self> {
+                slf.0.mime_types(content_types);
+                slf
             }
 
             /// 对象生命周期
             ///
             /// 单位为秒，但精确到天
             #[pyo3(text_signature = "($self, lifetime)")]
-            fn object_lifetime(&mut self, lifetime_secs: u64) {
-                self.0.object_lifetime(Duration::from_secs(lifetime_secs));
+            fn object_lifetime(
+                mut slf: PyRefMut<'_, Self>,
+                lifetime_secs: u64,
+            ) -> PyRefMut<'_, Self> {
+                slf.0.object_lifetime(Duration::from_secs(lifetime_secs));
+                slf
+            }
+
+            /// 指定上传凭证过期时间，与 `token_deadline()` 相同
+            #[pyo3(text_signature = "($self, deadline)")]
+            fn deadline(mut slf: PyRefMut<'_, Self>, timestamp: u64) -> PyRefMut<'_, Self> {
+                slf.0
+                    .token_deadline(SystemTime::UNIX_EPOCH + Duration::from_secs(timestamp));
+                slf
+            }
+
+            /// 限定上传文件的最大尺寸
+            ///
+            /// 单位为字节
+            #[pyo3(text_signature = "($self, max)")]
+            fn fsize_limit(mut slf: PyRefMut<'_, Self>, max: u64) -> PyRefMut<'_, Self> {
+                slf.0.file_size_limitation(..=max);
+                slf
             }
 
             fn __repr__(&self) -> String {
@@ -429,7 +565,7 @@ macro_rules! impl_upload_policy_builder {
 /// 用于生成上传策略，一旦生成完毕，上传策略将无法被修改
 #[pyclass]
 #[derive(Clone)]
-struct UploadPolicyBuilder(qiniu_sdk::upload_token::UploadPolicyBuilder);
+struct UploadPolicyBuilder(qiniu_sdk::upload_token::UploadPolicyBuilder, String);
 
 #[pymethods]
 impl UploadPolicyBuilder {
@@ -453,7 +589,7 @@ impl UploadPolicyBuilder {
         if let Some(fields) = fields {
             Self::set_builder_from_py_dict(&mut builder, fields)?;
         }
-        Ok(Self(builder))
+        Ok(Self(builder, bucket.to_owned()))
     }
 
     /// 为指定的存储空间和对象名称生成的上传策略
@@ -479,7 +615,7 @@ impl UploadPolicyBuilder {
         if let Some(fields) = fields {
             Self::set_builder_from_py_dict(&mut builder, fields)?;
         }
-        Ok(Self(builder))
+        Ok(Self(builder, bucket.to_owned()))
     }
 
     /// 为指定的存储空间和对象名称前缀生成的上传策略
@@ -505,7 +641,49 @@ impl UploadPolicyBuilder {
         if let Some(fields) = fields {
             Self::set_builder_from_py_dict(&mut builder, fields)?;
         }
-        Ok(Self(builder))
+        Ok(Self(builder, bucket.to_owned()))
+    }
+
+    /// 指定对象名称
+    ///
+    /// 上传客户端只能以该对象名称上传文件
+    #[pyo3(text_signature = "($self, key)")]
+    fn key(mut slf: PyRefMut<'_, Self>, key: &str) -> PyRefMut<'_, Self> {
+        let scope = format!("{}:{}", slf.1, key);
+        slf.0.set("scope".to_owned(), scope.into());
+        slf
+    }
+
+    /// 同时指定持久化处理指令、持久化处理队列和持久化处理结果通知 URL
+    ///
+    /// 如果 `ops` 为空，将抛出 `ValueError` 异常；如果 `notify_url` 不是合法的 HTTP URL，将抛出 `QiniuInvalidURLError` 异常
+    #[pyo3(text_signature = "($self, ops, pipeline, /, notify_url = None)")]
+    #[args(notify_url = "None")]
+    fn with_persistent_ops_and_pipeline(
+        mut slf: PyRefMut<'_, Self>,
+        ops: Vec<String>,
+        pipeline: String,
+        notify_url: Option<String>,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        if ops.is_empty() {
+            return Err(PyValueError::new_err("persistent ops must not be empty"));
+        }
+        if let Some(notify_url) = &notify_url {
+            let uri = parse_uri(notify_url)?;
+            if !matches!(uri.scheme_str(), Some("http") | Some("https")) {
+                return Err(QiniuInvalidURLError::new_err(format!(
+                    "notify url {:?} is not a HTTP URL",
+                    notify_url
+                )));
+            }
+        }
+        slf.0.set("persistentOps".to_owned(), ops.join(";").into());
+        slf.0.set("persistentPipeline".to_owned(), pipeline.into());
+        if let Some(notify_url) = notify_url {
+            slf.0
+                .set("persistentNotifyUrl".to_owned(), notify_url.into());
+        }
+        Ok(slf)
     }
 
     /// 生成上传策略
@@ -513,6 +691,18 @@ impl UploadPolicyBuilder {
     fn build(&mut self) -> UploadPolicy {
         UploadPolicy(self.0.build())
     }
+
+    /// 以当前时间为基准设置上传凭证过期时间，并生成上传策略
+    #[args(expires_in_secs = "3600")]
+    #[pyo3(text_signature = "($self, expires_in_secs = 3600)")]
+    fn build_with_default_deadline(&mut self, expires_in_secs: u64) -> PyResult<UploadPolicy> {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(QiniuTimeError::from_err)?;
+        self.0
+            .token_deadline(SystemTime::UNIX_EPOCH + now + Duration::from_secs(expires_in_secs));
+        Ok(UploadPolicy(self.0.build()))
+    }
 }
 impl_upload_policy_builder!(UploadPolicyBuilder);
 
@@ -552,12 +742,32 @@ impl_upload_policy_builder!(UploadPolicyBuilderRef);
 /// 上传凭证获取接口
 ///
 /// 可以阅读 <https://developer.qiniu.com/kodo/manual/1208/upload-token> 了解七牛安全机制。
+///
+/// 可以继承该类并实现 `get_upload_token()` 方法返回上传凭证字符串，从而实现自定义的上传凭证获取接口。
+/// 如果同时实现了 `async_get_upload_token()` 方法，异步获取上传凭证时将调用该方法。
+/// 子类如果覆盖了 `__init__()` 方法，必须调用 `super().__init__()`
 #[pyclass(subclass)]
+#[pyo3(text_signature = "()")]
 #[derive(Clone, Debug)]
 pub(super) struct UploadTokenProvider(Box<dyn qiniu_sdk::upload_token::UploadTokenProvider>);
 
 #[pymethods]
 impl UploadTokenProvider {
+    #[new]
+    #[args(_args = "*", _kwargs = "**")]
+    fn new(_args: &PyTuple, _kwargs: Option<&PyDict>) -> Self {
+        Self(Box::new(PythonUploadTokenProvider::Uninitialized))
+    }
+
+    #[args(_args = "*", _kwargs = "**")]
+    fn __init__(slf: &PyCell<Self>, _args: &PyTuple, _kwargs: Option<&PyDict>) -> PyResult<()> {
+        if slf.hasattr(GET_UPLOAD_TOKEN)? {
+            let this = slf.py().import("weakref")?.call_method1("ref", (slf,))?;
+            slf.borrow_mut().0 = Box::new(PythonUploadTokenProvider::Weak(this.into()));
+        }
+        Ok(())
+    }
+
     /// 从上传凭证内获取 AccessKey
     #[args(opts = "None")]
     #[pyo3(text_signature = "($self, opts = None)")]
@@ -728,6 +938,145 @@ impl qiniu_sdk::upload_token::UploadTokenProvider for UploadTokenProvider {
     }
 }
 
+const GET_UPLOAD_TOKEN: &str = "get_upload_token";
+const ASYNC_GET_UPLOAD_TOKEN: &str = "async_get_upload_token";
+
+/// 将 Python 子类的 `get_upload_token()` 方法适配为上传凭证获取接口
+///
+/// Python 子类实例自身仅持有弱引用以避免循环引用，被复制后将持有强引用
+#[derive(Debug)]
+enum PythonUploadTokenProvider {
+    Uninitialized,
+    Weak(PyObject),
+    Strong(PyObject),
+}
+
+impl Clone for PythonUploadTokenProvider {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Uninitialized => Self::Uninitialized,
+            Self::Weak(weak) => Python::with_gil(|py| match weak.call0(py) {
+                Ok(this) if !this.is_none(py) => Self::Strong(this),
+                _ => Self::Weak(weak.clone_ref(py)),
+            }),
+            Self::Strong(this) => Self::Strong(this.to_owned()),
+        }
+    }
+}
+
+impl PythonUploadTokenProvider {
+    fn this<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+        match self {
+            Self::Uninitialized => Err(QiniuCallbackError::from_err(anyhow::anyhow!(
+                "UploadTokenProvider is not initialized, `super().__init__()` must be called"
+            ))),
+            Self::Weak(weak) => {
+                let this = weak.as_ref(py).call0()?;
+                if this.is_none() {
+                    Err(QiniuCallbackError::from_err(anyhow::anyhow!(
+                        "UploadTokenProvider has been dropped"
+                    )))
+                } else {
+                    Ok(this)
+                }
+            }
+            Self::Strong(this) => Ok(this.as_ref(py)),
+        }
+    }
+
+    fn get_upload_token(&self) -> PyResult<String> {
+        Python::with_gil(|py| self.this(py)?.call_method0(GET_UPLOAD_TOKEN)?.extract())
+    }
+
+    async fn async_get_upload_token(&self) -> PyResult<String> {
+        let future = Python::with_gil(|py| {
+            let this = self.this(py)?;
+            if this.hasattr(ASYNC_GET_UPLOAD_TOKEN)? {
+                pyo3_asyncio::async_std::into_future(this.call_method0(ASYNC_GET_UPLOAD_TOKEN)?)
+                    .map(Some)
+            } else {
+                Ok(None)
+            }
+        })?;
+        if let Some(future) = future {
+            let token = future.await?;
+            Python::with_gil(|py| token.extract(py))
+        } else {
+            self.get_upload_token()
+        }
+    }
+}
+
+fn parse_upload_token(
+    upload_token: PyResult<String>,
+) -> ParseResult<qiniu_sdk::upload_token::StaticUploadTokenProvider> {
+    let provider = qiniu_sdk::upload_token::StaticUploadTokenProvider::new(
+        upload_token.map_err(|err| ParseError::CallbackError(err.into()))?,
+    );
+    provider.policy(Default::default())?;
+    Ok(provider)
+}
+
+impl qiniu_sdk::upload_token::UploadTokenProvider for PythonUploadTokenProvider {
+    fn access_key(
+        &self,
+        opts: qiniu_sdk::upload_token::GetAccessKeyOptions,
+    ) -> ParseResult<GotAccessKey> {
+        parse_upload_token(self.get_upload_token())?.access_key(opts)
+    }
+
+    fn policy(
+        &self,
+        opts: qiniu_sdk::upload_token::GetPolicyOptions,
+    ) -> ParseResult<GotUploadPolicy> {
+        Ok(parse_upload_token(self.get_upload_token())?
+            .policy(opts)?
+            .into_upload_policy()
+            .into())
+    }
+
+    fn to_token_string(
+        &self,
+        opts: qiniu_sdk::upload_token::ToStringOptions,
+    ) -> ToStringResult<Cow<'_, str>> {
+        let provider = parse_upload_token(self.get_upload_token())
+            .map_err(|err| ToStringError::CallbackError(err.into()))?;
+        Ok(provider.to_token_string(opts)?.into_owned().into())
+    }
+
+    fn async_access_key<'a>(
+        &'a self,
+        opts: qiniu_sdk::upload_token::GetAccessKeyOptions,
+    ) -> Pin<Box<dyn Future<Output = ParseResult<GotAccessKey>> + 'a + Send>> {
+        Box::pin(async move {
+            parse_upload_token(self.async_get_upload_token().await)?.access_key(opts)
+        })
+    }
+
+    fn async_policy<'a>(
+        &'a self,
+        opts: qiniu_sdk::upload_token::GetPolicyOptions,
+    ) -> Pin<Box<dyn Future<Output = ParseResult<GotUploadPolicy>> + 'a + Send>> {
+        Box::pin(async move {
+            Ok(parse_upload_token(self.async_get_upload_token().await)?
+                .policy(opts)?
+                .into_upload_policy()
+                .into())
+        })
+    }
+
+    fn async_to_token_string<'a>(
+        &'a self,
+        opts: qiniu_sdk::upload_token::ToStringOptions,
+    ) -> Pin<Box<dyn Future<Output = ToStringResult<Cow<'a, str>>> + 'a + Send>> {
+        Box::pin(async move {
+            let provider = parse_upload_token(self.async_get_upload_token().await)
+                .map_err(|err| ToStringError::CallbackError(err.into()))?;
+            Ok(provider.to_token_string(opts)?.into_owned().into())
+        })
+    }
+}
+
 fn convert_parse_error_to_py_err(err: ParseError) -> PyErr {
     match err {
         ParseError::CredentialGetError(err) => QiniuIoError::from_err(err),
@@ -742,7 +1091,10 @@ fn convert_parse_error_to_py_err(err: ParseError) -> PyErr {
 fn convert_to_string_error_to_py_err(err: ToStringError) -> PyErr {
     match err {
         ToStringError::CredentialGetError(err) => QiniuIoError::from_err(err),
-        ToStringError::CallbackError(err) => QiniuCallbackError::from_err(err),
+        ToStringError::CallbackError(err) => match err.downcast::<ParseError>() {
+            Ok(err) => convert_parse_error_to_py_err(err),
+            Err(err) => QiniuCallbackError::from_err(err),
+        },
         err => unreachable!("Unrecognized error {:?}", err),
     }
 }
@@ -853,6 +1205,291 @@ impl ObjectUploadTokenProvider {
     }
 }
 
+/// 上传凭证
+///
+/// 已经签名完毕的上传凭证，可以直接作为上传凭证获取接口使用
+#[pyclass(extends = UploadTokenProvider)]
+#[pyo3(text_signature = "(upload_token)")]
+pub(super) struct UploadToken;
+
+#[pymethods]
+impl UploadToken {
+    #[new]
+    fn new(upload_token: &str) -> (Self, UploadTokenProvider) {
+        (
+            Self,
+            UploadTokenProvider(Box::new(
+                qiniu_sdk::upload_token::StaticUploadTokenProvider::new(upload_token),
+            )),
+        )
+    }
+
+    /// 使用认证信息对上传策略签名，生成上传凭证
+    ///
+    /// 如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(policy, credential)")]
+    fn from_policy_and_credential(
+        policy: UploadPolicy,
+        credential: CredentialProvider,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        let upload_token = py.allow_threads(|| Self::sign(policy.0, credential))?;
+        Py::new(py, Self::new(&upload_token))
+    }
+
+    /// 使用 AccessKey 和 SecretKey 对上传策略签名，生成上传凭证
+    ///
+    /// 相当于 `FromUploadPolicy(policy, StaticCredentialProvider(access_key, secret_key)).to_token_string()`
+    #[staticmethod]
+    #[pyo3(text_signature = "(policy, access_key, secret_key)")]
+    fn sign_policy_and_create(
+        policy: UploadPolicy,
+        access_key: String,
+        secret_key: String,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        let upload_token = py.allow_threads(|| {
+            Self::sign(
+                policy.0,
+                CredentialProvider::new_static(access_key, secret_key),
+            )
+        })?;
+        Self::from_token_string(&upload_token, py)
+    }
+
+    /// 生成允许上传任意对象到指定存储空间的上传凭证，适用于批量上传
+    ///
+    /// 上传凭证有效期为 `expires_in` 秒，`max_file_size` 限定上传文件的最大尺寸，单位为字节。
+    /// 如果 `max_file_size` 不是正数，将抛出 `QiniuInvalidObjectSize` 异常；
+    /// 如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[staticmethod]
+    #[pyo3(text_signature = "(bucket, credential, /, expires_in = 3600, max_file_size = None)")]
+    #[args(expires_in = "3600", max_file_size = "None")]
+    fn for_batch_upload(
+        bucket: &str,
+        credential: CredentialProvider,
+        expires_in: u64,
+        max_file_size: Option<i64>,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        let mut builder = qiniu_sdk::upload_token::UploadPolicy::new_for_bucket(
+            bucket,
+            Duration::from_secs(expires_in),
+        );
+        if let Some(max_file_size) = max_file_size {
+            let max_file_size = u64::try_from(max_file_size)
+                .ok()
+                .filter(|&size| size > 0)
+                .ok_or_else(|| QiniuInvalidObjectSize::new_err("max_file_size must be positive"))?;
+            builder.file_size_limitation(..=max_file_size);
+        }
+        let policy = builder.build();
+        let upload_token = py.allow_threads(|| Self::sign(policy, credential))?;
+        Self::from_token_string(&upload_token, py)
+    }
+
+    /// 刷新上传凭证，返回新的上传凭证
+    ///
+    /// 保持原有上传策略不变，将过期时间更新为 `new_expires_in` 秒后，并使用认证信息重新签名。
+    /// 如果无法解析原有上传凭证的上传策略，将抛出 `QiniuUploadTokenFormatError` 异常；
+    /// 如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, credential_provider, /, new_expires_in = 3600)")]
+    #[args(new_expires_in = "3600")]
+    fn refresh(
+        slf: PyRef<'_, Self>,
+        credential_provider: CredentialProvider,
+        new_expires_in: u64,
+        py: Python<'_>,
+    ) -> PyResult<Py<Self>> {
+        let provider: &UploadTokenProvider = slf.as_ref();
+        let upload_token = py.allow_threads(|| {
+            let policy = provider
+                .0
+                .policy(Default::default())
+                .map_err(QiniuUploadTokenFormatError::from_err)?
+                .into_upload_policy();
+            Self::sign(
+                refresh_upload_policy(policy, new_expires_in)?,
+                credential_provider,
+            )
+        })?;
+        Self::from_token_string(&upload_token, py)
+    }
+
+    /// 异步刷新上传凭证，返回新的上传凭证
+    ///
+    /// 保持原有上传策略不变，将过期时间更新为 `new_expires_in` 秒后，并使用认证信息重新签名。
+    /// 如果无法解析原有上传凭证的上传策略，将抛出 `QiniuUploadTokenFormatError` 异常；
+    /// 如果获取认证信息失败，将抛出 `QiniuApiCallError` 异常
+    #[pyo3(text_signature = "($self, credential_provider, /, new_expires_in = 3600)")]
+    #[args(new_expires_in = "3600")]
+    fn async_refresh<'p>(
+        slf: PyRef<'_, Self>,
+        credential_provider: CredentialProvider,
+        new_expires_in: u64,
+        py: Python<'p>,
+    ) -> PyResult<&'p PyAny> {
+        let provider = slf.as_ref().0.to_owned();
+        pyo3_asyncio::async_std::future_into_py(py, async move {
+            let policy = provider
+                .async_policy(Default::default())
+                .await
+                .map_err(QiniuUploadTokenFormatError::from_err)?
+                .into_upload_policy();
+            let upload_token = Self::async_sign(
+                refresh_upload_policy(policy, new_expires_in)?,
+                credential_provider,
+            )
+            .await?;
+            Python::with_gil(|py| Self::from_token_string(&upload_token, py))
+        })
+    }
+
+    /// 获取上传凭证过期时间的 Unix 时间戳
+    ///
+    /// 如果上传凭证没有指定过期时间，返回 `None`
+    #[pyo3(text_signature = "($self)")]
+    fn get_deadline_timestamp(slf: PyRef<'_, Self>, py: Python<'_>) -> PyResult<Option<f64>> {
+        Self::deadline_timestamp(slf.as_ref(), py)
+    }
+
+    /// 获取上传凭证剩余的有效时长
+    ///
+    /// 单位为秒，如果上传凭证已经过期，则返回负数；如果上传凭证没有指定过期时间，返回 `None`
+    #[pyo3(text_signature = "($self)")]
+    fn get_remaining_lifetime(slf: PyRef<'_, Self>, py: Python<'_>) -> PyResult<Option<f64>> {
+        let deadline = Self::deadline_timestamp(slf.as_ref(), py)?;
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_err(QiniuTimeError::from_err)?;
+        Ok(deadline.map(|deadline| deadline - now.as_secs_f64()))
+    }
+
+    fn __richcmp__(
+        slf: PyRef<'_, Self>,
+        other: PyRef<'_, Self>,
+        op: CompareOp,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let equals = || -> PyResult<bool> {
+            Ok(Self::token_string(slf.as_ref(), py)? == Self::token_string(other.as_ref(), py)?)
+        };
+        match op {
+            CompareOp::Eq => Ok(equals()?.to_object(py)),
+            CompareOp::Ne => Ok((!equals()?).to_object(py)),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __hash__(slf: PyRef<'_, Self>, py: Python<'_>) -> PyResult<u64> {
+        let mut hasher = DefaultHasher::new();
+        Self::token_string(slf.as_ref(), py)?.hash(&mut hasher);
+        Ok(hasher.finish())
+    }
+
+    /// 返回上传凭证字符串的字节长度
+    fn __len__(slf: PyRef<'_, Self>, py: Python<'_>) -> PyResult<usize> {
+        Ok(Self::token_string(slf.as_ref(), py)?.len())
+    }
+}
+
+impl UploadToken {
+    pub(super) fn from_token_string(upload_token: &str, py: Python<'_>) -> PyResult<Py<Self>> {
+        Py::new(py, Self::new(upload_token))
+    }
+
+    /// 使用认证信息对上传策略签名，获取认证信息失败将抛出 `QiniuApiCallError` 异常
+    pub(super) fn sign(
+        policy: qiniu_sdk::upload_token::UploadPolicy,
+        credential: CredentialProvider,
+    ) -> PyResult<String> {
+        qiniu_sdk::upload_token::FromUploadPolicy::new(policy, credential)
+            .to_token_string(Default::default())
+            .map(|token| token.into_owned())
+            .map_err(convert_sign_error_to_py_err)
+    }
+
+    /// 使用认证信息对上传策略异步签名，获取认证信息失败将抛出 `QiniuApiCallError` 异常
+    pub(super) async fn async_sign(
+        policy: qiniu_sdk::upload_token::UploadPolicy,
+        credential: CredentialProvider,
+    ) -> PyResult<String> {
+        qiniu_sdk::upload_token::FromUploadPolicy::new(policy, credential)
+            .async_to_token_string(Default::default())
+            .await
+            .map(|token| token.into_owned())
+            .map_err(convert_sign_error_to_py_err)
+    }
+
+    fn token_string(provider: &UploadTokenProvider, py: Python<'_>) -> PyResult<String> {
+        provider.to_token_string(None, py)
+    }
+
+    fn deadline_timestamp(provider: &UploadTokenProvider, py: Python<'_>) -> PyResult<Option<f64>> {
+        py.allow_threads(|| provider.0.policy(Default::default()))
+            .map_err(convert_parse_error_to_py_err)?
+            .into_upload_policy()
+            .token_deadline()
+            .map(|deadline| {
+                deadline
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map(|duration| duration.as_secs_f64())
+            })
+            .transpose()
+            .map_err(QiniuTimeError::from_err)
+    }
+}
+
+/// 为存储空间生成上传策略，如果指定了 `key`，则仅允许以该对象名称上传
+pub(super) fn make_upload_policy(
+    bucket: &str,
+    key: Option<&str>,
+    expires_in: u64,
+    fields: Option<HashMap<String, PyObject>>,
+) -> PyResult<qiniu_sdk::upload_token::UploadPolicy> {
+    let expires_in = Duration::from_secs(expires_in);
+    let mut builder = if let Some(key) = key {
+        qiniu_sdk::upload_token::UploadPolicy::new_for_object(bucket, key, expires_in)
+    } else {
+        qiniu_sdk::upload_token::UploadPolicy::new_for_bucket(bucket, expires_in)
+    };
+    if let Some(fields) = fields {
+        UploadPolicyBuilder::set_builder_from_py_dict(&mut builder, fields)?;
+    }
+    Ok(builder.build())
+}
+
+fn refresh_upload_policy(
+    policy: qiniu_sdk::upload_token::UploadPolicy,
+    new_expires_in: u64,
+) -> PyResult<qiniu_sdk::upload_token::UploadPolicy> {
+    const DEADLINE_KEY: &str = "deadline";
+
+    let deadline = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_err(QiniuTimeError::from_err)?
+        .as_secs()
+        + new_expires_in;
+    let mut policy: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(&policy.as_json()).map_err(QiniuJsonError::from_err)?;
+    policy.insert(DEADLINE_KEY.to_owned(), deadline.into());
+    qiniu_sdk::upload_token::UploadPolicy::from_json(serde_json::Value::Object(policy).to_string())
+        .map_err(QiniuJsonError::from_err)
+}
+
+fn convert_sign_error_to_py_err(err: ToStringError) -> PyErr {
+    match err {
+        ToStringError::CredentialGetError(err) => QiniuApiCallError::from_err(MaybeOwned::Owned(
+            qiniu_sdk::http_client::ResponseError::new(
+                qiniu_sdk::http::ResponseErrorKind::LocalIoError.into(),
+                err,
+            ),
+        )),
+        err => convert_to_string_error_to_py_err(err),
+    }
+}
+
 pub(super) fn on_policy_generated_callback(
     callback: PyObject,
 ) -> impl Fn(&mut qiniu_sdk::upload_token::UploadPolicyBuilder) -> AnyResult<()> + Sync + Send + 'static
@@ -928,3 +1565,10 @@ impl ToStringOptions {
         self.__repr__()
     }
 }
+
+fn bucket_from_policy_json(policy: &serde_json::Map<String, serde_json::Value>) -> Option<&str> {
+    policy
+        .get("scope")
+        .and_then(|scope| scope.as_str())
+        .map(|scope| scope.split_once(':').map_or(scope, |(bucket, _)| bucket))
+}
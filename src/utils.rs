@@ -1,10 +1,11 @@
 use super::{
     exceptions::{
-        QiniuApiCallErrorInfo, QiniuBodySizeMissingError, QiniuHeaderValueEncodingError,
-        QiniuInvalidDomainWithPortError, QiniuInvalidEndpointError, QiniuInvalidHeaderNameError,
-        QiniuInvalidHeaderValueError, QiniuInvalidIpAddrError, QiniuInvalidIpAddrWithPortError,
-        QiniuInvalidMethodError, QiniuInvalidPortError, QiniuInvalidStatusCodeError,
-        QiniuInvalidURLError, QiniuMimeParseError, QiniuUnsupportedTypeError,
+        QiniuApiCallErrorInfo, QiniuBase64Error, QiniuBodySizeMissingError,
+        QiniuHeaderValueEncodingError, QiniuInvalidDomainWithPortError, QiniuInvalidEndpointError,
+        QiniuInvalidHeaderNameError, QiniuInvalidHeaderValueError, QiniuInvalidIpAddrError,
+        QiniuInvalidIpAddrWithPortError, QiniuInvalidMethodError, QiniuInvalidPortError,
+        QiniuInvalidStatusCodeError, QiniuInvalidURLError, QiniuMimeParseError,
+        QiniuUnsupportedTypeError,
     },
     http_client::{Endpoint, EndpointsProvider, RegionsProvider},
 };
@@ -44,6 +45,7 @@ use std::{
     },
     net::IpAddr,
     num::NonZeroU16,
+    path::PathBuf,
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
@@ -420,7 +422,7 @@ fn extract_bytes_from_py_object(py: Python<'_>, obj: PyObject) -> PyResult<Vec<u
     Ok(bytes)
 }
 
-fn make_io_error_from_py_err(err: PyErr) -> IoError {
+pub(super) fn make_io_error_from_py_err(err: PyErr) -> IoError {
     IoError::new(IoErrorKind::Other, err)
 }
 
@@ -558,9 +560,62 @@ pub(super) fn create_module(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "utils")?;
     m.add_class::<Reader>()?;
     m.add_class::<AsyncReader>()?;
+    m.add_function(wrap_pyfunction!(mime_type_from_path, m)?)?;
+    m.add_function(wrap_pyfunction!(mime_type_from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(base64_url_encode, m)?)?;
+    m.add_function(wrap_pyfunction!(base64_url_decode, m)?)?;
+    m.add_function(wrap_pyfunction!(entry_encode, m)?)?;
     Ok(m)
 }
 
+/// 根据文件路径的扩展名推测 MIME 类型
+///
+/// 无法推测时返回 `application/octet-stream`
+#[pyfunction]
+#[pyo3(text_signature = "(path)")]
+fn mime_type_from_path(path: PathBuf) -> String {
+    mime_guess::from_path(path)
+        .first_or_octet_stream()
+        .to_string()
+}
+
+/// 根据数据的前 512 个字节推测 MIME 类型
+///
+/// 无法推测时返回 `fallback`
+#[pyfunction]
+#[pyo3(text_signature = "(data, /, fallback = 'application/octet-stream')")]
+#[args(fallback = "\"application/octet-stream\"")]
+fn mime_type_from_bytes(data: &[u8], fallback: &str) -> String {
+    const MAGIC_BYTES: usize = 512;
+
+    infer::get(&data[..data.len().min(MAGIC_BYTES)])
+        .map_or(fallback, |t| t.mime_type())
+        .to_owned()
+}
+
+/// 对数据进行 URL 安全的 Base64 编码
+#[pyfunction]
+#[pyo3(text_signature = "(data)")]
+fn base64_url_encode(data: Vec<u8>) -> String {
+    qiniu_sdk::utils::base64::urlsafe(&data)
+}
+
+/// 对 URL 安全的 Base64 编码的字符串进行解码
+#[pyfunction]
+#[pyo3(text_signature = "(s)")]
+fn base64_url_decode<'p>(s: &str, py: Python<'p>) -> PyResult<&'p PyBytes> {
+    let decoded =
+        qiniu_sdk::utils::base64::decode(s.as_bytes()).map_err(QiniuBase64Error::from_err)?;
+    Ok(PyBytes::new(py, &decoded))
+}
+
+/// 生成七牛对象的 Entry 编码，即 `bucket:key` 的 URL 安全的 Base64 编码
+#[pyfunction]
+#[pyo3(text_signature = "(bucket, key)")]
+fn entry_encode(bucket: &str, key: &str) -> String {
+    qiniu_sdk::utils::base64::urlsafe(format!("{}:{}", bucket, key).as_bytes())
+}
+
 #[pyclass]
 pub(super) struct Reader(Box<dyn qiniu_sdk::upload::DynRead>);
 
@@ -1008,3 +1063,11 @@ fn split_seek_from(seek_from: SeekFrom) -> (i64, i64) {
 pub(super) fn convert_api_call_error(error: &PyErr) -> PyResult<QiniuApiCallErrorInfo> {
     Python::with_gil(|py| error.value(py).getattr("args")?.get_item(0i32)?.extract())
 }
+
+pub(super) fn is_unauthorized_error(err: &PyErr) -> bool {
+    matches!(
+        convert_api_call_error(err).map(|info| info.as_ref().kind()),
+        Ok(qiniu_sdk::http_client::ResponseErrorKind::StatusCodeError(status_code))
+            if status_code == qiniu_sdk::http::StatusCode::UNAUTHORIZED
+    )
+}
@@ -1,8 +1,10 @@
-from qiniu_sdk_bindings import credential
+from qiniu_sdk_bindings import credential, QiniuApiCallError, QiniuInvalidURLError
 import unittest
 import aiofiles
 import asyncio
 import io
+import json
+import tempfile
 
 
 class TestCredential(unittest.TestCase):
@@ -11,6 +13,19 @@ class TestCredential(unittest.TestCase):
         self.assertEqual(c.sign(b'hello'),
                          'abcdefghklmnopq:b84KVc-LroDiz0ebUANfdzSRxa0=')
 
+    def test_credential_eq_and_hash(self):
+        c = get_credential()
+        self.assertEqual(c, get_credential())
+        self.assertFalse(c != get_credential())
+        other = credential.Credential(c.access_key(), 'anothersecretkey')
+        self.assertNotEqual(c, other)
+        self.assertEqual(hash(c), hash(other))
+        with self.assertRaises(TypeError):
+            c < other
+        credentials = {c: 'first', other: 'second'}
+        self.assertEqual(credentials[get_credential()], 'first')
+        self.assertEqual(len(credentials), 2)
+
     def test_credential_sign_reader(self):
         c = get_credential()
         reader = io.BytesIO(b'hello')
@@ -26,6 +41,40 @@ class TestCredential(unittest.TestCase):
         self.assertTrue(url.startswith('http://www.qiniu.com/?go=1&e='))
         self.assertTrue('&token=abcdefghklmnopq' in url)
 
+    def test_credential_sign_request(self):
+        c = get_credential()
+        authorization = c.sign_request(
+            'http://upload.qiniup.com/', 'POST', {'Content-Type': 'application/x-www-form-urlencoded'}, b'name=test&language=go')
+        self.assertEqual(
+            authorization, 'QBox abcdefghklmnopq:VlWNSauF13XCI1YGoeGMUC229lI=')
+        authorization = c.sign_request_v2(
+            'http://upload.qiniup.com/', 'GET', {'Content-Type': 'application/json'}, b'{"name":"test"}')
+        self.assertEqual(
+            authorization, 'Qiniu abcdefghklmnopq:vzfDS1LpyLYKU1qLScCAsf74lCk=')
+        with self.assertRaises(QiniuInvalidURLError):
+            c.sign_request('http://upload qiniup.com/', 'GET', {})
+
+    def test_credential_verify_request(self):
+        c = get_credential()
+        headers = {'Content-Type': 'application/x-www-form-urlencoded',
+                   'Authorization': 'QBox abcdefghklmnopq:VlWNSauF13XCI1YGoeGMUC229lI='}
+        self.assertTrue(c.verify_request(
+            'http://upload.qiniup.com/', 'POST', headers, b'name=test&language=go'))
+        self.assertFalse(c.verify_request(
+            'http://upload.qiniup.com/', 'POST', headers, b'name=test&language=rust'))
+        headers = {'Content-Type': 'application/json',
+                   'Authorization': 'Qiniu abcdefghklmnopq:vzfDS1LpyLYKU1qLScCAsf74lCk='}
+        self.assertTrue(c.verify_request(
+            'http://upload.qiniup.com/', 'GET', headers, b'{"name":"test"}'))
+        self.assertFalse(c.verify_request(
+            'http://upload.qiniup.com/', 'POST', headers, b'{"name":"test"}'))
+        self.assertFalse(c.verify_request(
+            'http://upload.qiniup.com/', 'GET', {'Content-Type': 'application/json'}, b'{"name":"test"}'))
+        self.assertFalse(c.verify_request(
+            'http://upload.qiniup.com/', 'GET', {'Authorization': 'Bearer token'}))
+        with self.assertRaises(QiniuInvalidURLError):
+            c.verify_request('http://upload qiniup.com/', 'GET', {})
+
     def test_credential_authorization_v1_for_request(self):
         c = get_credential()
         authorization = c.authorization_v1_for_request(
@@ -92,6 +141,47 @@ class TestCredentialProvider(unittest.TestCase):
         self.assertEqual(cc.get().secret_key(), 'sk_global')
 
 
+    def test_credential_factories(self):
+        c = credential.static_credential('ak_static', 'sk_static')
+        self.assertIsInstance(c, credential.StaticCredentialProvider)
+        self.assertEqual(c.get_access_key(), 'ak_static')
+        self.assertEqual(c.get_secret_key(), '***')
+        self.assertEqual(c.get().secret_key(), 'sk_static')
+
+        credential.EnvCredentialProvider.setup(
+            credential.Credential('ak_env', 'sk_env'))
+        self.assertEqual(credential.credential_from_env().get().access_key(), 'ak_env')
+        credential.EnvCredentialProvider.clear()
+
+        with tempfile.NamedTemporaryFile('w', suffix='.json') as f:
+            json.dump({'access_key': 'ak_file', 'secret_key': 'sk_file'}, f)
+            f.flush()
+            fc = credential.credential_from_file(f.name)
+            self.assertEqual(fc.access_key(), 'ak_file')
+            self.assertEqual(fc.secret_key(), 'sk_file')
+
+        cc = credential.credential_chain([credential.credential_from_env(), c])
+        self.assertEqual(cc.get().access_key(), 'ak_static')
+
+    def test_static_credential_provider(self):
+        p = credential.StaticCredentialProvider('ak_static', 'sk_static')
+        self.assertIsInstance(p, credential.CredentialProvider)
+        self.assertIsInstance(p, credential.StaticCredentialProvider)
+        self.assertEqual(p.get_access_key(), 'ak_static')
+        self.assertEqual(p.get_secret_key(), '***')
+        self.assertNotIn('sk_static', repr(p))
+        self.assertEqual(p.get().secret_key(), 'sk_static')
+
+    def test_credential_provider_get_access_key(self):
+        self.assertEqual(credential.Credential(
+            'ak', 'sk').get_access_key(), 'ak')
+        cc = credential.credential_chain(
+            [credential.StaticCredentialProvider('ak_static', 'sk_static')])
+        self.assertEqual(cc.get_access_key(), 'ak_static')
+        credential.EnvCredentialProvider.clear()
+        with self.assertRaises(QiniuApiCallError):
+            credential.EnvCredentialProvider().get_access_key()
+
 class TestAsyncEtag(unittest.IsolatedAsyncioTestCase):
     async def test_credential_sign_reader(self):
         c = get_credential()
@@ -1,9 +1,13 @@
-from qiniu_sdk_bindings import credential, download, http_client
+from qiniu_sdk_bindings import credential, download, http_client, QiniuDownloadError, QiniuApiCallError, QiniuInvalidDomainWithPortError
 from aiohttp import web
+import array
+import asyncio
 import io
 import unittest
 import secrets
 import aiofiles
+import os
+import tempfile
 
 
 class TestDownloadUrlsGenerator(unittest.TestCase):
@@ -65,5 +69,189 @@ class TestDownloadManager(unittest.IsolatedAsyncioTestCase):
             reader = download_manager.async_reader('fakeobjectname')
             content = await reader.readall()
             self.assertEqual(content, rand_bytes)
+            async with download_manager.async_stream_download('fakeobjectname') as stream:
+                content = await stream.read(1024)
+                content += await stream.read(1 << 62)
+                self.assertEqual(content, rand_bytes)
+                self.assertEqual(await stream.readall(), b'')
+            with self.assertRaises(ValueError):
+                await stream.read()
+            await stream.close()
+
+            def readinto_buffers():
+                with download_manager.stream_download('fakeobjectname') as stream:
+                    buf = bytearray(1024)
+                    n = stream.readinto(buf)
+                    arr = array.array('B', bytes(1024))
+                    m = stream.readinto(memoryview(arr))
+                    with self.assertRaises(TypeError):
+                        stream.readinto(b'readonly')
+                    return bytes(buf[:n]) + arr.tobytes()[:m] + stream.read(1 << 62)
+            self.assertEqual(await asyncio.to_thread(readinto_buffers), rand_bytes)
+            self.assertTrue(await download_manager.async_verify_download('fakeobjectname', 'fakeetag'))
+            self.assertFalse(await download_manager.async_verify_download('fakeobjectname', 'wrongetag'))
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(rand_bytes)
+                await f.flush()
+                self.assertFalse(await download_manager.async_compute_and_verify('fakeobjectname', f.name))
+        finally:
+            await runner.cleanup()
+
+    async def test_verify_empty_object(self):
+        case = self
+
+        async def head_object(request):
+            case.assertEqual(request.method, 'HEAD')
+            if request.match_info['key'] == 'notfound':
+                return web.Response(status=404, headers={'X-Reqid': 'fakereqid'})
+            return web.Response(headers={
+                'Etag': '"Fto5o-5ea0sNMlW_75VgGJCv2AcJ"',
+                'Content-Length': '0',
+                'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.head('/{key}', head_object)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            download_manager = download.DownloadManager(download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False))
+            self.assertTrue(await download_manager.async_verify_download('emptyobject', 'Fto5o-5ea0sNMlW_75VgGJCv2AcJ'))
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                self.assertTrue(await download_manager.async_compute_and_verify('emptyobject', f.name))
+            with self.assertRaises(QiniuDownloadError):
+                await download_manager.async_verify_download('notfound', 'Fto5o-5ea0sNMlW_75VgGJCv2AcJ')
+        finally:
+            await runner.cleanup()
+
+    async def test_head_object(self):
+        case = self
+
+        async def head_object(request):
+            case.assertEqual(request.method, 'HEAD')
+            if request.match_info['key'] == 'notfound':
+                return web.Response(status=404, headers={'X-Reqid': 'fakereqid'})
+            elif request.match_info['key'] == 'forbidden':
+                return web.Response(status=401, headers={'X-Reqid': 'fakereqid'})
+            return web.Response(headers={
+                'Etag': '"fakeetag"',
+                'Content-Type': 'text/plain',
+                'Content-Length': '1024',
+                'Last-Modified': 'Sun, 06 Nov 1994 08:49:37 GMT',
+                'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.head('/{key}', head_object)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            download_manager = download.DownloadManager(download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False))
+            metadata = await download_manager.async_head_object('fakeobjectname')
+            self.assertEqual(metadata['etag'], 'fakeetag')
+            self.assertEqual(metadata['content_type'], 'text/plain')
+            self.assertEqual(metadata['content_length'], 1024)
+            self.assertEqual(metadata['last_modified'], '1994-11-06T08:49:37Z')
+            with self.assertRaises(QiniuDownloadError):
+                await download_manager.async_head_object('notfound')
+            with self.assertRaises(QiniuApiCallError):
+                await download_manager.async_head_object('forbidden')
+        finally:
+            await runner.cleanup()
+
+    async def test_download_range(self):
+        content = secrets.token_bytes(1024)
+
+        async def getfile(request):
+            range = request.http_range
+            if range.start is not None and range.start >= len(content):
+                return web.Response(status=416, headers={'X-Reqid': 'fakereqid'})
+            return web.Response(body=content[range], status=206, headers={'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/fakeobjectname', getfile)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            download_manager = download.DownloadManager(download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False))
+            self.assertEqual(await download_manager.async_download_range('fakeobjectname', 10, 19), content[10:20])
+            self.assertEqual(await download_manager.async_download_range('fakeobjectname', 0, 0), content[:1])
+            self.assertEqual(await download_manager.async_download_suffix('fakeobjectname', 16), content[-16:])
+            with self.assertRaises(ValueError):
+                download_manager.download_range('fakeobjectname', 20, 10)
+            with self.assertRaises(ValueError):
+                download_manager.download_range('fakeobjectname', -1, 10)
+            with self.assertRaises(ValueError):
+                download_manager.download_suffix('fakeobjectname', 0)
+            with self.assertRaises(QiniuDownloadError):
+                await download_manager.async_download_range('fakeobjectname', 2048, 4096)
+        finally:
+            await runner.cleanup()
+
+    async def test_download_manager_domains(self):
+        download_manager = download.DownloadManager(download.StaticDomainsUrlsGenerator(
+            ['domain.com', 'domain2.com:8080'], use_https=False))
+        self.assertEqual(download_manager.list_domains(), ['domain.com', 'domain2.com:8080'])
+        self.assertEqual(download_manager.get_primary_domain(), 'domain.com')
+
+        appended = download_manager.with_additional_domain('cdn.domain.com')
+        self.assertEqual(appended.list_domains(), ['domain.com', 'domain2.com:8080', 'cdn.domain.com'])
+        self.assertEqual(download_manager.list_domains(), ['domain.com', 'domain2.com:8080'])
+        with self.assertRaises(QiniuInvalidDomainWithPortError):
+            download_manager.with_additional_domain('invalid domain')
+
+        removed = appended.remove_domain('domain.com')
+        self.assertEqual(removed.list_domains(), ['domain2.com:8080', 'cdn.domain.com'])
+        self.assertEqual(removed.get_primary_domain(), 'domain2.com:8080')
+        with self.assertRaises(ValueError):
+            removed.remove_domain('domain.com')
+
+    async def test_download_batch(self):
+        contents = {
+            'a/b/c.txt': secrets.token_bytes(1024),
+            'special key+&=.txt': secrets.token_bytes(512),
+            'top.bin': secrets.token_bytes(256),
+        }
+
+        async def getfile(request):
+            key = request.match_info['key']
+            if key not in contents:
+                return web.Response(status=404, headers={'X-Reqid': 'fakereqid'})
+            return web.Response(body=contents[key], headers={'X-Reqid': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/{key:.+}', getfile)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            download_manager = download.DownloadManager(download.StaticDomainsUrlsGenerator(
+                ['127.0.0.1:8089'], use_https=False))
+            progresses = []
+            with tempfile.TemporaryDirectory() as dest_dir:
+                result = await download_manager.async_download_batch(
+                    list(contents.keys()) + ['missing/file.txt'], dest_dir, concurrency=2,
+                    on_progress=lambda completed, total: progresses.append((completed, total)))
+                self.assertEqual(result['succeeded'], 3)
+                self.assertEqual(len(result['failed']), 1)
+                self.assertEqual(result['failed'][0]['key'], 'missing/file.txt')
+                self.assertIsInstance(result['failed'][0]['error'], str)
+                self.assertEqual(progresses, [(1, 4), (2, 4), (3, 4), (4, 4)])
+                for key, content in contents.items():
+                    async with aiofiles.open(os.path.join(dest_dir, *key.split('/')), 'rb') as f:
+                        self.assertEqual(await f.read(), content)
+                self.assertFalse(os.path.exists(os.path.join(dest_dir, 'missing', 'file.txt')))
         finally:
             await runner.cleanup()
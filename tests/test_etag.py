@@ -1,4 +1,4 @@
-from qiniu_sdk_bindings import etag
+from qiniu_sdk_bindings import etag, QiniuIoError
 import unittest
 import aiofiles
 import asyncio
@@ -57,6 +57,50 @@ class TestEtagV2(unittest.TestCase):
             self.assertEqual(e.finalize(), 'nt82yvMNHlNgZ4H8_A_4de84mr2f')
 
 
+    def test_etag_of_parts(self):
+        parts = [_data_of_size(1 << 19), _data_of_size(1 << 23)]
+        self.assertEqual(etag.etag_of_parts(parts),
+                         'nt82yvMNHlNgZ4H8_A_4de84mr2f')
+        part_etags = [etag.etag_of(io.BytesIO(part)) for part in parts]
+        self.assertEqual(etag.etag_of_part_etags(part_etags),
+                         'nt82yvMNHlNgZ4H8_A_4de84mr2f')
+        with self.assertRaises(QiniuIoError):
+            etag.etag_of_parts([])
+        with self.assertRaises(QiniuIoError):
+            etag.etag_of_part_etags([])
+
+class TestEtagReader(unittest.IsolatedAsyncioTestCase):
+    def test_etag_reader(self):
+        stream = io.BytesIO(_data_of_size(1 << 20))
+        with etag.EtagReader(stream) as reader:
+            self.assertEqual(len(reader.read(1024)), 1024)
+        self.assertEqual(reader.etag, 'Foyl8onxBLWeRLL5oItRJphv6i4b')
+        self.assertTrue(stream.closed)
+
+        stream = io.BytesIO(b'etag')
+        with self.assertRaises(RuntimeError):
+            with etag.EtagReader(stream) as reader:
+                raise RuntimeError('fake error')
+        self.assertIsNone(reader.etag)
+        self.assertTrue(stream.closed)
+
+    async def test_async_etag_reader(self):
+        stream = io.BytesIO(b'etag')
+        async with etag.EtagReader(stream) as reader:
+            pass
+        self.assertEqual(reader.etag, 'FpLiADEaVoALPkdb8tJEJyRTXoe_')
+        self.assertTrue(stream.closed)
+
+    def test_etag_reader_close_error(self):
+        class BrokenStream(io.BytesIO):
+            def close(self):
+                raise OSError('fake close error')
+
+        with self.assertRaises(QiniuIoError):
+            with etag.EtagReader(BrokenStream(b'etag')):
+                pass
+
+
 class TestAsyncEtag(unittest.IsolatedAsyncioTestCase):
     async def test_empty_etag_of(self):
         async with aiofiles.tempfile.TemporaryFile('wb+') as f:
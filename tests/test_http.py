@@ -1,5 +1,5 @@
 from threading import Thread
-from qiniu_sdk_bindings import http
+from qiniu_sdk_bindings import http, QiniuInvalidStatusCodeError, QiniuInvalidURLError
 from http.server import ThreadingHTTPServer, BaseHTTPRequestHandler
 from aiohttp import web
 import unittest
@@ -46,6 +46,52 @@ class TestMetrics(unittest.TestCase):
         self.assertEqual(metrics.total_duration, 9876543210)
 
 
+class TestUri(unittest.TestCase):
+    def test_uri(self):
+        uri = http.Uri.parse('https://www.qiniu.com:8443/a/b?c=d')
+        self.assertEqual(uri.scheme(), 'https')
+        self.assertEqual(uri.host(), 'www.qiniu.com')
+        self.assertEqual(uri.port(), 8443)
+        self.assertEqual(uri.path(), '/a/b')
+        self.assertEqual(uri.query(), 'c=d')
+        self.assertEqual(uri.to_string(), 'https://www.qiniu.com:8443/a/b?c=d')
+        self.assertEqual(str(uri), 'https://www.qiniu.com:8443/a/b?c=d')
+
+        uri = http.Uri.parse('/a/b')
+        self.assertIsNone(uri.scheme())
+        self.assertIsNone(uri.host())
+        self.assertIsNone(uri.port())
+        self.assertIsNone(uri.query())
+
+        self.assertEqual(http.Uri.parse('http://www.qiniu.com/'), http.Uri.parse('http://www.qiniu.com/'))
+        self.assertNotEqual(http.Uri.parse('http://www.qiniu.com/'), http.Uri.parse('http://www.qiniu.com/a'))
+        self.assertEqual(len({http.Uri.parse('http://www.qiniu.com/'), http.Uri.parse('http://www.qiniu.com/')}), 1)
+        with self.assertRaises(QiniuInvalidURLError):
+            http.Uri.parse('http://invalid url/')
+
+
+class TestStatusCode(unittest.TestCase):
+    def test_status_code(self):
+        code = http.StatusCode.from_int(404)
+        self.assertEqual(int(code), 404)
+        self.assertTrue(code.is_client_error())
+        self.assertFalse(code.is_success())
+        self.assertFalse(code.is_server_error())
+        self.assertFalse(code.is_redirection())
+        self.assertEqual(code.canonical_reason(), 'Not Found')
+        self.assertTrue(http.StatusCode.from_int(200).is_success())
+        self.assertTrue(http.StatusCode.from_int(302).is_redirection())
+        self.assertTrue(http.StatusCode.from_int(503).is_server_error())
+        self.assertIsNone(http.StatusCode.from_int(599).canonical_reason())
+
+        self.assertEqual(http.StatusCode.from_int(404), http.StatusCode.from_int(404))
+        self.assertNotEqual(http.StatusCode.from_int(404), http.StatusCode.from_int(403))
+        self.assertEqual(len({http.StatusCode.from_int(404), http.StatusCode.from_int(404)}), 1)
+        for invalid in (0, 99, 1000, 70000, -1):
+            with self.assertRaises(QiniuInvalidStatusCodeError):
+                http.StatusCode.from_int(invalid)
+
+
 class TestSyncHttpResponse(unittest.TestCase):
     def test_new_sync_http_response(self):
         response = http.SyncHttpResponse(status_code=200, headers={
@@ -1,10 +1,13 @@
-from qiniu_sdk_bindings import credential, http, http_client, QiniuInvalidDomainWithPortError, QiniuInvalidIpAddrWithPortError, QiniuEmptyRegionsProvider, QiniuApiCallError
+from qiniu_sdk_bindings import credential, http, http_client, upload, QiniuInvalidDomainWithPortError, QiniuInvalidIpAddrWithPortError, QiniuEmptyRegionsProvider, QiniuApiCallError, QiniuHttpCallError, QiniuJsonError, QiniuInvalidEndpointError, QiniuInvalidURLError, QiniuInvalidServiceNameError, QiniuEmptyEndpoints
 from aiohttp import web
 import os
 import io
 import aiofiles
 import unittest
+import asyncio
 import fractions
+import json
+import tempfile
 
 
 class TestDomainWithPort(unittest.TestCase):
@@ -78,6 +81,92 @@ class TestEndpoint(unittest.TestCase):
         self.assertEqual(d.ip_addr, '127.0.0.1')
         self.assertEqual(d.port, None)
 
+    def test_endpoint_to_url(self):
+        d = http_client.Endpoint('www.qiniu.com')
+        self.assertEqual(d.to_base_url(use_https=True), 'https://www.qiniu.com')
+        self.assertEqual(d.to_url('/fake/path', use_https=True), 'https://www.qiniu.com/fake/path')
+        d = http_client.Endpoint('127.0.0.1', 8080)
+        self.assertEqual(d.to_base_url(), 'http://127.0.0.1:8080')
+        self.assertEqual(d.to_url('/fake/path?a=b'), 'http://127.0.0.1:8080/fake/path?a=b')
+        with self.assertRaises(ValueError):
+            d.to_url('fake/path')
+        with self.assertRaises(QiniuInvalidURLError):
+            d.to_url('/fake path')
+
+    def test_endpoint_hash(self):
+        stats = {http_client.Endpoint('www.qiniu.com', 8080): 1}
+        self.assertEqual(stats[http_client.Endpoint('www.qiniu.com:8080')], 1)
+        self.assertEqual(len({http_client.Endpoint('127.0.0.1', 8080),
+                              http_client.Endpoint('127.0.0.1:8080'),
+                              http_client.Endpoint('127.0.0.1')}), 2)
+        self.assertEqual(len({http_client.DomainWithPort('www.qiniu.com', 8080),
+                              http_client.DomainWithPort('www.qiniu.com:8080')}), 1)
+        self.assertEqual(len({http_client.IpAddrWithPort('127.0.0.1', 8080),
+                              http_client.IpAddrWithPort('127.0.0.1:8080')}), 1)
+
+    def test_to_socket_addr(self):
+        self.assertEqual(http_client.IpAddrWithPort('127.0.0.1', 8080).to_socket_addr(), ('127.0.0.1', 8080))
+        self.assertEqual(http_client.IpAddrWithPort('::1').to_socket_addr(), ('::1', 80))
+        self.assertEqual(http_client.Endpoint('www.qiniu.com').to_socket_addr(), ('www.qiniu.com', 80))
+        self.assertEqual(http_client.Endpoint('www.qiniu.com').to_socket_addr(use_https=True), ('www.qiniu.com', 443))
+        self.assertEqual(http_client.Endpoint('www.qiniu.com', 8080).to_socket_addr(
+            use_https=True), ('www.qiniu.com', 8080))
+        self.assertEqual(http_client.Endpoint('127.0.0.1', 8080).to_socket_addr(), ('127.0.0.1', 8080))
+
+    def test_endpoint_inequality(self):
+        self.assertFalse(http_client.Endpoint('www.qiniu.com', 8080) != http_client.Endpoint('www.qiniu.com:8080'))
+        self.assertTrue(http_client.Endpoint('127.0.0.1', 8080) != http_client.Endpoint('127.0.0.1'))
+        self.assertFalse(http_client.DomainWithPort('www.qiniu.com', 8080) !=
+                         http_client.DomainWithPort('www.qiniu.com:8080'))
+        self.assertTrue(http_client.IpAddrWithPort('127.0.0.1', 8080) != http_client.IpAddrWithPort('127.0.0.2', 8080))
+        self.assertFalse(http_client.Endpoints(['127.0.0.1:8080']) != http_client.Endpoints(['127.0.0.1:8080']))
+        self.assertTrue(http_client.Endpoints(['127.0.0.1:8080']) != http_client.Endpoints(['127.0.0.2:8080']))
+        self.assertTrue(http_client.Region('z0') != http_client.Region('z1'))
+        with self.assertRaises(TypeError):
+            http_client.Endpoint('127.0.0.1') < http_client.Endpoint('127.0.0.2')
+
+    def test_endpoint_bool(self):
+        self.assertTrue(http_client.Endpoint('www.qiniu.com'))
+        self.assertTrue(http_client.Endpoint('127.0.0.1', 8080))
+        self.assertTrue(http_client.DomainWithPort('www.qiniu.com'))
+
+    def test_endpoint_from_url(self):
+        self.assertEqual(http_client.Endpoint.from_url(
+            'http://up.qiniup.com'), http_client.Endpoint('up.qiniup.com'))
+        self.assertEqual(http_client.Endpoint.from_url(
+            'https://1.2.3.4:9090/path?q=1'), http_client.Endpoint('1.2.3.4', 9090))
+        self.assertEqual(http_client.Endpoint.from_url(
+            'ftp://bad.example.com'), http_client.Endpoint('bad.example.com'))
+        self.assertTrue(http_client.Endpoint.from_url('http://[::1]/').is_ipv6())
+        with self.assertRaises(QiniuInvalidURLError):
+            http_client.Endpoint.from_url('http://up qiniup.com')
+        with self.assertRaises(QiniuInvalidEndpointError):
+            http_client.Endpoint.from_url('/path/only')
+
+    def test_endpoint_path_joining(self):
+        endpoint = http_client.Endpoint('up.qiniup.com')
+        self.assertEqual(endpoint + 'health', 'up.qiniup.com/health')
+        self.assertEqual(endpoint + '/health', 'up.qiniup.com/health')
+        self.assertEqual(http_client.Endpoint('127.0.0.1', 8080) / 'a/b', '127.0.0.1:8080/a/b')
+        with self.assertRaises(TypeError):
+            endpoint + 1
+        with self.assertRaises(TypeError):
+            endpoint / b'health'
+
+    def test_ip_version(self):
+        self.assertTrue(http_client.IpAddrWithPort('127.0.0.1', 8080).is_ipv4())
+        self.assertFalse(http_client.IpAddrWithPort('127.0.0.1').is_ipv6())
+        self.assertTrue(http_client.IpAddrWithPort('[::1]:8080').is_ipv6())
+        self.assertTrue(http_client.Endpoint('127.0.0.1').is_ipv4())
+        self.assertTrue(http_client.Endpoint('::1').is_ipv6())
+        self.assertFalse(http_client.Endpoint('www.qiniu.com').is_ipv4())
+        self.assertFalse(http_client.Endpoint('www.qiniu.com').is_ipv6())
+        mapped = http_client.IpAddrWithPort(
+            '[::ffff:192.168.1.1]:8080').to_ipv4_mapped()
+        self.assertEqual(mapped, http_client.IpAddrWithPort('192.168.1.1', 8080))
+        self.assertIsNone(http_client.IpAddrWithPort('[::1]:8080').to_ipv4_mapped())
+        self.assertIsNone(http_client.IpAddrWithPort('127.0.0.1').to_ipv4_mapped())
+
 
 class TestEndpoints(unittest.TestCase):
     def test_endpoints(self):
@@ -118,6 +207,74 @@ class TestEndpoints(unittest.TestCase):
             http_client.Endpoint('192.168.2.3', 8080),
         ])
 
+    def test_endpoints_getitem(self):
+        e = http_client.Endpoints(
+            ['192.168.1.1:8080', '192.168.1.2:8080'], ['192.168.2.1:8080'])
+        self.assertEqual(len(e), 3)
+        self.assertEqual(e[0], http_client.Endpoint('192.168.1.1', 8080))
+        self.assertEqual(e[2], http_client.Endpoint('192.168.2.1', 8080))
+        self.assertEqual(e[-1], http_client.Endpoint('192.168.2.1', 8080))
+        self.assertEqual(e[1:3], [
+            http_client.Endpoint('192.168.1.2', 8080),
+            http_client.Endpoint('192.168.2.1', 8080),
+        ])
+        self.assertEqual(e[::-2], [
+            http_client.Endpoint('192.168.2.1', 8080),
+            http_client.Endpoint('192.168.1.1', 8080),
+        ])
+        self.assertEqual(e[1:1], [])
+        self.assertEqual(e[5:], [])
+        with self.assertRaises(IndexError):
+            e[3]
+        with self.assertRaises(IndexError):
+            e[-4]
+
+    def test_endpoints_modifiers(self):
+        e = http_client.Endpoints(
+            ['192.168.1.1:8080', '192.168.1.2:8080'], ['192.168.2.1:8080'])
+        e2 = e.with_preferred_endpoint_prepended(
+            http_client.Endpoint('192.168.1.3', 8080))
+        self.assertEqual(e2.preferred, [
+            http_client.Endpoint('192.168.1.3', 8080),
+            http_client.Endpoint('192.168.1.1', 8080),
+            http_client.Endpoint('192.168.1.2', 8080),
+        ])
+        self.assertEqual(e2.alternative, e.alternative)
+        e3 = e2.with_alternative_endpoint_prepended(
+            http_client.Endpoint('192.168.2.2', 8080))
+        self.assertEqual(e3.preferred, e2.preferred)
+        self.assertEqual(e3.alternative, [
+            http_client.Endpoint('192.168.2.2', 8080),
+            http_client.Endpoint('192.168.2.1', 8080),
+        ])
+        e4 = e3.without_endpoint(http_client.Endpoint('192.168.1.1', 8080)) \
+            .without_endpoint(http_client.Endpoint('192.168.2.1', 8080))
+        self.assertEqual(e4.preferred, [
+            http_client.Endpoint('192.168.1.3', 8080),
+            http_client.Endpoint('192.168.1.2', 8080),
+        ])
+        self.assertEqual(e4.alternative, [
+            http_client.Endpoint('192.168.2.2', 8080),
+        ])
+        self.assertEqual(len(e.preferred), 2)
+
+    def test_endpoints_json(self):
+        e = http_client.Endpoints.from_list(
+            ['192.168.1.1:8080', 'www.qiniu.com'], ['192.168.2.1:8080'])
+        self.assertEqual(e.preferred, [
+            http_client.Endpoint('192.168.1.1', 8080),
+            http_client.Endpoint('www.qiniu.com'),
+        ])
+        self.assertEqual(json.loads(e.to_json()), {
+            'preferred': ['192.168.1.1:8080', 'www.qiniu.com'],
+            'alternative': ['192.168.2.1:8080'],
+        })
+        self.assertEqual(http_client.Endpoints.from_json(e.to_json()), e)
+        with self.assertRaises(QiniuJsonError):
+            http_client.Endpoints.from_json('{"preferred": ')
+        with self.assertRaises(QiniuInvalidEndpointError):
+            http_client.Endpoints.from_json('{"preferred": ["192.168.1.1:abc"]}')
+
 
 class TestRegion(unittest.TestCase):
     def test_region(self):
@@ -212,6 +369,84 @@ class TestRegion(unittest.TestCase):
         ]))
 
 
+    def test_region_hash(self):
+        r1 = http_client.Region('z0', up_preferred_endpoints=[
+                                http_client.Endpoint('192.168.1.1', 8080)])
+        r2 = http_client.Region('z0', up_preferred_endpoints=[
+                                http_client.Endpoint('192.168.1.1', 8080)])
+        r3 = http_client.Region('z1')
+        self.assertEqual(hash(r1), hash(r2))
+        self.assertEqual(len({r1, r2, r3}), 2)
+        self.assertEqual({r1: 'z0'}[r2], 'z0')
+
+    def test_region_with_endpoints(self):
+        r1 = http_client.Region('z0',
+                                s3_region_id='cn-east-1',
+                                up_preferred_endpoints=['192.168.1.1:8080'],
+                                io_preferred_endpoints=['192.168.2.1:8080'])
+        r2 = r1.with_up_endpoints(http_client.Endpoints(
+            ['192.168.3.1:8080'], ['192.168.3.2:8080']))
+        self.assertEqual(r2.region_id, 'z0')
+        self.assertEqual(r2.s3_region_id, 'cn-east-1')
+        self.assertEqual([str(e) for e in r2.up_preferred_endpoints], ['192.168.3.1:8080'])
+        self.assertEqual([str(e) for e in r2.up_alternative_endpoints], ['192.168.3.2:8080'])
+        self.assertEqual([str(e) for e in r2.io_preferred_endpoints], ['192.168.2.1:8080'])
+        self.assertEqual([str(e) for e in r1.up_preferred_endpoints], ['192.168.1.1:8080'])
+        r3 = r1.with_s3_endpoints(http_client.Endpoints(['192.168.4.1:8080']))
+        self.assertEqual([str(e) for e in r3.s3_preferred_endpoints], ['192.168.4.1:8080'])
+        self.assertEqual([str(e) for e in r3.up_preferred_endpoints], ['192.168.1.1:8080'])
+
+    def test_region_preferred_endpoint(self):
+        r = http_client.Region('z0',
+                               up_preferred_endpoints=['192.168.1.1:8080', '192.168.1.2:8080'],
+                               io_preferred_endpoints=['192.168.2.1:8080'],
+                               rs_alternative_endpoints=['192.168.3.1:8080'])
+        self.assertEqual(r.get_preferred_upload_endpoint(),
+                         http_client.Endpoint('192.168.1.1', 8080))
+        self.assertEqual(r.get_preferred_download_endpoint(),
+                         http_client.Endpoint('192.168.2.1', 8080))
+        with self.assertRaises(QiniuEmptyEndpoints):
+            r.get_preferred_rs_endpoint()
+        with self.assertRaises(QiniuEmptyEndpoints):
+            r.get_preferred_s3_endpoint()
+
+    def test_region_contains(self):
+        r = http_client.Region('z0',
+                               up_preferred_endpoints=['up.qiniup.com'],
+                               io_preferred_endpoints=['192.168.2.1:8080'],
+                               s3_alternative_endpoints=['s3.qiniucs.com'])
+        self.assertIn(http_client.Endpoint('up.qiniup.com'), r)
+        self.assertIn(http_client.Endpoint('192.168.2.1', 8080), r)
+        self.assertIn(http_client.Endpoint('s3.qiniucs.com'), r)
+        self.assertNotIn(http_client.Endpoint('192.168.2.1'), r)
+        self.assertNotIn(http_client.Endpoint('rs.qiniu.com'), r)
+
+    def test_region_validate(self):
+        r = http_client.Region('z0',
+                               up_preferred_endpoints=['192.168.1.1:8080'],
+                               io_preferred_endpoints=['192.168.2.1:8080'],
+                               rs_alternative_endpoints=['192.168.3.1:8080'])
+        self.assertEqual(r.validate(), [
+            'uc service has no preferred endpoints',
+            'rs service has no preferred endpoints',
+            'rsf service has no preferred endpoints',
+            'api service has no preferred endpoints',
+            's3 service has no preferred endpoints',
+        ])
+        with self.assertRaises(QiniuEmptyEndpoints):
+            r.validate_strict()
+        endpoints = ['192.168.1.1:8080']
+        r = http_client.Region('z0',
+                               up_preferred_endpoints=endpoints,
+                               io_preferred_endpoints=endpoints,
+                               uc_preferred_endpoints=endpoints,
+                               rs_preferred_endpoints=endpoints,
+                               rsf_preferred_endpoints=endpoints,
+                               api_preferred_endpoints=endpoints,
+                               s3_preferred_endpoints=endpoints)
+        self.assertEqual(r.validate(), [])
+        r.validate_strict()
+
 class TestRegionsProvider(unittest.TestCase):
     def test_regions_provider(self):
         r1 = http_client.Region('z0',
@@ -240,9 +475,41 @@ class TestRegionsProvider(unittest.TestCase):
         r = provider.get_all()
         self.assertEqual(r, [r1, r2])
 
+        options = http_client.RegionsGetOptions(
+            service_names=[http_client.ServiceName.Up], timeout_ms=1000)
+        self.assertEqual(options.timeout_ms, 1000)
+        self.assertEqual(provider.get(options=options), r1)
+        self.assertEqual(provider.get_all(options=options), [r1, r2])
+        options = http_client.RegionsGetOptions(
+            service_names=[http_client.ServiceName.Rs])
+        self.assertEqual(provider.get_all(options=options), [])
+        with self.assertRaises(QiniuEmptyRegionsProvider):
+            provider.get(options=options)
+        r3 = http_client.Region('z2', rs_preferred_endpoints=['192.168.4.1:8080'])
+        self.assertEqual(http_client.RegionsProvider([r1, r3]).get(options=options), r3)
+
         with self.assertRaises(QiniuEmptyRegionsProvider):
             http_client.RegionsProvider([])
 
+    def test_combined_regions_provider(self):
+        r1 = http_client.Region('z0', up_preferred_endpoints=['192.168.1.1:8080'])
+        r2 = http_client.Region('z1', up_preferred_endpoints=['192.168.2.1:8080'])
+        p1 = http_client.RegionsProvider([r1])
+        p2 = http_client.RegionsProvider([r2, r1])
+
+        provider = http_client.RegionsProvider.combined([p1, p2])
+        self.assertEqual(provider.get(), r1)
+        self.assertEqual(provider.get_all(), [r1])
+
+        provider = http_client.RegionsProvider.combined([p1, p2], strategy='merge')
+        self.assertEqual(provider.get(), r1)
+        self.assertEqual(provider.get_all(), [r1, r2])
+
+        with self.assertRaises(QiniuEmptyRegionsProvider):
+            http_client.RegionsProvider.combined([])
+        with self.assertRaises(QiniuInvalidServiceNameError):
+            http_client.RegionsProvider.combined([p1], strategy='random')
+
 
 class TestEndpointsProvider(unittest.TestCase):
     def test_endpoints_provider(self):
@@ -286,6 +553,13 @@ class TestEndpointsProvider(unittest.TestCase):
                     '192.168.7.1:8080', '192.168.7.2:8080'],
                 ['192.168.6.1:8080', '192.168.6.2:8080', '192.168.8.1:8080', '192.168.8.2:8080']))
 
+        e = r.to_endpoints_provider(http_client.ServiceName.Io)
+        self.assertEqual(e.get(service_names=[http_client.ServiceName.Up]), http_client.Endpoints(
+            ['192.168.3.1:8080', '192.168.3.2:8080'], ['192.168.4.1:8080', '192.168.4.2:8080']))
+        e = http_client.RegionsProvider([r]).to_endpoints_provider_for_service(http_client.ServiceName.Rsf)
+        self.assertEqual(e.get(), http_client.Endpoints(
+            ['192.168.7.1:8080', '192.168.7.2:8080'], ['192.168.8.1:8080', '192.168.8.2:8080']))
+
 
 class TestAllRegionsProvider(unittest.IsolatedAsyncioTestCase):
     async def test_all_regions_provider(self):
@@ -311,6 +585,111 @@ class TestAllRegionsProvider(unittest.IsolatedAsyncioTestCase):
             self.assertEqual(regions[4].region_id, 'na0')
             region = await provider.async_get()
             self.assertEqual(region.region_id, 'z0')
+
+            async with http_client.AllRegionsProvider.in_memory(credential.Credential(
+                    'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089'])) as provider:
+                region = await provider.async_get()
+                self.assertEqual(region.region_id, 'z0')
+            with http_client.AllRegionsProvider.in_memory(credential.Credential(
+                    'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089'])) as provider:
+                self.assertIsInstance(
+                    provider, http_client.AllRegionsProvider)
+            self.assertIsNone(provider.get_cache_path())
+            provider = http_client.AllRegionsProvider(credential.Credential(
+                'ak', 'sk'), auto_persistent=False, use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            self.assertTrue(os.path.isabs(provider.get_cache_path()))
+            self.assertEqual(os.path.basename(provider.get_cache_path()), 'all-regions-cache.json')
+            with tempfile.TemporaryDirectory() as tmpdir:
+                path = os.path.join(tmpdir, 'regions.json')
+                provider = http_client.AllRegionsProvider.load_or_create_from(credential.Credential(
+                    'ak', 'sk'), path, use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']), cache_lifetime_secs=60, shrink_interval_secs=120)
+                self.assertEqual(provider.get_cache_path(), path)
+                self.assertEqual(provider.get_cache_lifetime_secs(), 60)
+                self.assertEqual(provider.get_shrink_interval_secs(), 120)
+        finally:
+            await runner.cleanup()
+
+    async def test_async_all_regions_provider(self):
+        async def handler(request):
+            return web.json_response(regions_response_body(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/regions', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            provider = await http_client.AllRegionsProvider.async_in_memory(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']), cache_lifetime_secs=60)
+            self.assertIsInstance(provider, http_client.AllRegionsProvider)
+            self.assertIsNone(provider.get_cache_path())
+            self.assertEqual(provider.get_cache_lifetime_secs(), 60)
+            region = await provider.async_get()
+            self.assertEqual(region.region_id, 'z0')
+
+            provider = await http_client.AllRegionsProvider.async_default_load_or_create_from(credential.Credential(
+                'ak', 'sk'), auto_persistent=False, use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            self.assertIsInstance(provider, http_client.AllRegionsProvider)
+            regions = await provider.async_get_all()
+            self.assertEqual(len(regions), 5)
+        finally:
+            await runner.cleanup()
+
+    async def test_all_regions_provider_to_static_provider(self):
+        requests = []
+
+        async def handler(request):
+            requests.append(request)
+            return web.json_response(regions_response_body(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/regions', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            provider = http_client.AllRegionsProvider.in_memory(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            static_provider = await provider.async_to_static_provider()
+            self.assertNotIsInstance(
+                static_provider, http_client.AllRegionsProvider)
+            self.assertEqual(len(requests), 1)
+        finally:
+            await runner.cleanup()
+
+        regions = await static_provider.async_get_all()
+        self.assertEqual(len(regions), 5)
+        self.assertEqual(regions[0].region_id, 'z0')
+        self.assertEqual(len(requests), 1)
+
+    async def test_all_regions_provider_set_uc_endpoints(self):
+        async def handler(request):
+            return web.json_response(regions_response_body(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/regions', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            provider = http_client.AllRegionsProvider.in_memory(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8090']))
+            self.assertEqual(provider.get_uc_endpoints().preferred, [
+                             http_client.Endpoint('127.0.0.1', 8090)])
+            with self.assertRaises(QiniuEmptyEndpoints):
+                provider.set_uc_endpoints(http_client.Endpoints([]))
+            provider.set_uc_endpoints(
+                http_client.Endpoints(['127.0.0.1:8089']))
+            self.assertEqual(provider.get_uc_endpoints().preferred, [
+                             http_client.Endpoint('127.0.0.1', 8089)])
+            region = await provider.async_get()
+            self.assertEqual(region.region_id, 'z0')
         finally:
             await runner.cleanup()
 
@@ -340,6 +719,68 @@ class TestBucketRegionsQueryer(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_region_from_endpoint(self):
+        case = self
+
+        async def handler(request):
+            case.assertEqual(request.query['ak'], 'ak')
+            case.assertEqual(request.query['bucket'], 'bucket')
+            return web.json_response(query_response_body(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            region = await http_client.Region.async_from_endpoint(
+                http_client.Endpoint('127.0.0.1', 8089), credential.Credential('ak', 'sk'), 'bucket')
+            self.assertEqual(region.region_id, 'z0')
+            with self.assertRaises(QiniuApiCallError):
+                await http_client.Region.async_from_endpoint(
+                    http_client.Endpoint('127.0.0.1', 8090), credential.Credential('ak', 'sk'), 'bucket')
+        finally:
+            await runner.cleanup()
+
+
+    async def test_regions_provider_with_timeout(self):
+        async def handler(request):
+            await asyncio.sleep(2)
+            return web.json_response(query_response_body(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            provider = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            query = provider.query('ak', 'bucket')
+            fallback = http_client.RegionsProvider([http_client.Region('z9')])
+            region = await query.with_timeout(0.5, fallback=fallback).async_get()
+            self.assertEqual(region.region_id, 'z9')
+            with self.assertRaises(QiniuApiCallError):
+                await query.with_timeout(0.5).async_get()
+            region = await asyncio.to_thread(query.with_timeout(0.5, fallback=fallback).get)
+            self.assertEqual(region.region_id, 'z9')
+            with self.assertRaises(QiniuApiCallError):
+                await asyncio.to_thread(query.with_timeout(0.5).get)
+        finally:
+            await runner.cleanup()
+
+    def test_with_timeout_rejects_invalid_timeout(self):
+        provider = http_client.RegionsProvider([http_client.Region('z9')])
+        endpoints = http_client.Endpoints(['127.0.0.1:8089'])
+        for timeout_secs in (-1.0, float('nan'), float('inf')):
+            with self.assertRaises(ValueError):
+                provider.with_timeout(timeout_secs)
+            with self.assertRaises(ValueError):
+                endpoints.with_timeout(timeout_secs)
 
 class TestBucketDomainsQueryer(unittest.IsolatedAsyncioTestCase):
     async def test_bucket_domains_queryer(self):
@@ -786,6 +1227,23 @@ class TestChoose(unittest.IsolatedAsyncioTestCase):
             chosen = await chooser.async_choose(['127.0.0.1', '127.0.0.2', '127.0.1.1'])
             self.assertEqual(len(chosen), 2)
 
+    async def test_selector_config(self):
+        config = http_client.SelectorConfig()
+        self.assertEqual(config.strategy, 'round_robin')
+        self.assertEqual(config.punish_duration_secs, 30)
+        self.assertEqual(config.base_timeout_ms, 500)
+        config = http_client.SelectorConfig('latency', punish_duration_secs=10, base_timeout_ms=100)
+        self.assertEqual(config.strategy, 'latency')
+        self.assertEqual(config.punish_duration_secs, 10)
+        self.assertEqual(config.base_timeout_ms, 100)
+        with self.assertRaises(QiniuInvalidServiceNameError):
+            http_client.SelectorConfig('fastest')
+        self.assertEqual(http_client.SelectorConfig('round_robin').strategy, 'round_robin')
+        upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+            credential.Credential('ak', 'sk'), 'fakebucket', 3600))
+        upload_manager = upload_manager.with_selector_config(http_client.SelectorConfig('random'))
+        self.assertIsInstance(upload_manager, upload.UploadManager)
+
 
 class TestRetrier(unittest.IsolatedAsyncioTestCase):
     async def test_error_retrier(self):
@@ -924,6 +1382,20 @@ class TestHttpClient(unittest.IsolatedAsyncioTestCase):
                 authorization=http_client.Authorization.v2(credential.Credential('ak', 'sk')))
             self.assertEqual(resp.status_code, 200)
             self.assertEqual(await resp.parse_json(), {})
+
+            async with http_client.HttpClient() as client:
+                resp = await client.async_call(
+                    'GET', http_client.Endpoints(['127.0.0.1:8089']),
+                    use_https=False,
+                    path='/getfile',
+                    query='fakeops',
+                    query_pairs=[('key1', 'val1'), ('key2', 'val2')],
+                    authorization=http_client.Authorization.v2(credential.Credential('ak', 'sk')))
+                self.assertEqual(resp.status_code, 200)
+            self.assertTrue(client.closed)
+            with self.assertRaises(QiniuHttpCallError):
+                await client.async_call('GET', http_client.Endpoints(['127.0.0.1:8089']),
+                                        use_https=False, path='/getfile')
         finally:
             await runner.cleanup()
 
@@ -1132,3 +1604,27 @@ class TestHttpClient(unittest.IsolatedAsyncioTestCase):
             self.assertEqual(await resp.parse_json(), {})
         finally:
             await runner.cleanup()
+
+    async def test_build_default_http_client(self):
+        async def handler(request):
+            self.assertIn('fakeuseragent', request.headers['User-Agent'])
+            return web.json_response({'ok': True}, status=200, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/default', handler)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            client = http_client.build_default_http_client(
+                user_agent='fakeuseragent', connection_timeout_ms=1000, request_timeout_ms=5000)
+            resp = await client.async_call(
+                'GET', http_client.Endpoints(['127.0.0.1:8089']),
+                path='/default',
+                accept_json=True)
+            self.assertEqual(resp.status_code, 200)
+            self.assertEqual(await resp.parse_json(), {'ok': True})
+        finally:
+            await runner.cleanup()
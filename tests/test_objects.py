@@ -1,8 +1,11 @@
-from qiniu_sdk_bindings import objects, credential, http_client
+from qiniu_sdk_bindings import objects, credential, http_client, QiniuApiCallError, QiniuInvalidTagError, QiniuInvalidConcurrency, QiniuUnsupportedTypeError, QiniuInvalidURLError, QiniuInvalidMethodError, QiniuMimeParseError, QiniuInvalidDomainWithPortError
 from aiohttp import web
 import unittest
+import asyncio
+import importlib.util
 import base64
 import time
+import os
 
 
 class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
@@ -117,6 +120,12 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
             resp = await bucket.modify_object_status('fakekey', True).async_call()
             self.assertEqual(resp.status_code, 200)
             self.assertEqual(resp.body, {})
+            info = await objects_manager.async_get_file_info('fakebucket', 'fakekey')
+            self.assertEqual(info['fsize'], 1024)
+            self.assertEqual(info['hash'], 'fakehash')
+            await objects_manager.async_copy_file('fakebucket', 'fakekey', 'fakebucket2', 'fakekey2')
+            await objects_manager.async_move_file('fakebucket', 'fakekey', 'fakebucket2', 'fakekey2', force=True)
+            await objects_manager.async_delete_file('fakebucket', 'fakekey')
         finally:
             await runner.cleanup()
 
@@ -189,6 +198,183 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_objects_async_list(self):
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def list(self):
+            if self.query.get('marker') == 'fakemarker':
+                return web.json_response({
+                    "marker": "",
+                    "items": [{"key": "fakeobj2", "put_time": int(time.time_ns()/100), "hash": "fakeobj2hash",
+                               "fsize": 2, "mime_type": "text/plain"}]
+                }, headers={'X-ReqId': 'fakereqid'})
+            else:
+                return web.json_response({
+                    "marker": "fakemarker",
+                    "items": [{"key": "fakeobj1", "put_time": int(time.time_ns()/100), "hash": "fakeobj1hash",
+                               "fsize": 1, "mime_type": "text/plain"}]
+                }, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/list', list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            keys = [object['key'] async for object in bucket.async_list(version=objects.ListVersion.V1)]
+            self.assertEqual(keys, ['fakeobj1', 'fakeobj2'])
+
+            listing = bucket.async_list(version=objects.ListVersion.V1)
+            pending = listing.__anext__()
+            with self.assertRaises(QiniuInvalidConcurrency):
+                listing.__anext__()
+            self.assertEqual((await pending)['key'], 'fakeobj1')
+            self.assertEqual((await listing.__anext__())['key'], 'fakeobj2')
+        finally:
+            await runner.cleanup()
+
+    async def test_iterate_objects(self):
+        markers = []
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def list(request):
+            markers.append(request.query.get('marker'))
+            assert request.query['prefix'] == 'fake'
+            assert request.query['limit'] == '1'
+            if request.query.get('marker') == 'fakemarker':
+                return web.json_response({
+                    "marker": "",
+                    "items": [{"key": "fakeobj2", "hash": "fakeobj2hash", "fsize": 2}]
+                }, headers={'X-ReqId': 'fakereqid'})
+            else:
+                return web.json_response({
+                    "marker": "fakemarker",
+                    "items": [{"key": "fakeobj1", "hash": "fakeobj1hash", "fsize": 1}]
+                }, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/list', list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            iterator = objects_manager.bucket('fakebucket').iterate_objects(prefix='fake', limit_per_page=1)
+            self.assertEqual((await asyncio.to_thread(next, iterator))['key'], 'fakeobj1')
+            self.assertEqual(markers, [None])
+            keys = [object['key'] for object in await asyncio.to_thread(lambda: [*iterator])]
+            self.assertEqual(keys, ['fakeobj2'])
+            self.assertEqual(markers, [None, 'fakemarker'])
+            iterator.reset()
+            keys = [object['key'] for object in await asyncio.to_thread(lambda: [*iterator])]
+            self.assertEqual(keys, ['fakeobj1', 'fakeobj2'])
+        finally:
+            await runner.cleanup()
+
+    async def test_list_with_progress(self):
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def list(request):
+            if request.query.get('marker') == 'fakemarker':
+                return web.json_response({
+                    "marker": "",
+                    "items": [{"key": "fakeobj3", "hash": "fakeobj3hash", "fsize": 3}]
+                }, headers={'X-ReqId': 'fakereqid'})
+            else:
+                return web.json_response({
+                    "marker": "fakemarker",
+                    "items": [{"key": "fakeobj1", "hash": "fakeobj1hash", "fsize": 1},
+                              {"key": "fakeobj2", "hash": "fakeobj2hash", "fsize": 2}]
+                }, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/list', list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            pages = []
+
+            async def on_page(page_number, items):
+                pages.append((page_number, [item['key'] for item in items]))
+
+            total = await bucket.async_list_with_progress(prefix='fake', limit=2, on_page=on_page)
+            self.assertEqual(total, 3)
+            self.assertEqual(pages, [(1, ['fakeobj1', 'fakeobj2']), (2, ['fakeobj3'])])
+
+            pages.clear()
+            total = await asyncio.to_thread(bucket.list_with_progress, on_page=lambda page_number, items: pages.append(
+                (page_number, len(items))))
+            self.assertEqual(total, 3)
+            self.assertEqual(pages, [(1, 2), (2, 1)])
+        finally:
+            await runner.cleanup()
+
+    async def test_list_as_dataframe(self):
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def list(request):
+            if request.query.get('marker') == 'fakemarker':
+                return web.json_response({
+                    "marker": "",
+                    "items": [{"key": "fakeobj2", "hash": "fakeobj2hash", "fsize": 2, "mimeType": "text/plain", "putTime": 20000000}]
+                }, headers={'X-ReqId': 'fakereqid'})
+            else:
+                return web.json_response({
+                    "marker": "fakemarker",
+                    "items": [{"key": "fakeobj1", "hash": "fakeobj1hash", "fsize": 1, "mimeType": "text/html", "putTime": 10000000}]
+                }, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/list', list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            with self.assertRaises(ValueError):
+                objects_manager.list_as_dataframe('fakebucket', columns=['fsize'])
+            result = await objects_manager.async_list_as_dataframe('fakebucket', 'fake', columns=['key', 'size', 'put_time'])
+            if importlib.util.find_spec('pandas') is not None:
+                self.assertEqual(list(result.columns), ['key', 'size', 'put_time'])
+                result = result.to_dict('records')
+            self.assertEqual(result, [
+                {'key': 'fakeobj1', 'size': 1, 'put_time': 1.0},
+                {'key': 'fakeobj2', 'size': 2, 'put_time': 2.0},
+            ])
+        finally:
+            await runner.cleanup()
+
     async def test_objects_operation(self):
         case = self
 
@@ -237,6 +423,873 @@ class TestObjectsOperation(unittest.IsolatedAsyncioTestCase):
         finally:
             await runner.cleanup()
 
+    async def test_batch_delete_with_conditions(self):
+        deleted = []
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def batch(self):
+            data = await self.post()
+            responses = []
+            for op in data.getall('op'):
+                name, base64ed_entry = op.split('/', 1)
+                entry = base64.urlsafe_b64decode(base64ed_entry).decode('utf-8')
+                key = entry[len('fakebucket:'):]
+                if name == 'stat':
+                    responses.append(
+                        {'code': 200, 'data': {'fsize': 1024, 'hash': 'hash_' + key}})
+                else:
+                    deleted.append(key)
+                    responses.append({'code': 200})
+            return web.json_response(responses, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.post('/batch', batch)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            results = await bucket.async_batch_delete_with_conditions(
+                ['object_1', 'object_2', 'object_3'],
+                [objects.BatchDeleteCondition(etag='hash_object_1', size=1024),
+                 objects.BatchDeleteCondition(etag='wrong_hash'),
+                 None])
+            self.assertIsNone(results[0])
+            self.assertIsInstance(results[1], QiniuApiCallError)
+            self.assertIsNone(results[2])
+            self.assertEqual(deleted, ['object_1', 'object_3'])
+            with self.assertRaises(ValueError):
+                await bucket.async_batch_delete_with_conditions(['object_1'], [])
+        finally:
+            await runner.cleanup()
+
+    async def test_stat_batch(self):
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def batch(self):
+            data = await self.post()
+            responses = []
+            for op in data.getall('op'):
+                _, base64ed_entry = op.split('/', 1)
+                entry = base64.urlsafe_b64decode(base64ed_entry).decode('utf-8')
+                key = entry[len('fakebucket:'):]
+                if key == 'missing':
+                    responses.append(
+                        {'code': 612, 'data': {'error': 'no such file or directory'}})
+                else:
+                    responses.append({'code': 200, 'data': {
+                        'fsize': 1024, 'hash': 'hash_' + key, 'mimeType': 'text/plain', 'putTime': 16000000000000000}})
+            return web.json_response(responses, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.post('/batch', batch)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            results = await bucket.async_stat_batch(['object_1', 'missing', 'object_2'])
+            self.assertEqual(len(results), 3)
+            self.assertIsInstance(results[0], objects.StatResult)
+            self.assertEqual(results[0].key, 'object_1')
+            self.assertEqual(results[0].hash, 'hash_object_1')
+            self.assertEqual(results[0].fsize, 1024)
+            self.assertEqual(results[0].mime_type, 'text/plain')
+            self.assertEqual(results[0].put_time, 1600000000.0)
+            self.assertIsInstance(results[1], QiniuApiCallError)
+            self.assertEqual(results[2].key, 'object_2')
+            self.assertEqual(await bucket.async_stat_batch([]), [])
+        finally:
+            await runner.cleanup()
+
+    async def test_object_copy_and_move(self):
+        case = self
+        existed = {'fakebucket2:fakekey2'}
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def copy_or_move(self):
+            from_entry = base64.urlsafe_b64decode(
+                self.match_info['from_entry']).decode('utf-8')
+            to_entry = base64.urlsafe_b64decode(
+                self.match_info['to_entry']).decode('utf-8')
+            case.assertEqual(from_entry, 'fakebucket:fakekey')
+            if to_entry in existed and self.match_info['force'] != 'true':
+                return web.json_response({'error': 'file exists'}, status=614, headers={'X-ReqId': 'fakereqid'})
+            existed.add(to_entry)
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes(
+            [web.post('/copy/{from_entry}/{to_entry}/force/{force}', copy_or_move)])
+        app.add_routes(
+            [web.post('/move/{from_entry}/{to_entry}/force/{force}', copy_or_move)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            with self.assertRaises(QiniuApiCallError) as ctx:
+                await bucket.async_copy_object('fakekey', 'fakebucket2', 'fakekey2')
+            self.assertTrue(repr(ctx.exception.args[0]).startswith(
+                'QiniuApiCallErrorInfo(status_code=614, '))
+            self.assertIn("request_id='fakereqid'",
+                          repr(ctx.exception.args[0]))
+            self.assertEqual(ctx.exception.args[0].response_body(), {
+                'error': 'file exists'})
+            self.assertEqual(
+                ctx.exception.args[0].response_body_text(), 'file exists')
+            await bucket.async_copy_object('fakekey', 'fakebucket2', 'fakekey2', force=True)
+            with self.assertRaises(QiniuApiCallError):
+                await bucket.async_move_object('fakekey', 'fakebucket2', 'fakekey2')
+            await bucket.async_move_object('fakekey', 'fakebucket2', 'fakekey2', force=True)
+            await bucket.async_rename('fakekey', 'fakekey3')
+            self.assertIn('fakebucket:fakekey3', existed)
+            with self.assertRaises(QiniuApiCallError):
+                await bucket.async_rename('fakekey', 'fakekey3')
+        finally:
+            await runner.cleanup()
+
+    async def test_objects_manager_builder(self):
+        case = self
+
+        async def stat(self):
+            case.assertEqual(
+                bytes(self.match_info['entry'], 'utf-8'),
+                base64.urlsafe_b64encode(b'fakebucket:fakekey'))
+            return web.json_response({"fsize": 1024, "hash": 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/stat/{entry}', stat)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            region = http_client.Region('z0', rs_preferred_endpoints=[
+                                        http_client.Endpoint('127.0.0.1', 8089)])
+            builder = objects.ObjectsManager.builder(
+                credential.Credential('ak', 'sk'))
+            builder.use_https(False)
+            builder.http_client(http_client.HttpClient())
+            builder.regions_provider(http_client.RegionsProvider([region]))
+            objects_manager = builder.build()
+            bucket = objects_manager.bucket('fakebucket')
+            resp = await bucket.stat_object('fakekey').async_call()
+            self.assertEqual(resp.status_code, 200)
+            self.assertEqual(resp.body['fsize'], 1024)
+        finally:
+            await runner.cleanup()
+
+    async def test_object_tags(self):
+        case = self
+        metadata = {}
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def stat(self):
+            case.assertEqual(
+                bytes(self.match_info['entry'], 'utf-8'),
+                base64.urlsafe_b64encode(b'fakebucket:fakekey'))
+            return web.json_response({"fsize": 1024, "hash": 'fakehash', "mimeType": 'text/plain', "x-qn-meta": metadata}, headers={'X-ReqId': 'fakereqid'})
+
+        async def chgm(self):
+            case.assertEqual(
+                bytes(self.match_info['entry'], 'utf-8'),
+                base64.urlsafe_b64encode(b'fakebucket:fakekey'))
+            case.assertEqual(
+                base64.urlsafe_b64decode(self.match_info['mime']), b'text/plain')
+            segments = self.match_info['tail'].split('/')
+            for i in range(0, len(segments), 2):
+                key = segments[i][len('x-qn-meta-'):]
+                metadata[key] = base64.urlsafe_b64decode(
+                    segments[i+1]).decode('utf-8')
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/stat/{entry}', stat)])
+        app.add_routes(
+            [web.post('/chgm/{entry}/mime/{mime}/{tail:.*}', chgm)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            await bucket.async_tag('fakekey', {'env': 'prod', 'owner': '测试'})
+            self.assertEqual(await bucket.async_get_tags('fakekey'), {'env': 'prod', 'owner': '测试'})
+            with self.assertRaises(QiniuInvalidTagError):
+                await bucket.async_tag('fakekey', {'bad key': 'value'})
+            with self.assertRaises(QiniuInvalidTagError):
+                await bucket.async_tag('fakekey', {'env': 'x' * 257})
+            await bucket.async_untag('fakekey')
+            self.assertEqual(await bucket.async_get_tags('fakekey'), {})
+            await bucket.async_change_meta('fakekey', {'x-qn-meta-env': 'test'})
+            self.assertEqual(await bucket.async_get_tags('fakekey'), {'env': 'test'})
+        finally:
+            await runner.cleanup()
+
+    async def test_object_change_mime_and_meta(self):
+        case = self
+        state = {'mime': 'text/plain', 'metadata': {}}
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def stat(self):
+            return web.json_response({"fsize": 1024, "hash": 'fakehash', "mimeType": state['mime'], "x-qn-meta": state['metadata']}, headers={'X-ReqId': 'fakereqid'})
+
+        async def chgm(self):
+            case.assertEqual(
+                bytes(self.match_info['entry'], 'utf-8'),
+                base64.urlsafe_b64encode(b'fakebucket:fakekey'))
+            state['mime'] = base64.urlsafe_b64decode(
+                self.match_info['mime']).decode('utf-8')
+            segments = self.match_info.get('tail', '').split('/')
+            for i in range(0, len(segments) - 1, 2):
+                key = segments[i][len('x-qn-meta-'):]
+                state['metadata'][key] = base64.urlsafe_b64decode(
+                    segments[i+1]).decode('utf-8')
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/stat/{entry}', stat)])
+        app.add_routes(
+            [web.post('/chgm/{entry}/mime/{mime}', chgm)])
+        app.add_routes(
+            [web.post('/chgm/{entry}/mime/{mime}/{tail:.*}', chgm)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            await bucket.async_change_mime('fakekey', 'image/png')
+            self.assertEqual(state['mime'], 'image/png')
+            with self.assertRaises(QiniuMimeParseError):
+                await bucket.async_change_mime('fakekey', 'not a mime')
+
+            await bucket.async_change_meta('fakekey', {'x-qn-meta-owner': 'alice'})
+            self.assertEqual(state['metadata'], {'owner': 'alice'})
+            self.assertEqual(state['mime'], 'image/png')
+            with self.assertRaises(QiniuUnsupportedTypeError):
+                await bucket.async_change_meta('fakekey', {'owner': 'bob'})
+            self.assertEqual(state['metadata'], {'owner': 'alice'})
+        finally:
+            await runner.cleanup()
+
+    async def test_make_private_url(self):
+        objects_manager = objects.ObjectsManager(
+            credential.Credential('ak', 'sk'), use_https=False)
+        bucket = objects_manager.bucket('fakebucket')
+        self.assertEqual(bucket.name, 'fakebucket')
+        self.assertEqual(repr(bucket), "Bucket(name='fakebucket')")
+        self.assertEqual(str(bucket), 'fakebucket')
+        signed_url = bucket.make_private_url(
+            'fakedomain.com', 'fake key', expires_in=60)
+        self.assertTrue(signed_url.url.startswith(
+            'http://fakedomain.com/fake%20key?e='))
+        self.assertIn('&token=ak:', signed_url.url)
+        self.assertEqual(str(signed_url), signed_url.url)
+        self.assertEqual(os.fspath(signed_url), signed_url.url)
+        self.assertAlmostEqual(signed_url.expires_at, time.time() + 60, delta=5)
+        self.assertFalse(signed_url.is_expired())
+        self.assertTrue(bucket.make_private_url(
+            'fakedomain.com', 'fake key', expires_in=0).is_expired())
+        self.assertEqual(signed_url, signed_url)
+        self.assertEqual(hash(signed_url), hash(signed_url))
+
+    async def test_credential_failure_raises_api_call_error(self):
+        credential.EnvCredentialProvider.clear()
+        objects_manager = objects.ObjectsManager(
+            credential.EnvCredentialProvider(), use_https=False)
+        with self.assertRaises(QiniuApiCallError):
+            objects_manager.bucket('fakebucket').make_private_url(
+                'fakedomain.com', 'fake key')
+        with self.assertRaises(QiniuApiCallError):
+            await objects_manager.async_batch_fetch([('http://fakedomain.com/object_1', 'fakebucket:object_1')])
+
+    async def test_generate_upload_token(self):
+        objects_manager = objects.ObjectsManager(
+            credential.Credential('ak', 'sk'), use_https=False)
+        bucket = objects_manager.bucket('fakebucket')
+        token = bucket.generate_upload_token()
+        self.assertEqual(token.access_key(), 'ak')
+        self.assertEqual(token.bucket_name(), 'fakebucket')
+        self.assertIsNone(token.policy().key())
+        self.assertTrue(3590 < token.get_remaining_lifetime() <= 3600)
+
+        token = await bucket.async_generate_upload_token('fakekey', 60, returnBody='{"key":$(key)}')
+        self.assertEqual(token.policy().key(), 'fakekey')
+        self.assertEqual(token.policy().return_body(), '{"key":$(key)}')
+        self.assertTrue(50 < token.get_remaining_lifetime() <= 60)
+
+    async def test_object_url(self):
+        async def domain_list(request):
+            if request.query['tbl'] != 'fakebucket':
+                return web.json_response([], headers={'X-ReqId': 'fakereqid'})
+            return web.json_response(['fakedomain.com', 'fakedomain2.com'], headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v6/domain/list', domain_list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            bucket = objects_manager.bucket('fakebucket')
+            self.assertEqual(bucket.object_url('a b/中文#?.txt', domain='customdomain.com'),
+                             'http://customdomain.com/a%20b/%E4%B8%AD%E6%96%87%23%3F.txt')
+            self.assertEqual(await bucket.async_object_url('a b/中文#?.txt'),
+                             'http://fakedomain.com/a%20b/%E4%B8%AD%E6%96%87%23%3F.txt')
+            with self.assertRaises(QiniuApiCallError):
+                await objects_manager.bucket('unknownbucket').async_object_url('fakekey')
+        finally:
+            await runner.cleanup()
+
+    async def test_bucket_management(self):
+        case = self
+        buckets = []
+        storage_types = {}
+
+        async def mkbucket(self):
+            case.assertEqual(self.match_info['region'], 'z0')
+            if self.match_info['bucket'] in buckets:
+                return web.json_response({'error': 'bucket exists'}, status=614, headers={'X-ReqId': 'fakereqid'})
+            buckets.append(self.match_info['bucket'])
+            storage_types[self.match_info['bucket']] = self.query.get('storage_type')
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        async def private(self):
+            data = await self.post()
+            case.assertEqual(data.get('bucket'), 'fakebucket2')
+            case.assertEqual(data.get('private'), '1')
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        async def list_buckets(self):
+            region = self.query.get('region')
+            if region is None or region == 'z0':
+                return web.json_response(buckets, headers={'X-ReqId': 'fakereqid'})
+            else:
+                return web.json_response([], headers={'X-ReqId': 'fakereqid'})
+
+        async def drop(self):
+            buckets.remove(self.match_info['bucket'])
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        async def regions(self):
+            return web.json_response(all_regions_info(['z0', 'z1']), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes(
+            [web.post('/mkbucketv3/{bucket}/region/{region}', mkbucket)])
+        app.add_routes([web.post('/private', private)])
+        app.add_routes([web.get('/buckets', list_buckets)])
+        app.add_routes([web.post('/drop/{bucket}', drop)])
+        app.add_routes([web.get('/regions', regions)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            bucket = await objects_manager.async_create_bucket('fakebucket', 'z0')
+            self.assertEqual(bucket.name, 'fakebucket')
+            bucket2 = await objects_manager.async_create_bucket('fakebucket2', 'z0', private=True, storage_type=1)
+            self.assertEqual(storage_types, {'fakebucket': None, 'fakebucket2': '1'})
+            with self.assertRaises(QiniuApiCallError):
+                await objects_manager.async_create_bucket('fakebucket', 'z0')
+            for invalid_name in ['ab', 'a' * 64, 'FakeBucket', 'fake_bucket']:
+                with self.assertRaises(QiniuInvalidDomainWithPortError):
+                    await objects_manager.async_create_bucket(invalid_name, 'z0')
+            with self.assertRaises(ValueError):
+                await objects_manager.async_create_bucket('fakebucket3', 'z0', storage_type=5)
+            self.assertEqual(await objects_manager.async_list_buckets(), ['fakebucket', 'fakebucket2'])
+            self.assertEqual(await objects_manager.async_list_buckets_in_region('z0'), ['fakebucket', 'fakebucket2'])
+            self.assertEqual(await objects_manager.async_list_buckets_in_region('z1'), [])
+            all_buckets = await objects_manager.async_list_all_buckets()
+            self.assertEqual(all_buckets, {'z0': ['fakebucket', 'fakebucket2'], 'z1': []})
+            self.assertEqual(await asyncio.to_thread(objects_manager.list_all_buckets), all_buckets)
+            await bucket.async_drop()
+            await bucket2.async_drop()
+            self.assertEqual(await objects_manager.async_list_buckets(), [])
+
+            client = http_client.HttpClient()
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), http_client=client, use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            self.assertEqual(await objects_manager.async_list_buckets(), [])
+            client.close()
+            self.assertTrue(client.closed)
+            with self.assertRaises(QiniuApiCallError):
+                await objects_manager.async_list_buckets()
+        finally:
+            await runner.cleanup()
+
+    async def test_delete_bucket(self):
+        keys = ['obj1', 'obj2', 'locked']
+        dropped = []
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def list(request):
+            return web.json_response({
+                "marker": "",
+                "items": [{"key": key, "hash": key + "hash", "fsize": 1} for key in keys]
+            }, headers={'X-ReqId': 'fakereqid'})
+
+        async def batch(self):
+            data = await self.post()
+            responses = []
+            for op in data.getall('op'):
+                _, base64ed_entry = op.split('/', 1)
+                key = base64.urlsafe_b64decode(base64ed_entry).decode('utf-8')[len('fakebucket:'):]
+                if key == 'locked':
+                    responses.append({'code': 612, 'data': {'error': 'no such file or directory'}})
+                else:
+                    keys.remove(key)
+                    responses.append({'code': 200})
+            return web.json_response(responses, headers={'X-ReqId': 'fakereqid'})
+
+        async def drop(self):
+            if keys:
+                return web.json_response({'error': 'bucket is not empty'}, status=403, headers={'X-ReqId': 'fakereqid'})
+            dropped.append(self.match_info['bucket'])
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.get('/list', list)])
+        app.add_routes([web.post('/batch', batch)])
+        app.add_routes([web.post('/drop/{bucket}', drop)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential('ak', 'sk'), use_https=False,
+                                                     uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']), queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            with self.assertRaises(QiniuApiCallError):
+                await bucket.async_delete_bucket()
+            with self.assertRaises(QiniuApiCallError) as cm:
+                await bucket.async_delete_bucket(force=True)
+            self.assertIn('2 objects were deleted', str(cm.exception))
+            self.assertEqual(keys, ['locked'])
+            self.assertEqual(dropped, [])
+            keys.remove('locked')
+            await bucket.async_delete_bucket(force=True)
+            self.assertEqual(dropped, ['fakebucket'])
+        finally:
+            await runner.cleanup()
+
+    async def test_get_bucket_info(self):
+        case = self
+
+        async def bucket_info(request):
+            if request.query['bucket'] != 'fakebucket':
+                return web.json_response({'error': 'no such bucket'}, status=612, headers={'X-ReqId': 'fakereqid'})
+            case.assertTrue(request.headers['Authorization'].startswith('Qiniu ak:'))
+            return web.json_response({'region': 'z0', 'private': 1, 'file_num': 10, 'storage_size': 1024, 'storage_type': 1}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v2/bucketInfo', bucket_info)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            info = await objects_manager.bucket('fakebucket').async_get_bucket_info()
+            self.assertEqual(info.region_id, 'z0')
+            self.assertTrue(info.private)
+            self.assertEqual(info.file_count, 10)
+            self.assertEqual(info.storage_size, 1024)
+            self.assertEqual(info.storage_type, 1)
+            with self.assertRaises(QiniuApiCallError):
+                await objects_manager.bucket('unknownbucket').async_get_bucket_info()
+        finally:
+            await runner.cleanup()
+
+    async def test_get_domains(self):
+        tables = []
+
+        async def domain_list(request):
+            tables.append(request.query['tbl'])
+            return web.json_response(['fakedomain.com', 'fakedomain2.com'], headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v6/domain/list', domain_list)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            bucket = objects_manager.bucket('fakebucket')
+            self.assertEqual(await bucket.async_get_domains(), ['fakedomain.com', 'fakedomain2.com'])
+            self.assertEqual(await asyncio.to_thread(bucket.get_domains), ['fakedomain.com', 'fakedomain2.com'])
+            self.assertEqual(tables, ['fakebucket', 'fakebucket'])
+        finally:
+            await runner.cleanup()
+
+    async def test_bucket_cors(self):
+        rules = []
+
+        async def set_cors(request):
+            rules.clear()
+            rules.extend(await request.json())
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        async def get_cors(request):
+            return web.json_response(rules, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/corsRules/set/fakebucket', set_cors)])
+        app.add_routes([web.get('/corsRules/get/fakebucket', get_cors)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            bucket = objects_manager.bucket('fakebucket')
+            await bucket.async_enable_cors(['http://www.example.com', 'https://cdn.example.com'], ['GET', 'PUT'],
+                                           allowed_headers=['X-Custom'], max_age_secs=600)
+            self.assertEqual(rules, [{
+                'allowed_origin': ['http://www.example.com', 'https://cdn.example.com'],
+                'allowed_method': ['GET', 'PUT'],
+                'allowed_header': ['X-Custom'],
+                'exposed_header': [],
+                'max_age': 600,
+            }])
+            self.assertEqual([rule.to_dict() for rule in await bucket.async_get_cors_rules()], [{
+                'allowed_origins': ['http://www.example.com', 'https://cdn.example.com'],
+                'allowed_methods': ['GET', 'PUT'],
+                'allowed_headers': ['X-Custom'],
+                'expose_headers': [],
+                'max_age_secs': 600,
+            }])
+            await bucket.async_enable_cors(['*'], ['POST'], expose_headers=['ETag'])
+            [rule] = await bucket.async_get_cors_rules()
+            self.assertEqual(rule.allowed_origins, ['*'])
+            self.assertEqual(rule.allowed_methods, ['POST'])
+            self.assertEqual(rule.allowed_headers, [])
+            self.assertEqual(rule.expose_headers, ['ETag'])
+            self.assertEqual(rule.max_age_secs, 3600)
+            await bucket.async_enable_cors([objects.CorsRule(['*'], ['get']), objects.CorsRule(['https://www.example.com'], ['DELETE'], max_age_secs=60)])
+            self.assertEqual([(rule.allowed_methods, rule.max_age_secs) for rule in await bucket.async_get_cors_rules()],
+                             [(['GET'], 3600), (['DELETE'], 60)])
+            await bucket.async_enable_cors(rule)
+            self.assertEqual(len(await bucket.async_get_cors_rules()), 1)
+            with self.assertRaises(ValueError):
+                await bucket.async_enable_cors(rule, ['GET'])
+            with self.assertRaises(ValueError):
+                await bucket.async_enable_cors(['*'])
+            with self.assertRaises(QiniuInvalidURLError):
+                await bucket.async_enable_cors(['http://exa mple.com'], ['GET'])
+            with self.assertRaises(QiniuInvalidMethodError):
+                await bucket.async_enable_cors(['*'], ['GE T'])
+            with self.assertRaises(QiniuInvalidMethodError):
+                objects.CorsRule(['*'], ['FOO'])
+            await bucket.async_disable_cors()
+            self.assertEqual(await bucket.async_get_cors_rules(), [])
+        finally:
+            await runner.cleanup()
+
+    async def test_change_storage_type(self):
+        case = self
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def chtype(request):
+            entry = base64.urlsafe_b64decode(
+                request.match_info['entry']).decode('utf-8')
+            if entry == 'fakebucket:frozen':
+                return web.json_response({'error': 'archive object is frozen'}, status=400, headers={'X-ReqId': 'fakereqid'})
+            case.assertEqual(entry, 'fakebucket:fakeobject')
+            case.assertEqual(request.match_info['type'], '4')
+            storage_types[entry] = int(request.match_info['type'])
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        async def stat(request):
+            entry = base64.urlsafe_b64decode(
+                request.match_info['entry']).decode('utf-8')
+            return web.json_response({'type': storage_types.get(entry, 0)}, headers={'X-ReqId': 'fakereqid'})
+
+        storage_types = {}
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.post('/chtype/{entry}/type/{type}', chtype)])
+        app.add_routes([web.get('/stat/{entry}', stat)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            self.assertEqual(await bucket.async_get_storage_class('fakeobject'), objects.StorageClass.Standard)
+            await bucket.async_change_storage_type('fakeobject', objects.StorageClass.ArchiveInfrequentAccess)
+            self.assertEqual(await bucket.async_get_storage_class('fakeobject'), objects.StorageClass.ArchiveInfrequentAccess)
+            with self.assertRaises(QiniuApiCallError):
+                await bucket.async_change_storage_type('frozen', objects.StorageClass.Standard)
+        finally:
+            await runner.cleanup()
+
+    async def test_restore_archived(self):
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        async def restoreAr(request):
+            entry = base64.urlsafe_b64decode(
+                request.match_info['entry']).decode('utf-8')
+            if entry == 'fakebucket:standard':
+                return web.json_response({'error': 'invalid arguments'}, status=400, headers={'X-ReqId': 'fakereqid'})
+            restored.append((entry, request.match_info['afterDays']))
+            return web.json_response({}, headers={'X-ReqId': 'fakereqid'})
+
+        restored = []
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes(
+            [web.post('/restoreAr/{entry}/freezeAfterDays/{afterDays}', restoreAr)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            await bucket.async_restore_archived('fakeobject')
+            await bucket.async_restore_archived('fakeobject', freeze_after_days=7)
+            await asyncio.to_thread(bucket.restore_archived, 'fakeobject')
+            self.assertEqual(restored, [
+                ('fakebucket:fakeobject', '1'),
+                ('fakebucket:fakeobject', '7'),
+                ('fakebucket:fakeobject', '1'),
+            ])
+            with self.assertRaises(QiniuApiCallError):
+                await bucket.async_restore_archived('standard')
+        finally:
+            await runner.cleanup()
+
+    async def test_batch_fetch(self):
+        case = self
+
+        async def query(self):
+            info = regions_info()
+            info['hosts'][0]['io']['domains'] = ['127.0.0.1:8089']
+            return web.json_response(info, headers={'X-ReqId': 'fakereqid'})
+
+        async def fetch(self):
+            url = base64.urlsafe_b64decode(
+                self.match_info['src']).decode('utf-8')
+            entry = base64.urlsafe_b64decode(
+                self.match_info['dst']).decode('utf-8')
+            case.assertTrue(entry.startswith('fakebucket:'))
+            if url.endswith('/missing'):
+                return web.json_response({'error': 'resource not found'}, status=404, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'fsize': 1024, 'hash': 'fakehash', 'key': entry[len('fakebucket:'):]}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.post('/fetch/{src}/to/{dst}', fetch)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            results = await objects_manager.async_batch_fetch([
+                ('http://fakedomain.com/object_1', 'fakebucket:object_1'),
+                ('http://fakedomain.com/missing', 'fakebucket:object_2'),
+                ('http://fakedomain.com/object_3', 'fakebucket:object_3'),
+            ], concurrency=2)
+            self.assertEqual(len(results), 3)
+            self.assertEqual(results[0]['key'], 'object_1')
+            self.assertIsInstance(results[1], QiniuApiCallError)
+            self.assertEqual(results[2]['key'], 'object_3')
+            with self.assertRaises(ValueError):
+                await objects_manager.async_batch_fetch([('http://fakedomain.com/object_1', 'object_1')])
+        finally:
+            await runner.cleanup()
+
+    async def test_import_from_url_async_bulk(self):
+        async def query(self):
+            info = regions_info()
+            info['hosts'][0]['io']['domains'] = ['127.0.0.1:8089']
+            return web.json_response(info, headers={'X-ReqId': 'fakereqid'})
+
+        async def fetch(self):
+            url = base64.urlsafe_b64decode(
+                self.match_info['src']).decode('utf-8')
+            entry = base64.urlsafe_b64decode(
+                self.match_info['dst']).decode('utf-8')
+            if url.endswith('/missing'):
+                return web.json_response({'error': 'resource not found'}, status=404, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'fsize': 1024, 'hash': 'fakehash', 'key': entry[len('fakebucket:'):]}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.post('/fetch/{src}/to/{dst}', fetch)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        async def urls_keys():
+            for i in range(10):
+                yield ('http://fakedomain.com/object_%d' % i, 'object_%d' % i)
+            yield ('http://fakedomain.com/missing', 'missing')
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            results = [result async for result in bucket.import_from_url_async_bulk(urls_keys(), concurrency=3)]
+            self.assertEqual(len(results), 11)
+            succeeded = sorted(result['result']['key'] for result in results if 'result' in result)
+            self.assertEqual(succeeded, sorted('object_%d' % i for i in range(10)))
+            failed = [result for result in results if 'error' in result]
+            self.assertEqual(len(failed), 1)
+            self.assertEqual(failed[0]['key'], 'missing')
+            self.assertEqual(failed[0]['url'], 'http://fakedomain.com/missing')
+            self.assertIsInstance(failed[0]['error'], QiniuApiCallError)
+        finally:
+            await runner.cleanup()
+
+    async def test_copy_from_url(self):
+        case = self
+
+        async def query(self):
+            info = regions_info()
+            info['hosts'][0]['io']['domains'] = ['127.0.0.1:8089']
+            return web.json_response(info, headers={'X-ReqId': 'fakereqid'})
+
+        async def fetch(self):
+            url = base64.urlsafe_b64decode(
+                self.match_info['src']).decode('utf-8')
+            entry = base64.urlsafe_b64decode(
+                self.match_info['dst']).decode('utf-8')
+            case.assertEqual(url, 'http://fakedomain.com/image.png')
+            case.assertEqual(entry, 'fakebucket:fakeobject')
+            case.assertEqual(self.headers['Content-Type'], 'image/png')
+            case.assertEqual(self.headers['X-Qn-Meta-Source'], 'cdn')
+            return web.json_response({'fsize': 1024, 'hash': 'fakehash', 'key': 'fakeobject'}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/v4/query', query)])
+        app.add_routes([web.post('/fetch/{src}/to/{dst}', fetch)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            objects_manager = objects.ObjectsManager(credential.Credential(
+                'ak', 'sk'), use_https=False, queryer=queryer)
+            bucket = objects_manager.bucket('fakebucket')
+            result = await bucket.async_copy_from_url(
+                'http://fakedomain.com/image.png', 'fakeobject', mime='image/png', meta={'x-qn-meta-source': 'cdn'})
+            self.assertEqual(result['hash'], 'fakehash')
+            self.assertEqual(result['key'], 'fakeobject')
+            with self.assertRaises(QiniuUnsupportedTypeError):
+                bucket.async_copy_from_url('http://fakedomain.com/image.png', 'fakeobject', meta={'source': 'cdn'})
+        finally:
+            await runner.cleanup()
+
+
+def all_regions_info(region_ids):
+    return {
+        "regions": [
+            {
+                "id": region_id,
+                "ttl": 5,
+                "description": region_id,
+                "io": {"domains": ["127.0.0.1:8089"]},
+                "up": {"domains": ["127.0.0.1:8089"]},
+                "uc": {"domains": ["127.0.0.1:8089"]},
+                "rs": {"domains": ["127.0.0.1:8089"]},
+                "rsf": {"domains": ["127.0.0.1:8089"]},
+                "api": {"domains": ["127.0.0.1:8089"]},
+                "s3": {"domains": ["127.0.0.1:8089"], "region_alias": region_id},
+            }
+            for region_id in region_ids
+        ]
+    }
+
 
 def regions_info():
     return {
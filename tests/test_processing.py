@@ -0,0 +1,53 @@
+from qiniu_sdk_bindings import processing, credential, http_client, QiniuApiCallError
+from aiohttp import web
+import unittest
+
+
+class TestPfopStatus(unittest.TestCase):
+    def test_pfop_status(self):
+        self.assertTrue(processing.PfopStatus.Success.is_terminal())
+        self.assertTrue(processing.PfopStatus.Failure.is_terminal())
+        self.assertTrue(processing.PfopStatus.NotifyFailure.is_terminal())
+        self.assertFalse(processing.PfopStatus.Pending.is_terminal())
+        self.assertFalse(processing.PfopStatus.Processing.is_terminal())
+
+
+class TestPersistentOpsManager(unittest.IsolatedAsyncioTestCase):
+    async def test_wait_for_completion(self):
+        case = self
+        polls = []
+
+        async def prefop(request):
+            persistent_id = request.query['id']
+            polls.append(persistent_id)
+            if persistent_id == 'fakeid':
+                code = [1, 2, 0][min(len(polls), 3) - 1]
+            else:
+                code = 1
+            return web.json_response({'id': persistent_id, 'code': code, 'desc': 'fakedesc', 'items': []}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.get('/status/get/prefop', prefop)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            manager = processing.PersistentOpsManager(credential.Credential('ak', 'sk'), poll_interval_secs=0.01,
+                                                      max_polls=3, use_https=False, api_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            result = await manager.async_wait_for_completion('fakeid')
+            case.assertEqual(result['id'], 'fakeid')
+            case.assertEqual(result['code'], 0)
+            case.assertEqual(len(polls), 3)
+
+            polls.clear()
+            with self.assertRaises(QiniuApiCallError):
+                await manager.async_wait_for_completion('pendingid')
+            case.assertEqual(len(polls), 3)
+
+            for interval in (-1.0, float('nan'), float('inf')):
+                with self.assertRaises(ValueError):
+                    processing.PersistentOpsManager(credential.Credential('ak', 'sk'), poll_interval_secs=interval)
+        finally:
+            await runner.cleanup()
@@ -1,6 +1,7 @@
-from qiniu_sdk_bindings import upload, credential, http_client, QiniuIoError
+from qiniu_sdk_bindings import upload, credential, http_client, etag, QiniuIoError, QiniuUnsupportedTypeError, QiniuBodySizeMissingError, QiniuInvalidConcurrency
 from aiohttp import web
 import unittest
+import asyncio
 import io
 import os
 import secrets
@@ -146,6 +147,532 @@ class TestFormUploader(unittest.IsolatedAsyncioTestCase):
             await runner.cleanup()
 
 
+    async def test_upload_file_with_form_fields(self):
+        case = self
+
+        async def form_upload(request):
+            data = await request.post()
+            case.assertEqual(data['key'], 'fakeobjectname')
+            case.assertEqual(data['x-qn-meta-author'], 'qiniu')
+            case.assertEqual(data['x:biz_id'], '123')
+            case.assertEqual(len(data['file'].file.read()), 1 << 10)
+            data['file'].file.close()
+
+            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer)
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(os.urandom(1 << 10))
+                await f.flush()
+                with self.assertRaises(QiniuUnsupportedTypeError):
+                    await upload_manager.async_upload_file_with_form_fields(
+                        f.name, {'author': 'qiniu'}, key='fakeobjectname')
+                result = await upload_manager.async_upload_file_with_form_fields(
+                    f.name, {'x-qn-meta-author': 'qiniu', 'x:biz_id': '123'}, key='fakeobjectname')
+                self.assertEqual(result['key'], 'fakekey')
+                self.assertEqual(result['hash'], 'fakehash')
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_stream(self):
+        case = self
+
+        async def form_upload(request):
+            data = await request.post()
+            case.assertEqual(data['key'], 'fakeobjectname')
+            case.assertEqual(data['file'].content_type, 'text/plain')
+            case.assertEqual(len(data['file'].file.read()), 1 << 10)
+            data['file'].file.close()
+            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        class AsyncStream:
+            def __init__(self, data):
+                self.data = data
+
+            async def read(self, size=-1):
+                if size < 0:
+                    size = len(self.data)
+                chunk, self.data = self.data[:size], self.data[size:]
+                return chunk
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        site = web.TCPSite(runner, '127.0.0.1', 8089)
+        await site.start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer)
+            with self.assertRaises(QiniuBodySizeMissingError):
+                await upload_manager.async_upload_stream(AsyncStream(os.urandom(1 << 10)), key='fakeobjectname')
+            result = await upload_manager.async_upload_stream(
+                AsyncStream(os.urandom(1 << 10)), 1 << 10, key='fakeobjectname', mime='text/plain')
+            self.assertEqual(result['key'], 'fakekey')
+            self.assertEqual(result['hash'], 'fakehash')
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_logger(self):
+        requests = []
+
+        async def form_upload(request):
+            data = await request.post()
+            data['file'].file.close()
+            requests.append(data['key'])
+            if len(requests) == 1:
+                return web.json_response({'error': 'fake error'}, status=599, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'key': 'fakekey', 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            info = regions_info()
+            info['hosts'][0]['up']['domains'].append('127.0.0.1:8090')
+            return web.json_response(info, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+        await web.TCPSite(runner, '127.0.0.1', 8090).start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            entries = []
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer).with_upload_logger(entries.append)
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(os.urandom(1 << 10))
+                await f.seek(0, io.SEEK_SET)
+                result = await upload_manager.form_uploader().async_upload_reader(
+                    f, object_name='fakeobjectname', file_name='fakefilename')
+                self.assertEqual(result['key'], 'fakekey')
+            self.assertEqual(len(requests), 2)
+            self.assertEqual(len(entries), 2)
+            self.assertFalse(entries[0].success)
+            self.assertIsNotNone(entries[0].error)
+            self.assertEqual(entries[0].retry_count, 0)
+            self.assertEqual(entries[0].server_ip, '127.0.0.1')
+            self.assertIn(entries[0].server_port, (8089, 8090))
+            self.assertEqual(entries[0].endpoint.ip_addr, '127.0.0.1')
+            self.assertEqual(entries[0].endpoint.port, entries[0].server_port)
+            self.assertIsNone(entries[0].part_number)
+            self.assertTrue(entries[1].success)
+            self.assertIsNone(entries[1].error)
+            self.assertIsNone(entries[1].part_number)
+            self.assertIsNone(entries[1].part_offset)
+            self.assertIsNone(entries[1].part_size)
+            self.assertEqual(entries[1].retry_count, 0)
+            self.assertEqual(entries[1].server_ip, '127.0.0.1')
+            self.assertEqual(entries[1].endpoint.ip_addr, '127.0.0.1')
+        finally:
+            await runner.cleanup()
+
+    async def test_default_regions_provider(self):
+        async def form_upload(request):
+            data = await request.post()
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            self.assertIsNone(upload.UploadManager.get_default_regions_provider())
+            upload.UploadManager.set_default_regions_provider(http_client.RegionsProvider(
+                [http_client.Region('z0', up_preferred_endpoints=['127.0.0.1:8089'])]))
+            self.assertIsNotNone(upload.UploadManager.get_default_regions_provider())
+            uploader = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False).form_uploader()
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(b'hello')
+                await f.seek(0, io.SEEK_SET)
+                result = await uploader.async_upload_reader(f, object_name='fakeobjectname')
+                self.assertEqual(result['key'], 'fakeobjectname')
+        finally:
+            upload.UploadManager.set_default_regions_provider(None)
+            await runner.cleanup()
+        self.assertIsNone(upload.UploadManager.get_default_regions_provider())
+
+    async def test_upload_manager_with_selector_config(self):
+        case = self
+
+        async def form_upload(request):
+            case.assertIn('fakeuseragent', request.headers['User-Agent'])
+            data = await request.post()
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                http_client=http_client.HttpClient(appended_user_agent='fakeuseragent'), use_https=False)
+            uploader = upload_manager.with_selector_config(
+                http_client.SelectorConfig('latency', base_timeout_ms=0)).form_uploader()
+            region = http_client.Region('z0', up_preferred_endpoints=['127.0.0.1:8089'])
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(b'hello')
+                await f.seek(0, io.SEEK_SET)
+                result = await uploader.async_upload_reader(f, object_name='fakeobjectname', region_provider=region)
+            self.assertEqual(result['key'], 'fakeobjectname')
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_manager_with_max_retry(self):
+        case = self
+        hits = {'bad': 0, 'good': 0}
+
+        async def bad_upload(request):
+            case.assertIn('fakeuseragent', request.headers['User-Agent'])
+            hits['bad'] += 1
+            await request.read()
+            return web.json_response({'error': 'service unavailable'}, status=503, headers={'X-ReqId': 'fakereqid'})
+
+        async def good_upload(request):
+            case.assertIn('fakeuseragent', request.headers['User-Agent'])
+            hits['good'] += 1
+            data = await request.post()
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        bad_app = web.Application()
+        bad_app.add_routes([web.post('/', bad_upload)])
+        bad_runner = web.AppRunner(bad_app)
+        await bad_runner.setup()
+        await web.TCPSite(bad_runner, '127.0.0.1', 8089).start()
+        good_app = web.Application()
+        good_app.add_routes([web.post('/', good_upload)])
+        good_runner = web.AppRunner(good_app)
+        await good_runner.setup()
+        await web.TCPSite(good_runner, '127.0.0.1', 8090).start()
+
+        try:
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                http_client=http_client.HttpClient(appended_user_agent='fakeuseragent'), use_https=False)
+            with self.assertRaises(QiniuInvalidConcurrency):
+                upload_manager.with_max_retry(-1)
+            uploader = upload_manager.with_max_retry(1).with_retry_backoff(
+                http_client.FixedBackoff(0)).form_uploader()
+            region = http_client.Region('z0', up_preferred_endpoints=[
+                                        '127.0.0.1:8089', '127.0.0.1:8090'])
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(b'hello')
+                await f.seek(0, io.SEEK_SET)
+                result = await uploader.async_upload_reader(f, object_name='fakeobjectname', region_provider=region)
+            self.assertEqual(result['key'], 'fakeobjectname')
+            self.assertEqual(hits['bad'], 1)
+            self.assertEqual(hits['good'], 1)
+        finally:
+            await bad_runner.cleanup()
+            await good_runner.cleanup()
+
+    async def test_upload_file_in_chunks(self):
+        uploaded = {}
+
+        async def init_parts(request):
+            return web.json_response({'uploadId': 'fakeUploadId'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def upload_part(request):
+            part_number = int(request.match_info['part_number'])
+            uploaded[part_number] = await request.read()
+            return web.json_response({'etag': 'fakeEtag-%d' % part_number, 'md5': 'fakemd5'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def complete_parts(request):
+            data = await request.json()
+            return web.json_response({'key': base64.urlsafe_b64decode(request.match_info['encoded_key']).decode('utf-8'), 'parts': len(data['parts'])}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads', init_parts)])
+        app.add_routes(
+            [web.put('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}/{part_number}', upload_part)])
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}', complete_parts)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        chunks = []
+
+        async def on_chunk(part_number, data):
+            chunks.append(part_number)
+            return bytes(reversed(data))
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False, queryer=queryer)
+            with self.assertRaises(ValueError):
+                upload_manager.upload_file_in_chunks(
+                    'unused', 'fakeobjectname', 1000, lambda _, data: data)
+            content = os.urandom(1 << 21)
+            entries = []
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(content)
+                await f.flush()
+                result = await upload_manager.with_upload_logger(entries.append).async_upload_file_in_chunks(
+                    f.name, 'fakeobjectname', 1 << 20, on_chunk)
+            self.assertEqual(result['key'], 'fakeobjectname')
+            self.assertEqual(chunks, [1, 2])
+            part_entries = [entry for entry in entries if entry.part_size is not None]
+            self.assertEqual([entry.part_number for entry in part_entries], [1, 2])
+            self.assertEqual([entry.part_offset for entry in part_entries], [0, 1 << 20])
+            self.assertTrue(all(entry.retry_count == 0 for entry in entries))
+            self.assertEqual(b''.join(uploaded[n] for n in sorted(uploaded)), bytes(
+                reversed(content[:1 << 20])) + bytes(reversed(content[1 << 20:])))
+
+            uploaded.clear()
+
+            async def on_chunk_with_header(part_number, data):
+                return b'part-%d:' % part_number + data[:len(data) // 2]
+            async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                await f.write(content)
+                await f.flush()
+                result = await upload_manager.async_upload_file_in_chunks(f.name, 'fakeobjectname', 1 << 20, on_chunk_with_header)
+            self.assertEqual(result['parts'], 2)
+            self.assertEqual(uploaded, {
+                1: b'part-1:' + content[:1 << 19],
+                2: b'part-2:' + content[1 << 20:(1 << 20) + (1 << 19)],
+            })
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_file_with_content_type_detection(self):
+        content_types = []
+
+        async def form_upload(request):
+            data = await request.post()
+            content_types.append(data['file'].content_type)
+            data['file'].file.close()
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        files = [
+            (b'\x89PNG\r\n\x1a\n', 'image/png'),
+            (b'\xff\xd8\xff\xe0', 'image/jpeg'),
+            (b'%PDF-1.4\n', 'application/pdf'),
+            (b'PK\x03\x04', 'application/zip'),
+            (b'\x00\x01\x02\x03', 'application/octet-stream'),
+        ]
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False, queryer=queryer)
+            for magic, _ in files:
+                async with aiofiles.tempfile.NamedTemporaryFile('wb+') as f:
+                    await f.write(magic + os.urandom(1 << 10))
+                    await f.flush()
+                    result = await upload_manager.async_upload_file_with_content_type_detection(f.name, 'fakeobjectname')
+                    self.assertEqual(result['key'], 'fakeobjectname')
+            self.assertEqual(content_types, [content_type for _, content_type in files])
+            with self.assertRaises(QiniuIoError):
+                await upload_manager.async_upload_file_with_content_type_detection('/non/existent/file')
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_file_async_generator(self):
+        uploaded = {}
+
+        async def init_parts(request):
+            return web.json_response({'uploadId': 'fakeUploadId'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def upload_part(request):
+            part_number = int(request.match_info['part_number'])
+            uploaded[part_number] = await request.read()
+            return web.json_response({'etag': 'fakeEtag-%d' % part_number, 'md5': 'fakemd5'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def complete_parts(request):
+            data = await request.json()
+            return web.json_response({'key': base64.urlsafe_b64decode(request.match_info['encoded_key']).decode('utf-8'), 'parts': len(data['parts'])}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application(client_max_size=1 << 30)
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads', init_parts)])
+        app.add_routes(
+            [web.put('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}/{part_number}', upload_part)])
+        app.add_routes(
+            [web.post('/buckets/{bucket_name}/objects/{encoded_key}/uploads/{upload_id}', complete_parts)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        chunks = [os.urandom(1 << 20) for _ in range(6)]
+
+        async def gen():
+            for chunk in chunks:
+                await asyncio.sleep(0)
+                yield chunk
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False, queryer=queryer)
+            result = await upload_manager.upload_file_async_generator(gen(), 6 << 20, key='fakeobjectname')
+            self.assertEqual(result['key'], 'fakeobjectname')
+            self.assertEqual(b''.join(uploaded[n] for n in sorted(uploaded)), b''.join(chunks))
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_directory(self):
+        keys = []
+
+        async def form_upload(request):
+            data = await request.post()
+            data['file'].file.close()
+            keys.append(data['key'])
+            if data['key'].endswith('bad.txt'):
+                return web.json_response({'error': 'fake error'}, status=400, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'key': data['key'], 'hash': 'fakehash'}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            return web.json_response(regions_info(), headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer)
+            async with aiofiles.tempfile.TemporaryDirectory() as d:
+                os.makedirs(os.path.join(d, 'sub'))
+                for name in ['a.txt', 'bad.txt', 'skip.log', os.path.join('sub', 'b.txt')]:
+                    async with aiofiles.open(os.path.join(d, name), 'wb') as f:
+                        await f.write(os.urandom(1 << 10))
+                result = await upload_manager.async_upload_directory(
+                    d, 'prefix/', concurrency=2, file_filter=lambda path: not path.endswith('.log'))
+            self.assertEqual(result['succeeded'], 2)
+            self.assertEqual(len(result['failed']), 1)
+            self.assertEqual(result['failed'][0]['path'], 'bad.txt')
+            self.assertEqual(result['failed'][0]['key'], 'prefix/bad.txt')
+            self.assertIsNotNone(result['failed'][0]['error'])
+            self.assertEqual(sorted(set(keys)), ['prefix/a.txt', 'prefix/bad.txt', 'prefix/sub/b.txt'])
+        finally:
+            await runner.cleanup()
+
+    async def test_upload_file_check_duplicate(self):
+        data = os.urandom(1 << 10)
+        local_etag = etag.etag_of(io.BytesIO(data))
+        remote = {}
+        uploaded = []
+
+        async def form_upload(request):
+            form = await request.post()
+            form['file'].file.close()
+            uploaded.append(form['key'])
+            return web.json_response({'key': form['key'], 'hash': local_etag}, headers={'X-ReqId': 'fakereqid'})
+
+        async def stat(request):
+            if 'hash' not in remote:
+                return web.json_response({'error': 'no such file or directory'}, status=612, headers={'X-ReqId': 'fakereqid'})
+            return web.json_response({'hash': remote['hash'], 'fsize': 1 << 10}, headers={'X-ReqId': 'fakereqid'})
+
+        async def query(self):
+            info = regions_info()
+            info['hosts'][0]['rs']['domains'].append('127.0.0.1:8089')
+            return web.json_response(info, headers={'X-ReqId': 'fakereqid'})
+
+        app = web.Application()
+        app.add_routes([web.post('/', form_upload)])
+        app.add_routes([web.get('/stat/{entry}', stat)])
+        app.add_routes([web.get('/v4/query', query)])
+        runner = web.AppRunner(app)
+        await runner.setup()
+        await web.TCPSite(runner, '127.0.0.1', 8089).start()
+
+        try:
+            queryer = http_client.BucketRegionsQueryer.in_memory(
+                use_https=False, uc_endpoints=http_client.Endpoints(['127.0.0.1:8089']))
+            upload_manager = upload.UploadManager(upload.UploadTokenSigner.new_credential_provider(
+                credential.Credential('ak', 'sk'), 'fakebucket', 3600),
+                use_https=False,
+                queryer=queryer)
+            async with aiofiles.tempfile.NamedTemporaryFile('wb') as f:
+                await f.write(data)
+                await f.flush()
+                result = await upload_manager.async_upload_file_check_duplicate(f.name, 'fakeobjectname')
+                self.assertEqual(result['key'], 'fakeobjectname')
+                remote['hash'] = local_etag
+                self.assertIsNone(await upload_manager.async_upload_file_check_duplicate(f.name, 'fakeobjectname'))
+                result = await upload_manager.async_upload_file_check_duplicate(
+                    f.name, 'fakeobjectname', skip_on_match=False)
+                self.assertEqual(result['hash'], local_etag)
+            self.assertEqual(uploaded, ['fakeobjectname', 'fakeobjectname'])
+        finally:
+            await runner.cleanup()
+
+
 class TestMultiPartsUploader(unittest.IsolatedAsyncioTestCase):
     async def test_multi_parts_v1_uploader(self):
         case = self
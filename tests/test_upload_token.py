@@ -1,5 +1,7 @@
-from qiniu_sdk_bindings import upload_token, credential
+from qiniu_sdk_bindings import upload_token, upload, credential, QiniuApiCallError, QiniuUploadTokenFormatError, QiniuInvalidURLError, QiniuInvalidObjectSize
 import unittest
+import asyncio
+import time
 
 
 class TestUploadPolicy(unittest.TestCase):
@@ -15,6 +17,18 @@ class TestUploadPolicy(unittest.TestCase):
         self.assertEqual(policy.key(), 'test-object')
         self.assertFalse(policy.use_prefixal_object_key())
 
+    def test_policy_factories(self):
+        policy = upload_token.UploadPolicy.for_object('test-bucket', 'test-object')
+        self.assertEqual(policy.bucket(), 'test-bucket')
+        self.assertEqual(policy.key(), 'test-object')
+        self.assertFalse(policy.use_prefixal_object_key())
+        self.assertAlmostEqual(policy.token_deadline(), time.time() + 3600, delta=5)
+
+        policy = upload_token.UploadPolicy.for_bucket('test-bucket', expires_in=60)
+        self.assertEqual(policy.bucket(), 'test-bucket')
+        self.assertIsNone(policy.key())
+        self.assertAlmostEqual(policy.token_deadline(), time.time() + 60, delta=5)
+
     def test_object_prefix_policy(self):
         policy = upload_token.UploadPolicy.new_for_objects_with_prefix(
             'test-bucket', 'test-object', 3600).build()
@@ -162,6 +176,63 @@ class TestUploadPolicy(unittest.TestCase):
         self.assertEqual(policy.maximum_file_size(), 5)
 
 
+    def test_policy_builder(self):
+        policy = upload_token.UploadPolicy.builder('test-bucket') \
+            .key('test-object') \
+            .insert_only() \
+            .fsize_limit(1024) \
+            .build_with_default_deadline(7200)
+        self.assertEqual(policy.bucket(), 'test-bucket')
+        self.assertEqual(policy.key(), 'test-object')
+        self.assertTrue(policy.is_insert_only())
+        self.assertEqual(policy.maximum_file_size(), 1024)
+        self.assertGreater(policy.token_deadline(), time.time() + 3600)
+
+        policy = upload_token.UploadPolicy.builder(
+            'test-bucket').deadline(int(time.time()) + 60).build()
+        self.assertEqual(policy.bucket(), 'test-bucket')
+        self.assertLess(policy.token_deadline(), time.time() + 120)
+
+    def test_persistent_ops_and_pipeline(self):
+        policy = upload_token.UploadPolicy.new_for_bucket('test-bucket', 3600) \
+            .with_persistent_ops_and_pipeline(
+                ['avthumb/mp4', 'vframe/jpg/offset/1'], 'test-pipeline',
+                notify_url='https://example.com/notify') \
+            .build()
+        self.assertEqual(policy.get('persistentOps'), 'avthumb/mp4;vframe/jpg/offset/1')
+        self.assertEqual(policy.get('persistentPipeline'), 'test-pipeline')
+        self.assertEqual(policy.get('persistentNotifyUrl'), 'https://example.com/notify')
+
+        builder = upload_token.UploadPolicy.new_for_bucket('test-bucket', 3600)
+        with self.assertRaises(ValueError):
+            builder.with_persistent_ops_and_pipeline([], 'test-pipeline')
+        with self.assertRaises(QiniuInvalidURLError):
+            builder.with_persistent_ops_and_pipeline(['avthumb/mp4'], 'test-pipeline', notify_url='ftp://example.com/notify')
+        policy = builder.with_persistent_ops_and_pipeline(['avthumb/mp4'], 'test-pipeline').build()
+        self.assertIsNone(policy.get('persistentNotifyUrl'))
+
+    def test_merge(self):
+        base = upload_token.UploadPolicy.builder('test-bucket') \
+            .mime_types(['image/jpeg']) \
+            .fsize_limit(1024) \
+            .build()
+        other = upload_token.UploadPolicy.new_for_object('test-bucket', 'test-object', 3600) \
+            .mime_types(['image/png']) \
+            .insert_only() \
+            .build()
+        merged = base.merge(other)
+        self.assertEqual(merged.bucket(), 'test-bucket')
+        self.assertEqual(merged.key(), 'test-object')
+        self.assertTrue(merged.is_insert_only())
+        self.assertEqual(merged.maximum_file_size(), 1024)
+        self.assertEqual(merged.mime_types(), ['image/jpeg', 'image/png'])
+
+        another = upload_token.UploadPolicy.new_for_bucket(
+            'another-bucket', 3600).build()
+        with self.assertRaises(QiniuApiCallError):
+            base.merge(another)
+
+
 class TestUploadTokenProvider(unittest.TestCase):
     def test_static_upload_token_provider(self):
         cred = credential.Credential('test-ak', 'test-sk')
@@ -174,6 +245,49 @@ class TestUploadTokenProvider(unittest.TestCase):
         self.assertEqual(provider.bucket_name(), 'test-bucket')
         self.assertEqual(provider.policy().key(), 'test-object')
 
+    def test_upload_token_lifetime(self):
+        cred = credential.Credential('test-ak', 'test-sk')
+        policy = upload_token.UploadPolicy.new_for_bucket('test-bucket', 3600).build()
+        token = upload_token.UploadToken.from_policy_and_credential(policy, cred)
+        self.assertEqual(token.get_deadline_timestamp(), policy.token_deadline())
+        self.assertTrue(3590 < token.get_remaining_lifetime() <= 3600)
+
+        policy = upload_token.UploadPolicy.new_for_bucket('test-bucket', 3600).token_deadline(int(time.time()) - 10).build()
+        token = upload_token.UploadToken.from_policy_and_credential(policy, cred)
+        self.assertTrue(token.get_remaining_lifetime() < 0)
+
+    def test_upload_token_refresh(self):
+        cred = credential.Credential('test-ak', 'test-sk')
+        policy = upload_token.UploadPolicy.new_for_object('test-bucket', 'test-object', 3600) \
+            .token_deadline(int(time.time()) - 10).build()
+        token = upload_token.UploadToken.from_policy_and_credential(policy, cred)
+        self.assertTrue(token.get_remaining_lifetime() < 0)
+
+        refreshed = token.refresh(cred, new_expires_in=7200)
+        self.assertTrue(7190 < refreshed.get_remaining_lifetime() <= 7200)
+        self.assertEqual(refreshed.bucket_name(), 'test-bucket')
+        self.assertEqual(refreshed.policy().key(), 'test-object')
+        self.assertEqual(refreshed.access_key(), 'test-ak')
+
+        async def async_refresh():
+            return await token.async_refresh(cred)
+        refreshed = asyncio.run(async_refresh())
+        self.assertTrue(3590 < refreshed.get_remaining_lifetime() <= 3600)
+        self.assertEqual(refreshed.policy().key(), 'test-object')
+
+        with self.assertRaises(QiniuUploadTokenFormatError):
+            upload_token.UploadToken('test-ak:fake-sign:fake-policy').refresh(cred)
+
+    def test_upload_token_equality(self):
+        t1 = upload_token.UploadToken('test-ak:fake-sign:fake-policy')
+        t2 = upload_token.UploadToken('test-ak:fake-sign:fake-policy')
+        t3 = upload_token.UploadToken('test-ak:another-sign:fake-policy')
+        self.assertEqual(t1, t2)
+        self.assertNotEqual(t1, t3)
+        self.assertEqual(len({t1, t2, t3}), 2)
+        self.assertEqual({t1: 'cached'}[t2], 'cached')
+        self.assertEqual(len(t1), len('test-ak:fake-sign:fake-policy'))
+
     def test_bucket_upload_token_provider(self):
         cred = credential.Credential('test-ak', 'test-sk')
         provider = upload_token.BucketUploadTokenProvider(
@@ -216,6 +330,83 @@ class TestUploadTokenProvider(unittest.TestCase):
         self.assertEqual(provider.policy().return_url(), 'http://abc.com')
         self.assertTrue(provider.to_token_string().startswith('test-ak:'))
 
+    def test_python_upload_token_provider(self):
+        token = upload_token.UploadPolicy.new_for_object(
+            'test-bucket', 'test-object', 3600).build().to_upload_token_provider(
+                credential.Credential('test-ak', 'test-sk')).to_token_string()
+
+        class MyUploadTokenProvider(upload_token.UploadTokenProvider):
+            def __init__(self, token):
+                super().__init__()
+                self.token = token
+
+            def get_upload_token(self):
+                return self.token
+
+        provider = MyUploadTokenProvider(token)
+        self.assertEqual(provider.to_token_string(), token)
+        self.assertEqual(provider.access_key(), 'test-ak')
+        self.assertEqual(provider.bucket_name(), 'test-bucket')
+        self.assertEqual(provider.policy().key(), 'test-object')
+
+        async def async_to_token_string():
+            return await provider.async_to_token_string()
+
+        self.assertEqual(asyncio.run(async_to_token_string()), token)
+        signer = upload.UploadTokenSigner.new_upload_token_provider(
+            MyUploadTokenProvider(token))
+        self.assertIsNotNone(signer)
+
+        with self.assertRaises(QiniuUploadTokenFormatError):
+            MyUploadTokenProvider('malformed').to_token_string()
+        with self.assertRaises(QiniuUploadTokenFormatError):
+            MyUploadTokenProvider('malformed').access_key()
+
+    def test_upload_token_sign_policy_and_create(self):
+        policy = upload_token.UploadPolicy.new_for_object(
+            'test-bucket', 'test-object', 3600).build()
+        token = upload_token.UploadToken.sign_policy_and_create(policy, 'ak', 'sk')
+        self.assertEqual(token.access_key(), 'ak')
+        self.assertEqual(token.bucket_name(), 'test-bucket')
+        self.assertEqual(token, upload_token.UploadToken.from_policy_and_credential(
+            policy, credential.StaticCredentialProvider('ak', 'sk')))
+
+    def test_upload_token_for_batch_upload(self):
+        token = upload_token.UploadToken.for_batch_upload(
+            'test-bucket', credential.Credential('ak', 'sk'), max_file_size=1 << 20)
+        self.assertEqual(token.access_key(), 'ak')
+        self.assertEqual(token.bucket_name(), 'test-bucket')
+        policy = token.policy()
+        self.assertIsNone(policy.key())
+        self.assertEqual(policy.maximum_file_size(), 1 << 20)
+        self.assertGreater(policy.token_deadline(), time.time() + 3500)
+
+        token = upload_token.UploadToken.for_batch_upload(
+            'test-bucket', credential.Credential('ak', 'sk'), expires_in=60)
+        self.assertIsNone(token.policy().maximum_file_size())
+        for invalid in (0, -1):
+            with self.assertRaises(QiniuInvalidObjectSize):
+                upload_token.UploadToken.for_batch_upload(
+                    'test-bucket', credential.Credential('ak', 'sk'), max_file_size=invalid)
+
+    def test_upload_token_from_policy_and_credential(self):
+        policy = upload_token.UploadPolicy.new_for_object(
+            'test-bucket', 'test-object', 3600).build()
+        token = upload_token.UploadToken.from_policy_and_credential(
+            policy, credential.Credential('ak', 'sk'))
+        self.assertTrue(token.to_token_string().startswith('ak:'))
+        self.assertEqual(token.access_key(), 'ak')
+        self.assertEqual(token.bucket_name(), 'test-bucket')
+        self.assertEqual(token.policy().key(), 'test-object')
+
+        token = upload_token.UploadToken(token.to_token_string())
+        self.assertEqual(token.bucket_name(), 'test-bucket')
+
+        credential.EnvCredentialProvider.clear()
+        with self.assertRaises(QiniuApiCallError):
+            upload_token.UploadToken.from_policy_and_credential(
+                policy, credential.EnvCredentialProvider())
+
 
 if __name__ == '__main__':
     unittest.main()
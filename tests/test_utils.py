@@ -0,0 +1,31 @@
+from qiniu_sdk_bindings import utils, QiniuBase64Error
+import pathlib
+import unittest
+
+
+class TestMimeType(unittest.TestCase):
+    def test_mime_type_from_path(self):
+        self.assertEqual(utils.mime_type_from_path('/tmp/image.png'), 'image/png')
+        self.assertEqual(utils.mime_type_from_path(pathlib.Path('index.html')), 'text/html')
+        self.assertEqual(utils.mime_type_from_path('no_extension'), 'application/octet-stream')
+
+    def test_mime_type_from_bytes(self):
+        self.assertEqual(utils.mime_type_from_bytes(b'\x89PNG\r\n\x1a\n' + bytes(16)), 'image/png')
+        self.assertEqual(utils.mime_type_from_bytes(b'\xff\xd8\xff\xe0' + bytes(16)), 'image/jpeg')
+        self.assertEqual(utils.mime_type_from_bytes(b'%PDF-1.4\n' + bytes(1024)), 'application/pdf')
+        self.assertEqual(utils.mime_type_from_bytes(b'plain text'), 'application/octet-stream')
+        self.assertEqual(utils.mime_type_from_bytes(b'plain text', fallback='text/plain'), 'text/plain')
+        self.assertEqual(utils.mime_type_from_bytes(b''), 'application/octet-stream')
+        self.assertEqual(utils.mime_type_from_bytes(bytes(512) + b'\x89PNG\r\n\x1a\n'), 'application/octet-stream')
+
+
+class TestBase64(unittest.TestCase):
+    def test_base64_url(self):
+        self.assertEqual(utils.base64_url_encode(b'\xfb\xff\xbf'), '-_-_')
+        self.assertEqual(utils.base64_url_decode('-_-_'), b'\xfb\xff\xbf')
+        self.assertEqual(utils.base64_url_decode(utils.base64_url_encode(b'hello world')), b'hello world')
+        with self.assertRaises(QiniuBase64Error):
+            utils.base64_url_decode('!!!')
+
+    def test_entry_encode(self):
+        self.assertEqual(utils.entry_encode('bucket', 'key'), 'YnVja2V0OmtleQ==')